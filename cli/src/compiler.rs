@@ -65,6 +65,14 @@ pub fn compile(config: Config) -> Result<(), GeneralError> {
                 &out_path,
                 format.extension(),
             )?,
+            OutputFormatKind::Epub => write_raw_file(
+                &um.render_epub().map_err(|err| {
+                    log!(err);
+                    GeneralError::Render
+                })?,
+                &out_path,
+                format.extension(),
+            )?,
             OutputFormatKind::Umi => write_file(
                 &um.render_umi()
                     .map_err(|_| GeneralError::Render)?
@@ -73,6 +81,27 @@ pub fn compile(config: Config) -> Result<(), GeneralError> {
                 &out_path,
                 OutputFormatKind::Umi.extension(),
             )?,
+            OutputFormatKind::Markdown => write_file(
+                &um.render_markdown()
+                    .map_err(|_| GeneralError::Render)?
+                    .to_string(),
+                &out_path,
+                format.extension(),
+            )?,
+            OutputFormatKind::Latex => write_file(
+                &um.render_latex()
+                    .map_err(|_| GeneralError::Render)?
+                    .to_string(),
+                &out_path,
+                format.extension(),
+            )?,
+            OutputFormatKind::Txt => write_file(
+                &um.render_plain_text()
+                    .map_err(|_| GeneralError::Render)?
+                    .to_string(),
+                &out_path,
+                format.extension(),
+            )?,
         }
     }
 
@@ -102,6 +102,27 @@ fn equals_blocks_output(input: &Vec<Block>, output: &Vec<Block>) -> bool {
                     assert!(equals_blocks_output(&in_entry.body, &out_entry.body));
                 }
             }
+            (Block::NumberedList(block_in), Block::NumberedList(block_out)) => {
+                assert_eq!(
+                    block_in.entries.len(),
+                    block_out.entries.len(),
+                    "Numbered List entry count does not match"
+                );
+                assert_eq!(
+                    block_in.start_number, block_out.start_number,
+                    "Numbered List start number does not match"
+                );
+
+                for (in_entry, out_entry) in zip(block_in.entries.iter(), block_out.entries.iter())
+                {
+                    assert_eq!(
+                        in_entry.keyword, out_entry.keyword,
+                        "Numbered List Entry Keyword does not match"
+                    );
+                    assert!(equals_inlines_output(&in_entry.heading, &out_entry.heading));
+                    assert!(equals_blocks_output(&in_entry.body, &out_entry.body));
+                }
+            }
             _ => return false,
         }
     }
@@ -0,0 +1,203 @@
+//! Contains the [`Attributes`] that may be set for Unimarkup block and inline elements.
+
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value};
+
+/// Attribute keys that are treated as CSS properties.
+const STYLE_PROPERTIES: &[&str] = &[
+    "background",
+    "background-color",
+    "border",
+    "color",
+    "font-family",
+    "font-size",
+    "font-style",
+    "font-weight",
+    "height",
+    "margin",
+    "padding",
+    "text-align",
+    "text-decoration",
+    "width",
+];
+
+/// Attributes of a Unimarkup element set with the JSON-like attribute syntax.
+///
+/// e.g. `{ "id" : "ref-heading", "class" : "highlighted", "color" : "red" }`
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Attributes {
+    /// Optional identifier set with the `id` attribute.
+    id: Option<String>,
+    /// Class names set with the `class` attribute.
+    classes: Vec<String>,
+    /// CSS properties set with the `style` attribute, or directly as attribute (e.g. `"color" : "red"`).
+    style: Vec<(String, String)>,
+    /// All other attributes.
+    others: BTreeMap<String, String>,
+    /// The attributes in their original form.
+    raw: String,
+}
+
+impl Attributes {
+    /// Returns the identifier set with the `id` attribute.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Returns the class names set with the `class` attribute.
+    pub fn classes(&self) -> &[String] {
+        &self.classes
+    }
+
+    /// Returns the CSS properties as pairs of property name and value.
+    pub fn style(&self) -> &[(String, String)] {
+        &self.style
+    }
+
+    /// Returns the CSS properties combined to one inline style (e.g. `color: red; font-size: 20pt`).
+    pub fn css(&self) -> Option<String> {
+        if self.style.is_empty() {
+            return None;
+        }
+
+        Some(
+            self.style
+                .iter()
+                .map(|(property, value)| format!("{property}: {value}"))
+                .collect::<Vec<_>>()
+                .join("; "),
+        )
+    }
+
+    /// Returns the value of an attribute that is neither `id`, `class`, nor a CSS property.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.others.get(key).map(String::as_str)
+    }
+
+    /// Returns all attributes that are neither `id`, `class`, nor CSS properties.
+    pub fn others(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.others
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Returns the attributes in their original form.
+    pub fn as_unimarkup(&self) -> &str {
+        &self.raw
+    }
+
+    fn push_style(&mut self, property: &str, value: String) {
+        // `size` is a shorthand for the font size
+        let property = match property {
+            "size" => "font-size",
+            _ => property,
+        };
+
+        match self.style.iter_mut().find(|(name, _)| name == property) {
+            Some((_, prev_value)) => *prev_value = value,
+            None => self.style.push((property.to_string(), value)),
+        }
+    }
+}
+
+impl TryFrom<&str> for Attributes {
+    type Error = serde_json::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let map: Map<String, Value> = serde_json::from_str(value)?;
+        let mut attributes = Attributes {
+            raw: value.to_string(),
+            ..Default::default()
+        };
+
+        for (key, value) in map {
+            match key.as_str() {
+                "id" => attributes.id = Some(value_as_string(value)),
+                "class" => match value {
+                    Value::Array(classes) => attributes
+                        .classes
+                        .extend(classes.into_iter().map(value_as_string)),
+                    value => attributes
+                        .classes
+                        .extend(value_as_string(value).split_whitespace().map(String::from)),
+                },
+                "style" => match value {
+                    Value::Object(properties) => {
+                        for (property, value) in properties {
+                            attributes.push_style(&property, value_as_string(value));
+                        }
+                    }
+                    value => {
+                        for declaration in value_as_string(value).split(';') {
+                            if let Some((property, value)) = declaration.split_once(':') {
+                                attributes.push_style(property.trim(), value.trim().to_string());
+                            }
+                        }
+                    }
+                },
+                "size" => attributes.push_style(&key, value_as_string(value)),
+                key if STYLE_PROPERTIES.contains(&key) => {
+                    attributes.push_style(key, value_as_string(value))
+                }
+                _ => {
+                    attributes.others.insert(key, value_as_string(value));
+                }
+            }
+        }
+
+        Ok(attributes)
+    }
+}
+
+/// Converts a JSON value to its string representation without quotes around strings.
+fn value_as_string(value: Value) -> String {
+    match value {
+        Value::String(s) => s,
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Attributes;
+
+    #[test]
+    fn id_class_and_style() {
+        let attributes = Attributes::try_from(
+            r#"{ "id" : "ref-heading", "class" : "first second", "style" : "color: red; margin: 0" }"#,
+        )
+        .unwrap();
+
+        assert_eq!(attributes.id(), Some("ref-heading"));
+        assert_eq!(attributes.classes(), ["first", "second"]);
+        assert_eq!(attributes.css().as_deref(), Some("color: red; margin: 0"));
+    }
+
+    #[test]
+    fn style_properties_as_attributes() {
+        let attributes =
+            Attributes::try_from(r#"{ "size" : "20pt", "color" : "rgb(255,0,0)" }"#).unwrap();
+
+        assert_eq!(
+            attributes.css().as_deref(),
+            Some("color: rgb(255,0,0); font-size: 20pt")
+        );
+    }
+
+    #[test]
+    fn arbitrary_attributes() {
+        let attributes = Attributes::try_from(r#"{ "display" : "replace", "count" : 2 }"#).unwrap();
+
+        assert_eq!(attributes.get("display"), Some("replace"));
+        assert_eq!(attributes.get("count"), Some("2"));
+        assert_eq!(attributes.id(), None);
+        assert_eq!(attributes.css(), None);
+    }
+
+    #[test]
+    fn invalid_attributes() {
+        assert!(Attributes::try_from(r#"{ "id" : }"#).is_err());
+        assert!(Attributes::try_from(r#"[ "id" ]"#).is_err());
+    }
+}
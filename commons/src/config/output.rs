@@ -13,7 +13,7 @@ pub struct Output {
     /// Defines the output format to render to.
     /// If this option is not set, the input is rendered to all supported formats.
     ///
-    /// **Supported formats:** `html`, `pdf`
+    /// **Supported formats:** `html`, `pdf`, `markdown`, `latex`, `txt`, `epub`
     #[arg(long, alias = "output-formats", value_parser = parse_to_hashset::<OutputFormatKind>, required = false, default_value = "html")]
     pub formats: HashSet<OutputFormatKind>,
     /// `true` overwrites existing output files
@@ -72,6 +72,10 @@ pub enum OutputFormatKind {
     Html,
     Umi,
     Pdf,
+    Markdown,
+    Latex,
+    Txt,
+    Epub,
 }
 
 impl OutputFormatKind {
@@ -81,6 +85,10 @@ impl OutputFormatKind {
             OutputFormatKind::Html => "html",
             OutputFormatKind::Umi => "umi",
             OutputFormatKind::Pdf => "pdf",
+            OutputFormatKind::Markdown => "md",
+            OutputFormatKind::Latex => "tex",
+            OutputFormatKind::Txt => "txt",
+            OutputFormatKind::Epub => "epub",
         }
     }
 }
@@ -93,6 +101,10 @@ impl FromStr for OutputFormatKind {
             "html" => Ok(OutputFormatKind::Html),
             "umi" => Ok(OutputFormatKind::Umi),
             "pdf" => Ok(OutputFormatKind::Pdf),
+            "md" | "markdown" => Ok(OutputFormatKind::Markdown),
+            "tex" | "latex" => Ok(OutputFormatKind::Latex),
+            "txt" | "text" => Ok(OutputFormatKind::Txt),
+            "epub" => Ok(OutputFormatKind::Epub),
             o => Err(format!("Bad output format: {}", o)),
         }
     }
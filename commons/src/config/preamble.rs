@@ -87,6 +87,23 @@ pub struct RenderConfig {
     #[arg(long)]
     #[serde(default)]
     pub allow_unsafe: bool,
+    /// Embeds local images as data URIs, so rendered HTML does not depend on external files.
+    #[arg(long)]
+    #[serde(default)]
+    pub embed_media: bool,
+    /// Adds a glossary of all defined abbreviations at the end of the rendered document.
+    #[arg(long)]
+    #[serde(default)]
+    pub glossary: bool,
+    /// Disables implicit substitutions of arrows (e.g. `-->`) and emoticons (e.g. `:D`).
+    #[arg(long)]
+    #[serde(default)]
+    pub disable_implicit_substitutions: bool,
+    /// Maximum line width of rendered plain text. Lines are not wrapped if set to `0`.
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub text_width: Option<usize>,
 }
 
 impl ConfigFns for RenderConfig {
@@ -95,6 +112,10 @@ impl ConfigFns for RenderConfig {
         self.parameter.extend(other.parameter);
         self.keep_comments |= other.keep_comments;
         self.allow_unsafe |= other.allow_unsafe;
+        self.embed_media |= other.embed_media;
+        self.glossary |= other.glossary;
+        self.disable_implicit_substitutions |= other.disable_implicit_substitutions;
+        self.text_width.replace_none(other.text_width);
     }
 
     fn validate(&self) -> Result<(), ConfigErr> {
@@ -59,6 +59,12 @@ pub enum SymbolKind {
     Ampersand,
     /// A comma literal (`,`)
     Comma,
+    /// The greater-than literal (`>`) is used for quotation blocks.
+    GreaterThan,
+    /// The single quote literal (`'`) is used for render blocks.
+    SingleQuote,
+    /// The equals literal (`=`) is used for title decorations.
+    Equals,
     /// The open parentheses (`(`) literal is used for additional data to text group elements (e.g.
     /// image insert).
     OpenParenthesis,
@@ -252,6 +258,9 @@ impl From<&str> for SymbolKind {
             "." => SymbolKind::Dot,
             "&" => SymbolKind::Ampersand,
             "," => SymbolKind::Comma,
+            ">" => SymbolKind::GreaterThan,
+            "'" => SymbolKind::SingleQuote,
+            "=" => SymbolKind::Equals,
             symbol
                 if symbol != "\n"
                     && symbol != "\r\n"
@@ -313,6 +322,9 @@ impl SymbolKind {
             SymbolKind::Dot => ".",
             SymbolKind::Ampersand => "&",
             SymbolKind::Comma => ",",
+            SymbolKind::GreaterThan => ">",
+            SymbolKind::SingleQuote => "'",
+            SymbolKind::Equals => "=",
         }
     }
 }
@@ -41,32 +41,194 @@ impl ImplicitSubstitutionKind {
     }
 }
 
+/// Arrows that are implicitly substituted (e.g. `-->` becomes `⟶`).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum ArrowSubsitutionKind {}
+pub enum ArrowSubsitutionKind {
+    /// Right arrow `->`.
+    Right,
+    /// Left arrow `<-`.
+    Left,
+    /// Left-right arrow `<->`.
+    LeftRight,
+    /// Long right arrow `-->`.
+    LongRight,
+    /// Long left arrow `<--`.
+    LongLeft,
+    /// Long left-right arrow `<-->`.
+    LongLeftRight,
+    /// Right double arrow `=>`.
+    DoubleRight,
+    /// Left-right double arrow `<=>`.
+    DoubleLeftRight,
+    /// Long right double arrow `==>`.
+    LongDoubleRight,
+    /// Long left double arrow `<==`.
+    LongDoubleLeft,
+    /// Long left-right double arrow `<==>`.
+    LongDoubleLeftRight,
+    /// Right arrow from bar `|->`.
+    RightFromBar,
+    /// Left arrow from bar `<-|`.
+    LeftFromBar,
+    /// Long right arrow from bar `|-->`.
+    LongRightFromBar,
+    /// Long left arrow from bar `<--|`.
+    LongLeftFromBar,
+}
 
 impl ArrowSubsitutionKind {
+    /// All available arrow substitutions.
+    pub const ALL: &'static [Self] = &[
+        ArrowSubsitutionKind::Right,
+        ArrowSubsitutionKind::Left,
+        ArrowSubsitutionKind::LeftRight,
+        ArrowSubsitutionKind::LongRight,
+        ArrowSubsitutionKind::LongLeft,
+        ArrowSubsitutionKind::LongLeftRight,
+        ArrowSubsitutionKind::DoubleRight,
+        ArrowSubsitutionKind::DoubleLeftRight,
+        ArrowSubsitutionKind::LongDoubleRight,
+        ArrowSubsitutionKind::LongDoubleLeft,
+        ArrowSubsitutionKind::LongDoubleLeftRight,
+        ArrowSubsitutionKind::RightFromBar,
+        ArrowSubsitutionKind::LeftFromBar,
+        ArrowSubsitutionKind::LongRightFromBar,
+        ArrowSubsitutionKind::LongLeftFromBar,
+    ];
+
     pub fn orig(&self) -> &'static str {
-        //TODO: implement once arrows are defined
-        ""
+        match self {
+            ArrowSubsitutionKind::Right => "->",
+            ArrowSubsitutionKind::Left => "<-",
+            ArrowSubsitutionKind::LeftRight => "<->",
+            ArrowSubsitutionKind::LongRight => "-->",
+            ArrowSubsitutionKind::LongLeft => "<--",
+            ArrowSubsitutionKind::LongLeftRight => "<-->",
+            ArrowSubsitutionKind::DoubleRight => "=>",
+            ArrowSubsitutionKind::DoubleLeftRight => "<=>",
+            ArrowSubsitutionKind::LongDoubleRight => "==>",
+            ArrowSubsitutionKind::LongDoubleLeft => "<==",
+            ArrowSubsitutionKind::LongDoubleLeftRight => "<==>",
+            ArrowSubsitutionKind::RightFromBar => "|->",
+            ArrowSubsitutionKind::LeftFromBar => "<-|",
+            ArrowSubsitutionKind::LongRightFromBar => "|-->",
+            ArrowSubsitutionKind::LongLeftFromBar => "<--|",
+        }
     }
 
     pub fn subst(&self) -> &'static str {
-        //TODO: implement once arrows are defined
-        ""
+        match self {
+            ArrowSubsitutionKind::Right => "→",
+            ArrowSubsitutionKind::Left => "←",
+            ArrowSubsitutionKind::LeftRight => "↔",
+            ArrowSubsitutionKind::LongRight => "⟶",
+            ArrowSubsitutionKind::LongLeft => "⟵",
+            ArrowSubsitutionKind::LongLeftRight => "⟷",
+            ArrowSubsitutionKind::DoubleRight => "⇒",
+            ArrowSubsitutionKind::DoubleLeftRight => "⇔",
+            ArrowSubsitutionKind::LongDoubleRight => "⟹",
+            ArrowSubsitutionKind::LongDoubleLeft => "⟸",
+            ArrowSubsitutionKind::LongDoubleLeftRight => "⟺",
+            ArrowSubsitutionKind::RightFromBar => "↦",
+            ArrowSubsitutionKind::LeftFromBar => "↤",
+            ArrowSubsitutionKind::LongRightFromBar => "⟼",
+            ArrowSubsitutionKind::LongLeftFromBar => "⟻",
+        }
     }
 }
 
+/// Emoticons that are implicitly substituted by emojis (e.g. `:D` becomes `😃`).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum EmojiSubstitutionKind {}
+pub enum EmojiSubstitutionKind {
+    /// Slightly smiling face `:)`.
+    Smile,
+    /// Slightly smiling face with nose `:-)`.
+    SmileWithNose,
+    /// Slightly frowning face `:(`.
+    Frown,
+    /// Slightly frowning face with nose `:-(`.
+    FrownWithNose,
+    /// Grinning face `:D`.
+    Grin,
+    /// Grinning face with nose `:-D`.
+    GrinWithNose,
+    /// Winking face `;)`.
+    Wink,
+    /// Winking face with nose `;-)`.
+    WinkWithNose,
+    /// Face with tongue `:P`.
+    Tongue,
+    /// Face with tongue and nose `:-P`.
+    TongueWithNose,
+    /// Face with open mouth `:O`.
+    Surprised,
+    /// Face with open mouth and nose `:-O`.
+    SurprisedWithNose,
+    /// Neutral face `:|`.
+    Neutral,
+    /// Crying face `:'(`.
+    Crying,
+    /// Red heart `<3`.
+    Heart,
+}
 
 impl EmojiSubstitutionKind {
+    /// All available emoji substitutions.
+    pub const ALL: &'static [Self] = &[
+        EmojiSubstitutionKind::Smile,
+        EmojiSubstitutionKind::SmileWithNose,
+        EmojiSubstitutionKind::Frown,
+        EmojiSubstitutionKind::FrownWithNose,
+        EmojiSubstitutionKind::Grin,
+        EmojiSubstitutionKind::GrinWithNose,
+        EmojiSubstitutionKind::Wink,
+        EmojiSubstitutionKind::WinkWithNose,
+        EmojiSubstitutionKind::Tongue,
+        EmojiSubstitutionKind::TongueWithNose,
+        EmojiSubstitutionKind::Surprised,
+        EmojiSubstitutionKind::SurprisedWithNose,
+        EmojiSubstitutionKind::Neutral,
+        EmojiSubstitutionKind::Crying,
+        EmojiSubstitutionKind::Heart,
+    ];
+
     pub fn orig(&self) -> &'static str {
-        //TODO: implement once emojis are defined
-        ""
+        match self {
+            EmojiSubstitutionKind::Smile => ":)",
+            EmojiSubstitutionKind::SmileWithNose => ":-)",
+            EmojiSubstitutionKind::Frown => ":(",
+            EmojiSubstitutionKind::FrownWithNose => ":-(",
+            EmojiSubstitutionKind::Grin => ":D",
+            EmojiSubstitutionKind::GrinWithNose => ":-D",
+            EmojiSubstitutionKind::Wink => ";)",
+            EmojiSubstitutionKind::WinkWithNose => ";-)",
+            EmojiSubstitutionKind::Tongue => ":P",
+            EmojiSubstitutionKind::TongueWithNose => ":-P",
+            EmojiSubstitutionKind::Surprised => ":O",
+            EmojiSubstitutionKind::SurprisedWithNose => ":-O",
+            EmojiSubstitutionKind::Neutral => ":|",
+            EmojiSubstitutionKind::Crying => ":'(",
+            EmojiSubstitutionKind::Heart => "<3",
+        }
     }
 
     pub fn subst(&self) -> &'static str {
-        //TODO: implement once emojis are defined
-        ""
+        match self {
+            EmojiSubstitutionKind::Smile => "🙂",
+            EmojiSubstitutionKind::SmileWithNose => "🙂",
+            EmojiSubstitutionKind::Frown => "🙁",
+            EmojiSubstitutionKind::FrownWithNose => "🙁",
+            EmojiSubstitutionKind::Grin => "😃",
+            EmojiSubstitutionKind::GrinWithNose => "😃",
+            EmojiSubstitutionKind::Wink => "😉",
+            EmojiSubstitutionKind::WinkWithNose => "😉",
+            EmojiSubstitutionKind::Tongue => "😛",
+            EmojiSubstitutionKind::TongueWithNose => "😛",
+            EmojiSubstitutionKind::Surprised => "😮",
+            EmojiSubstitutionKind::SurprisedWithNose => "😮",
+            EmojiSubstitutionKind::Neutral => "😐",
+            EmojiSubstitutionKind::Crying => "😢",
+            EmojiSubstitutionKind::Heart => "❤️",
+        }
     }
 }
@@ -2,123 +2,106 @@ mod kind;
 
 pub use kind::*;
 
-// use itertools::PeekingNext;
-// use crate::lexer::position::Offset;
+use itertools::PeekingNext;
 
-// use super::{iterator::implicit::TokenIteratorImplicits, Token, TokenKind};
+use crate::lexer::{
+    position::Offset,
+    symbol::{iterator::SymbolIterator, Symbol, SymbolKind},
+};
 
-// pub fn get_implicit<'input>(
-//     implicit_iter: &mut TokenIteratorImplicits<'input>,
-// ) -> Option<Token<'input>> {
-//     if let Some(arrow_token) = get_implicit_arrow(implicit_iter) {
-//         Some(arrow_token)
-//     } else if let Some(emoji_token) = get_implicit_emoji(implicit_iter) {
-//         Some(emoji_token)
-//     } else if let Some(direct_uri_token) = get_direct_uri(implicit_iter) {
-//         Some(direct_uri_token)
-//     } else {
-//         let mut first_token = implicit_iter.base_iter.peeking_next(|_| true)?;
-//         match first_token.kind {
-//             // Might be: Trademark, Copyright, Registered, plusminus
-//             TokenKind::OpenParenthesis => {
-//                 if let Some(implicit_kind) =
-//                     get_trademark_copyright_registered_plusmins_kind(implicit_iter)
-//                 {
-//                     let closing = implicit_iter.base_iter.peeking_next(|_| true)?;
-//                     if closing.kind == TokenKind::CloseParenthesis {
-//                         return Some(Token {
-//                             input: first_token.input,
-//                             offset: Offset {
-//                                 start: first_token.offset.start,
-//                                 end: closing.offset.end,
-//                             },
-//                             kind: TokenKind::ImplicitSubstitution(implicit_kind),
-//                             start: first_token.start,
-//                             end: closing.end,
-//                         });
-//                     }
-//                 }
+use super::{Token, TokenKind};
 
-//                 return None;
-//             }
-//             TokenKind::Dot(3) => {
-//                 first_token.kind =
-//                     TokenKind::ImplicitSubstitution(ImplicitSubstitutionKind::HorizontalEllipsis);
-//             }
-//             TokenKind::Minus(2) => {
-//                 first_token.kind =
-//                     TokenKind::ImplicitSubstitution(ImplicitSubstitutionKind::EnDash);
-//             }
-//             TokenKind::Minus(3) => {
-//                 first_token.kind =
-//                     TokenKind::ImplicitSubstitution(ImplicitSubstitutionKind::EmDash);
-//             }
-//             _ => return None,
-//         }
+/// Tries to create a [`TokenKind::ImplicitSubstitution`] token starting at the given symbol.
+/// The given symbol must already be consumed by the symbol iterator.
+///
+/// Arrows are substituted anywhere, but emoticons must be surrounded by spaces or punctuation,
+/// to not substitute parts of regular words (e.g. `:D` in `C:Data`).
+///
+/// The symbol iterator is not advanced if no implicit substitution was found.
+pub(crate) fn get_implicit<'input>(
+    sym_iter: &mut SymbolIterator<'_, 'input>,
+    first_symbol: Symbol<'input>,
+    prev_kind: Option<TokenKind>,
+) -> Option<Token<'input>> {
+    let kind = implicit_at(&first_symbol)?;
 
-//         Some(first_token)
-//     }
-// }
+    if let ImplicitSubstitutionKind::Emoji(_) = kind {
+        if !matches!(
+            prev_kind,
+            None | Some(TokenKind::Whitespace | TokenKind::Newline | TokenKind::Blankline)
+        ) {
+            return None;
+        }
+    }
 
-// fn get_trademark_copyright_registered_plusmins_kind(
-//     implicit_iter: &mut TokenIteratorImplicits,
-// ) -> Option<ImplicitSubstitutionKind> {
-//     // First open parenthesis already consumed, lase closing parenthesis is checked in `get_implicit`
-//     let outer_token = implicit_iter.base_iter.peeking_next(|_| true)?;
-//     match outer_token.kind {
-//         // Might be: Copyright or Registered
-//         TokenKind::OpenParenthesis => {
-//             let inner_token = implicit_iter.base_iter.peeking_next(|_| true)?;
-//             if inner_token.kind == TokenKind::Plain {
-//                 let content = String::from(inner_token).to_lowercase();
-//                 let subst = if content == "c" {
-//                     Some(ImplicitSubstitutionKind::Copyright)
-//                 } else if content == "r" {
-//                     Some(ImplicitSubstitutionKind::Registered)
-//                 } else {
-//                     return None;
-//                 };
+    // Symbols are graphemes, so the implicit must end at a symbol boundary
+    let end_offset = first_symbol.offset.start + kind.orig().len();
+    let mut last_symbol = first_symbol;
 
-//                 if implicit_iter.base_iter.peeking_next(|_| true)?.kind
-//                     == TokenKind::CloseParenthesis
-//                 {
-//                     subst
-//                 } else {
-//                     None
-//                 }
-//             } else {
-//                 None
-//             }
-//         }
-//         // Might be: trademark or plusminus
-//         TokenKind::Plain => {
-//             let content = String::from(outer_token).to_lowercase();
-//             if content == "tm" {
-//                 Some(ImplicitSubstitutionKind::Trademark)
-//             } else if content == "+-" || content == "-+" {
-//                 Some(ImplicitSubstitutionKind::PlusMinus)
-//             } else {
-//                 None
-//             }
-//         }
-//         _ => None,
-//     }
-// }
+    while last_symbol.offset.end < end_offset {
+        match sym_iter.peeking_next(|_| true) {
+            Some(symbol) => last_symbol = *symbol,
+            None => break,
+        }
+    }
 
-// fn get_implicit_arrow<'input>(
-//     _implicit_iter: &mut TokenIteratorImplicits<'input>,
-// ) -> Option<Token<'input>> {
-//     None
-// }
+    if last_symbol.offset.end != end_offset
+        || (matches!(kind, ImplicitSubstitutionKind::Emoji(_))
+            && !sym_iter.peek_kind().is_none_or(is_emoji_end))
+    {
+        sym_iter.reset_peek();
+        return None;
+    }
 
-// fn get_implicit_emoji<'input>(
-//     _implicit_iter: &mut TokenIteratorImplicits<'input>,
-// ) -> Option<Token<'input>> {
-//     None
-// }
+    // Consume peeked symbols without iterating over them again
+    sym_iter.set_index(sym_iter.peek_index());
 
-// fn get_direct_uri<'input>(
-//     _implicit_iter: &mut TokenIteratorImplicits<'input>,
-// ) -> Option<Token<'input>> {
-//     None
-// }
+    Some(Token {
+        input: first_symbol.input,
+        offset: Offset {
+            start: first_symbol.offset.start,
+            end: last_symbol.offset.end,
+        },
+        kind: TokenKind::ImplicitSubstitution(kind),
+        start: first_symbol.start,
+        end: last_symbol.end,
+    })
+}
+
+/// Returns `true` if an emoticon may end before a symbol of the given kind.
+fn is_emoji_end(kind: SymbolKind) -> bool {
+    kind.is_space()
+        || matches!(
+            kind,
+            SymbolKind::TerminalPunctuation
+                | SymbolKind::Dot
+                | SymbolKind::Comma
+                | SymbolKind::CloseParenthesis
+                | SymbolKind::CloseBracket
+        )
+}
+
+/// Returns `true` if an arrow substitution starts at the given symbol.
+/// Used to not combine the start of an arrow with preceding plain symbols.
+pub(crate) fn is_arrow_start(symbol: &Symbol<'_>) -> bool {
+    matches!(
+        implicit_at(symbol),
+        Some(ImplicitSubstitutionKind::Arrow(_))
+    )
+}
+
+/// Returns the longest implicit substitution the remaining input starts with at the given symbol.
+fn implicit_at(symbol: &Symbol<'_>) -> Option<ImplicitSubstitutionKind> {
+    let remaining = &symbol.input[symbol.offset.start..];
+
+    ArrowSubsitutionKind::ALL
+        .iter()
+        .map(|arrow| ImplicitSubstitutionKind::Arrow(*arrow))
+        .chain(
+            EmojiSubstitutionKind::ALL
+                .iter()
+                .map(|emoji| ImplicitSubstitutionKind::Emoji(*emoji)),
+        )
+        .filter(|kind| remaining.starts_with(kind.orig()))
+        .max_by_key(|kind| kind.orig().len())
+}
@@ -91,15 +91,38 @@ impl<'slice, 'input> EndMatcher for TokenIterator<'slice, 'input> {
         let peek_index = self.peek_index();
 
         // peeking_next to move peek index forward
-        let is_blankline = if matches!(
-            self.peeking_next(|_| true).map(|t| t.kind),
-            Some(TokenKind::Blankline) | Some(TokenKind::Eoi)
-        ) {
-            self.set_match_index(self.peek_index());
-
-            true
-        } else {
-            false
+        let is_blankline = match self.peeking_next(|_| true).map(|t| t.kind) {
+            Some(TokenKind::Blankline) | Some(TokenKind::Eoi) => {
+                self.set_match_index(self.peek_index());
+
+                true
+            }
+            Some(TokenKind::Newline) => {
+                // Parent iterators may have stripped a non-whitespace prefix (e.g. `>` for quotations),
+                // leaving a line with only whitespace that was not lexed as blank line.
+                let _whitespaces = self
+                    .peeking_take_while(|t| t.kind == TokenKind::Whitespace)
+                    .count();
+                let line_end = self.peek_index();
+
+                // Line end is not consumed, to allow chaining contiguous blank lines
+                if self
+                    .peeking_next(|t| {
+                        matches!(
+                            t.kind,
+                            TokenKind::Newline | TokenKind::Blankline | TokenKind::Eoi
+                        )
+                    })
+                    .is_some()
+                {
+                    self.set_match_index(line_end);
+
+                    true
+                } else {
+                    false
+                }
+            }
+            _ => false,
         };
 
         self.set_peek_index(peek_index);
@@ -131,6 +154,9 @@ impl<'slice, 'input> EndMatcher for TokenIterator<'slice, 'input> {
                 Some(token) => {
                     kind != &TokenKind::EnclosedBlockEnd
                         || matches!(token.kind, TokenKind::Blankline | TokenKind::Eoi)
+                        // Attributes and decorations may directly follow enclosed blocks
+                        || (token.kind == TokenKind::Newline
+                            && (self.peeked_attributes_start() || self.peeked_decoration_start()))
                 }
                 None => kind == &TokenKind::EnclosedBlockEnd || kind == &TokenKind::Any,
             };
@@ -175,6 +201,30 @@ impl<'slice, 'input> EndMatcher for TokenIterator<'slice, 'input> {
     }
 }
 
+impl<'slice, 'input> TokenIterator<'slice, 'input> {
+    /// Returns `true` if the next peeked token may start attributes.
+    /// The peek index is not changed.
+    fn peeked_attributes_start(&mut self) -> bool {
+        let peek_index = self.peek_index();
+        let is_attributes_start = self
+            .peeking_next(|t| t.kind == TokenKind::OpenBrace)
+            .is_some();
+        self.set_peek_index(peek_index);
+        is_attributes_start
+    }
+
+    /// Returns `true` if the next peeked token may start a decoration (`===` title or `+++` caption).
+    /// The peek index is not changed.
+    fn peeked_decoration_start(&mut self) -> bool {
+        let peek_index = self.peek_index();
+        let is_decoration_start = self
+            .peeking_next(|t| matches!(t.kind, TokenKind::Equals(3) | TokenKind::Plus(3)))
+            .is_some();
+        self.set_peek_index(peek_index);
+        is_decoration_start
+    }
+}
+
 impl<'slice, 'input> PrefixMatcher for TokenIterator<'slice, 'input> {
     fn consumed_prefix(&mut self, sequence: &[TokenKind]) -> bool {
         debug_assert!(
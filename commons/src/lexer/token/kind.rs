@@ -3,6 +3,8 @@ use crate::lexer::symbol::SymbolKind;
 use super::implicit::ImplicitSubstitutionKind;
 
 pub const COMMENT_TOKEN_LEN: usize = 2;
+/// Keyword that opens and closes a comment.
+pub const COMMENT_KEYWORD: &str = ";;";
 
 /// The kind of the token found in Unimarkup document.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -24,6 +26,9 @@ pub enum TokenKind {
     Dot(usize),
     Ampersand(usize),
     Comma(usize),
+    GreaterThan(usize),
+    SingleQuote(usize),
+    Equals(usize),
 
     // parenthesis
     OpenParenthesis,
@@ -43,6 +48,10 @@ pub enum TokenKind {
     EscapedPlain,
     EscapedWhitespace,
     EscapedNewline,
+    /// Backslash directly before a blankline.
+    /// Blanklines are not escaped to get correct block-end matching,
+    /// so the blankline follows as separate [`TokenKind::Blankline`].
+    EscapedBlankline,
 
     // Plain
     #[default]
@@ -80,6 +89,7 @@ impl TokenKind {
                 | TokenKind::EscapedPlain
                 | TokenKind::EscapedWhitespace
                 | TokenKind::EscapedNewline
+                | TokenKind::EscapedBlankline
                 | TokenKind::Plain
                 | TokenKind::TerminalPunctuation
                 | TokenKind::Comment { .. }
@@ -142,6 +152,9 @@ impl From<TokenKind> for String {
             TokenKind::Dot(len) => SymbolKind::Dot.as_str().repeat(len),
             TokenKind::Ampersand(len) => SymbolKind::Ampersand.as_str().repeat(len),
             TokenKind::Comma(len) => SymbolKind::Comma.as_str().repeat(len),
+            TokenKind::GreaterThan(len) => SymbolKind::GreaterThan.as_str().repeat(len),
+            TokenKind::SingleQuote(len) => SymbolKind::SingleQuote.as_str().repeat(len),
+            TokenKind::Equals(len) => SymbolKind::Equals.as_str().repeat(len),
             TokenKind::OpenParenthesis => {
                 let mut s = String::with_capacity(SymbolKind::OpenParenthesis.as_str().len());
                 s.push_str(SymbolKind::OpenParenthesis.as_str());
@@ -178,16 +191,21 @@ impl From<TokenKind> for String {
                 s.push_str(SymbolKind::Newline.as_str());
                 s
             }
+            TokenKind::EscapedBlankline => {
+                let mut s = String::with_capacity(SymbolKind::Backslash.as_str().len());
+                s.push_str(SymbolKind::Backslash.as_str());
+                s
+            }
             TokenKind::Whitespace => {
                 let mut s = String::with_capacity(SymbolKind::Whitespace.as_str().len());
                 s.push_str(SymbolKind::Whitespace.as_str());
                 s
             }
+            TokenKind::ImplicitSubstitution(impl_subst) => impl_subst.orig().to_string(),
             TokenKind::Plain
             | TokenKind::TerminalPunctuation
             | TokenKind::EscapedPlain
             | TokenKind::EscapedWhitespace
-            | TokenKind::ImplicitSubstitution(_)
             | TokenKind::Comment { .. }
             | TokenKind::DirectUri
             | TokenKind::PossibleAttributes
@@ -230,9 +248,12 @@ impl From<SymbolKind> for TokenKind {
             SymbolKind::Quote => TokenKind::Quote(1),
             SymbolKind::Dollar => TokenKind::Dollar(1),
             SymbolKind::Colon => TokenKind::Colon(1),
-            SymbolKind::Dot => TokenKind::Colon(1),
+            SymbolKind::Dot => TokenKind::Dot(1),
             SymbolKind::Ampersand => TokenKind::Ampersand(1),
             SymbolKind::Comma => TokenKind::Comma(1),
+            SymbolKind::GreaterThan => TokenKind::GreaterThan(1),
+            SymbolKind::SingleQuote => TokenKind::SingleQuote(1),
+            SymbolKind::Equals => TokenKind::Equals(1),
             SymbolKind::OpenParenthesis => TokenKind::OpenParenthesis,
             SymbolKind::CloseParenthesis => TokenKind::CloseParenthesis,
             SymbolKind::OpenBracket => TokenKind::OpenBracket,
@@ -270,6 +291,9 @@ impl From<(SymbolKind, usize)> for TokenKind {
             SymbolKind::Dot => TokenKind::Dot(len),
             SymbolKind::Ampersand => TokenKind::Ampersand(len),
             SymbolKind::Comma => TokenKind::Comma(len),
+            SymbolKind::GreaterThan => TokenKind::GreaterThan(len),
+            SymbolKind::SingleQuote => TokenKind::SingleQuote(len),
+            SymbolKind::Equals => TokenKind::Equals(len),
             SymbolKind::OpenParenthesis => TokenKind::OpenParenthesis,
             SymbolKind::CloseParenthesis => TokenKind::CloseParenthesis,
             SymbolKind::OpenBracket => TokenKind::OpenBracket,
@@ -58,7 +58,11 @@ where
 impl From<&Token<'_>> for String {
     fn from(value: &Token<'_>) -> Self {
         match value.kind {
-            TokenKind::Plain | TokenKind::TerminalPunctuation | TokenKind::Whitespace => {
+            TokenKind::Plain
+            | TokenKind::TerminalPunctuation
+            | TokenKind::Whitespace
+            | TokenKind::Comment { .. }
+            | TokenKind::ImplicitSubstitution(_) => {
                 value.input[value.offset.start..value.offset.end].to_string()
             }
             TokenKind::EscapedPlain | TokenKind::EscapedWhitespace => {
@@ -120,7 +124,12 @@ pub fn lex_str<'input>(s: &'input str) -> Vec<Token<'input>> {
     let mut tokens: Vec<Token> = Vec::new();
 
     while let Some(symbol) = sym_iter.next() {
-        tokens.push(next_token(&mut sym_iter, *symbol))
+        let prev_kind = tokens.last().map(|token| token.kind);
+
+        match implicit::get_implicit(&mut sym_iter, *symbol, prev_kind) {
+            Some(implicit) => tokens.push(implicit),
+            None => tokens.push(next_token(&mut sym_iter, *symbol)),
+        }
     }
 
     tokens
@@ -143,8 +152,10 @@ fn next_token<'input>(
     match first_kind {
         SymbolKind::Eoi => token.kind = TokenKind::Eoi,
         SymbolKind::Plain => {
-            // Consume contiguous plain symbols
-            if let Some(last_symbol) = sym_iter.peeking_take_while(|s| s.kind == first_kind).last()
+            // Consume contiguous plain symbols, but keep arrows like `<--` separate
+            if let Some(last_symbol) = sym_iter
+                .peeking_take_while(|s| s.kind == first_kind && !implicit::is_arrow_start(s))
+                .last()
             {
                 // Consume peeked symbols without iterating over them again
                 sym_iter.set_index(sym_iter.peek_index());
@@ -157,6 +168,12 @@ fn next_token<'input>(
             // Kind is already set in From impl above.
         }
         SymbolKind::Backslash => {
+            if is_blankline_next(sym_iter) {
+                // Blankline is not escaped, and lexed as separate token after the backslash
+                token.kind = TokenKind::EscapedBlankline;
+                return token;
+            }
+
             let escaped_symbol_opt = sym_iter.next();
 
             match escaped_symbol_opt {
@@ -209,6 +226,10 @@ fn next_token<'input>(
 
         SymbolKind::TerminalPunctuation => {
             token.kind = TokenKind::TerminalPunctuation;
+
+            if let Some(comment) = make_comment(sym_iter, token) {
+                token = comment;
+            }
         }
 
         _ if first_kind.is_parenthesis() => {
@@ -243,6 +264,68 @@ fn next_token<'input>(
     token
 }
 
+/// Converts a [`TokenKind::TerminalPunctuation`] into a [`TokenKind::Comment`] if it starts a comment (`;;`).
+/// Comments are closed by another `;;`, or implicitly at the end of the line.
+fn make_comment<'input>(
+    sym_iter: &mut SymbolIterator<'_, 'input>,
+    mut token: Token<'input>,
+) -> Option<Token<'input>> {
+    if !token.input[token.offset.start..].starts_with(COMMENT_KEYWORD) {
+        return None;
+    }
+
+    // Second symbol of the opening keyword
+    let open_end = sym_iter.next()?;
+    token.offset.extend(open_end.offset);
+    token.end = open_end.end;
+
+    let mut implicit_close = true;
+
+    while let Some(symbol) = sym_iter.peek() {
+        if matches!(symbol.kind, SymbolKind::Newline | SymbolKind::Eoi) {
+            break;
+        }
+
+        let is_close = symbol.input[symbol.offset.start..].starts_with(COMMENT_KEYWORD);
+        let symbol = sym_iter.next()?;
+
+        if is_close {
+            let close_end = sym_iter.next()?;
+            token.offset.extend(close_end.offset);
+            token.end = close_end.end;
+            implicit_close = false;
+            break;
+        }
+
+        token.offset.extend(symbol.offset);
+        token.end = symbol.end;
+    }
+
+    token.kind = TokenKind::Comment { implicit_close };
+    Some(token)
+}
+
+/// Returns `true` if the next symbols form a blankline, without consuming them.
+fn is_blankline_next(sym_iter: &mut SymbolIterator<'_, '_>) -> bool {
+    let is_blankline = sym_iter
+        .peeking_take_while(|s| s.kind == SymbolKind::Newline)
+        .next()
+        .is_some()
+        && {
+            let _whitespaces = sym_iter
+                .peeking_take_while(|s| s.kind == SymbolKind::Whitespace)
+                .count();
+
+            matches!(
+                sym_iter.peek_kind(),
+                Some(SymbolKind::Newline | SymbolKind::Eoi)
+            )
+        };
+
+    sym_iter.reset_peek();
+    is_blankline
+}
+
 /// Converts a [`TokenKind::Newline`] into a [`TokenKind::Blankline`] if there are only whitespaces until the next [`TokenKind::Newline`].
 fn make_blankline<'input>(
     sym_iter: &mut SymbolIterator<'_, 'input>,
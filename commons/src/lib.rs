@@ -1,3 +1,4 @@
+pub mod attributes;
 pub mod config;
 pub mod lexer;
 pub mod parsing;
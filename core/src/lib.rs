@@ -4,6 +4,7 @@ pub use unimarkup_commons as commons;
 pub use unimarkup_inline as inline;
 pub use unimarkup_parser as parser;
 pub use unimarkup_render as render;
+use unimarkup_render::epub::render::render_epub;
 use unimarkup_render::pdf::render::render_pdf;
 
 use crate::commons::config::output::OutputFormatKind;
@@ -11,8 +12,14 @@ use crate::commons::config::Config;
 use crate::parser::document::Document;
 use crate::render::html::render::HtmlRenderer;
 use crate::render::html::Html;
+use crate::render::latex::render::LatexRenderer;
+use crate::render::latex::Latex;
 use crate::render::log_id::RenderError;
+use crate::render::markdown::render::MarkdownRenderer;
+use crate::render::markdown::Markdown;
 use crate::render::render::{OutputFormat, Renderer};
+use crate::render::txt::render::PlainTextRenderer;
+use crate::render::txt::PlainText;
 use crate::render::umi::render::UmiRenderer;
 use crate::render::umi::Umi;
 
@@ -62,6 +69,22 @@ impl Unimarkup {
         self.render(OutputFormatKind::Umi, UmiRenderer::default())
     }
 
+    pub fn render_markdown(&self) -> Result<Markdown, RenderError> {
+        self.render(OutputFormatKind::Markdown, MarkdownRenderer::default())
+    }
+
+    pub fn render_latex(&self) -> Result<Latex, RenderError> {
+        self.render(OutputFormatKind::Latex, LatexRenderer::default())
+    }
+
+    pub fn render_plain_text(&self) -> Result<PlainText, RenderError> {
+        self.render(OutputFormatKind::Txt, PlainTextRenderer::default())
+    }
+
+    pub fn render_epub(&self) -> Result<Vec<u8>, RenderError> {
+        render_epub(&self.render_html(false)?, &self.doc)
+    }
+
     pub fn render_pdf(&self) -> Result<Vec<u8>, RenderError> {
         render_pdf(&self.render_html(true)?.to_string())
     }
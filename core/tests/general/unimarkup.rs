@@ -12,3 +12,199 @@ pub fn compile_empty_content() {
 
     assert!(rendered_result.get_document().blocks.is_empty());
 }
+
+pub fn render_kept_comments() {
+    let mut cfg = Config::default();
+    cfg.output.formats.insert(OutputFormatKind::Html);
+    cfg.preamble.render.keep_comments = true;
+
+    let um = unimarkup_core::Unimarkup::parse(";; line comment\n\nSome ;; inline ;; text", cfg);
+    let html = um.render_html(false).unwrap().to_string();
+
+    assert!(
+        html.contains("<!-- line comment -->"),
+        "Comment block was not kept: {html}"
+    );
+    assert!(
+        html.contains("Some <!-- inline --> text"),
+        "Inline comment was not kept: {html}"
+    );
+}
+
+pub fn render_markdown() {
+    let mut cfg = Config::default();
+    cfg.output.formats.insert(OutputFormatKind::Markdown);
+
+    let um = unimarkup_core::Unimarkup::parse(
+        "# Heading\n\nSome **bold** and ‾over‾ text.\n\n+---+---+\n| a | b |\n+---+---+\n",
+        cfg,
+    );
+    let md = um.render_markdown().unwrap().to_string();
+
+    assert_eq!(
+        md,
+        "# Heading\n\nSome **bold** and <span style=\"text-decoration: overline;\">over</span> text.\n\n| | |\n| --- | --- |\n| a | b |\n"
+    );
+}
+
+pub fn render_latex() {
+    let mut cfg = Config::default();
+    cfg.output.formats.insert(OutputFormatKind::Latex);
+    cfg.preamble.metadata.title = Some("Some title".to_string());
+    cfg.preamble.metadata.authors.insert("Jane Doe".to_string());
+
+    let um = unimarkup_core::Unimarkup::parse(
+        "# Heading\n\nSome **bold** text with $$x^2$$ and 100%.\n\n- first\n- second\n\n```rust\nfn main() {}\n```\n",
+        cfg,
+    );
+    let latex = um.render_latex().unwrap().to_string();
+
+    for expected in [
+        "\\title{Some title}\n\\author{Jane Doe}",
+        "\\usepackage{minted}",
+        "\\begin{document}\n\n\\maketitle",
+        "\\section{Heading}\\label{heading}",
+        "Some \\textbf{bold} text with $x^2$ and 100\\%.",
+        "\\begin{itemize}\n\\item first\n\\item second\n\\end{itemize}",
+        "\\begin{minted}{rust}\nfn main() {}\n\\end{minted}",
+        "\\end{document}\n",
+    ] {
+        assert!(
+            latex.contains(expected),
+            "Rendered LaTeX does not contain '{expected}':\n{latex}"
+        );
+    }
+}
+
+pub fn render_plain_text() {
+    let mut cfg = Config::default();
+    cfg.output.formats.insert(OutputFormatKind::Txt);
+    cfg.preamble.render.text_width = Some(30);
+
+    let um = unimarkup_core::Unimarkup::parse(
+        "# Heading\n\n## Sub heading\n\nSome **bold** text --> with a [link](https://example.com) that is wrapped.\n\n- first\n\n  - nested entry with enough text to wrap\n",
+        cfg,
+    );
+    let text = um.render_plain_text().unwrap().to_string();
+
+    assert_eq!(
+        text,
+        "Heading\n=======\n\nSub heading\n-----------\n\nSome bold text ⟶ with a\nlink [1] that is wrapped.\n\n- first\n\n  - nested entry with enough\n    text to wrap\n\n[1] https://example.com\n"
+    );
+}
+
+pub fn render_epub() {
+    let mut cfg = Config::default();
+    cfg.output.formats.insert(OutputFormatKind::Epub);
+    cfg.preamble.metadata.title = Some("Some title".to_string());
+    cfg.preamble.metadata.authors.insert("Jane Doe".to_string());
+
+    let um = unimarkup_core::Unimarkup::parse(
+        "Preface with {<fake element>} & [link](<url>).\n\n# First\n\nText with footnote[^^note]_.\n\n## Section\n\n# Second & <third>\n\n_[^^note] Footnote content.\n",
+        cfg,
+    );
+    let epub = um.render_epub().unwrap();
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(epub)).unwrap();
+
+    assert_eq!(
+        archive.file_names().count(),
+        7,
+        "Unexpected files in EPUB: {:?}",
+        archive.file_names().collect::<Vec<_>>()
+    );
+
+    let mimetype = archive.by_index(0).unwrap();
+    assert_eq!(mimetype.name(), "mimetype");
+    assert_eq!(mimetype.compression(), zip::CompressionMethod::Stored);
+    drop(mimetype);
+
+    let mut read = |name: &str| {
+        let mut content = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name(name).unwrap(), &mut content).unwrap();
+        content
+    };
+
+    assert_eq!(read("mimetype"), "application/epub+zip");
+    assert!(read("META-INF/container.xml").contains("full-path=\"OEBPS/content.opf\""));
+
+    for (file, expected) in [
+        ("OEBPS/content.opf", "<dc:title>Some title</dc:title>\n<dc:creator>Jane Doe</dc:creator>"),
+        ("OEBPS/content.opf", "<spine>\n<itemref idref=\"chapter-1\"/>\n<itemref idref=\"chapter-2\"/>\n<itemref idref=\"chapter-3\"/>\n</spine>"),
+        ("OEBPS/nav.xhtml", "<li><a href=\"chapter-2.xhtml#first\">First</a><ol><li><a href=\"chapter-2.xhtml#section\">Section</a></li></ol></li><li><a href=\"chapter-3.xhtml#second-&amp;-&lt;third&gt;\">Second &amp; &lt;third&gt;</a></li>"),
+        ("OEBPS/chapter-1.xhtml", "<p>Preface with {&lt;fake element>} &amp; <a href='&lt;url>'>link</a>.</p>"),
+        ("OEBPS/chapter-2.xhtml", "<title>First</title>"),
+        ("OEBPS/chapter-2.xhtml", "<a href='chapter-3.xhtml#fn-1' id='fnref-1'>1</a>"),
+        ("OEBPS/chapter-3.xhtml", "<title>Second &amp; &lt;third&gt;</title>"),
+        ("OEBPS/chapter-3.xhtml", "<hr style=\"width: 25%; margin-left: 0\"/>"),
+        ("OEBPS/chapter-3.xhtml", "<a href='chapter-2.xhtml#fnref-1'>"),
+    ] {
+        let content = read(file);
+        assert!(
+            content.contains(expected),
+            "'{file}' does not contain '{expected}':\n{content}"
+        );
+    }
+
+    for file in [
+        "META-INF/container.xml",
+        "OEBPS/content.opf",
+        "OEBPS/nav.xhtml",
+        "OEBPS/chapter-1.xhtml",
+        "OEBPS/chapter-2.xhtml",
+        "OEBPS/chapter-3.xhtml",
+    ] {
+        let content = read(file);
+        let options = roxmltree::ParsingOptions {
+            allow_dtd: true,
+            ..Default::default()
+        };
+        if let Err(err) = roxmltree::Document::parse_with_options(&content, options) {
+            panic!("'{file}' is no well-formed XML: {err}\n{content}");
+        }
+    }
+}
+
+pub fn render_epub_with_images() {
+    let dir = std::env::temp_dir().join("unimarkup-epub-images");
+    std::fs::create_dir_all(&dir).unwrap();
+    let image = b"\x89PNG\r\n\x1a\nnot really a png";
+    std::fs::write(dir.join("logo.png"), image).unwrap();
+
+    let mut cfg = Config {
+        input: dir.join("images.um"),
+        ..Default::default()
+    };
+    cfg.output.formats.insert(OutputFormatKind::Epub);
+
+    let um = unimarkup_core::Unimarkup::parse("!!![Logo](logo.png)\n", cfg);
+    let epub = um.render_epub().unwrap();
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(epub)).unwrap();
+
+    let mut packaged = Vec::new();
+    std::io::Read::read_to_end(
+        &mut archive.by_name("OEBPS/images/image-1.png").unwrap(),
+        &mut packaged,
+    )
+    .unwrap();
+    assert_eq!(packaged, image, "Packaged image differs from the original.");
+
+    let mut read = |name: &str| {
+        let mut content = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name(name).unwrap(), &mut content).unwrap();
+        content
+    };
+
+    for (file, expected) in [
+        (
+            "OEBPS/content.opf",
+            "href=\"images/image-1.png\" media-type=\"image/png\"/>",
+        ),
+        ("OEBPS/chapter-1.xhtml", "src='images/image-1.png'"),
+    ] {
+        let content = read(file);
+        assert!(
+            content.contains(expected),
+            "'{file}' does not contain '{expected}':\n{content}"
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use super::Snapshot;
+use unimarkup_commons::test_runner::as_snapshot::AsSnapshot;
+use unimarkup_inline::element::InlineElement;
+use unimarkup_parser::elements::definitions::AbbreviationDefinition;
+
+impl AsSnapshot for Snapshot<&AbbreviationDefinition> {
+    fn as_snapshot(&self) -> String {
+        let expansion: String = self
+            .expansion
+            .as_unimarkup()
+            .lines()
+            .fold(String::new(), |s, line| s + "  " + line + "\n");
+
+        format!("AbbreviationDefinition({}\n{expansion})", self.abbreviation)
+    }
+}
@@ -36,6 +36,16 @@ impl AsSnapshot for Snapshot<&BulletListEntry> {
         } else {
             format!("  EntryHeading({entry_heading})")
         };
+        let entry_heading = match (self.task, self.task_status()) {
+            (Some(task), Some(status)) => {
+                format!(
+                    "  Task({}, {})\n{entry_heading}",
+                    task.as_str(),
+                    status.as_str()
+                )
+            }
+            _ => entry_heading,
+        };
 
         if self.body.is_empty() {
             format!("BulletListEntry(\n{entry_heading}\n)")
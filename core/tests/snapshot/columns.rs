@@ -0,0 +1,30 @@
+use super::Snapshot;
+use unimarkup_commons::test_runner::as_snapshot::AsSnapshot;
+use unimarkup_parser::elements::enclosed::Columns;
+
+impl AsSnapshot for Snapshot<&Columns> {
+    fn as_snapshot(&self) -> String {
+        let columns: String = self
+            .columns
+            .iter()
+            .map(|column| {
+                let content: String = column
+                    .iter()
+                    .map(|block| Snapshot(block).as_snapshot() + "\n")
+                    .collect();
+
+                let content: String = content
+                    .lines()
+                    .fold(String::new(), |s, line| s + "  " + line + "\n");
+
+                format!("Column(\n{content})\n")
+            })
+            .collect();
+
+        let columns: String = columns
+            .lines()
+            .fold(String::new(), |s, line| s + "  " + line + "\n");
+
+        format!("Columns(\n{columns})")
+    }
+}
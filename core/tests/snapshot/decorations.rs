@@ -0,0 +1,24 @@
+use super::Snapshot;
+use unimarkup_commons::test_runner::as_snapshot::AsSnapshot;
+use unimarkup_inline::element::InlineElement;
+use unimarkup_parser::elements::decorations::Decorations;
+
+impl AsSnapshot for Snapshot<&Decorations> {
+    fn as_snapshot(&self) -> String {
+        let mut s = String::new();
+
+        if let Some(number) = self.number {
+            s.push_str(&format!("Number({number})\n"));
+        }
+
+        if let Some(title) = &self.title {
+            s.push_str(&format!("Title({})\n", title.as_unimarkup()));
+        }
+
+        if let Some(caption) = &self.caption {
+            s.push_str(&format!("Caption({})\n", caption.as_unimarkup()));
+        }
+
+        s
+    }
+}
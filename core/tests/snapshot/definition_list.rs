@@ -0,0 +1,46 @@
+use super::Snapshot;
+use unimarkup_commons::test_runner::as_snapshot::AsSnapshot;
+use unimarkup_inline::element::InlineElement;
+use unimarkup_parser::elements::indents::{DefinitionList, DefinitionListEntry};
+
+impl AsSnapshot for Snapshot<&DefinitionList> {
+    fn as_snapshot(&self) -> String {
+        let content: String = self
+            .entries
+            .iter()
+            .map(|entry| Snapshot(entry).as_snapshot() + "\n")
+            .collect();
+
+        let content: String = content
+            .lines()
+            .fold(String::new(), |s, line| s + "  " + line + "\n");
+
+        format!("DefinitionList(\n{content})")
+    }
+}
+
+impl AsSnapshot for Snapshot<&DefinitionListEntry> {
+    fn as_snapshot(&self) -> String {
+        let mut content = format!("  Term({})\n", self.term.as_unimarkup());
+
+        if let Some(classifier) = &self.classifier {
+            content.push_str(&format!("  Classifier({})\n", classifier.as_unimarkup()));
+        }
+
+        if !self.description.is_empty() {
+            let description: String = self
+                .description
+                .iter()
+                .map(|block| Snapshot(block).as_snapshot() + "\n")
+                .collect();
+
+            let description: String = description
+                .lines()
+                .fold(String::new(), |s, line| s + "    " + line + "\n");
+
+            content.push_str(&format!("  Description(\n{description}  )\n"));
+        }
+
+        format!("DefinitionListEntry(\n{content})")
+    }
+}
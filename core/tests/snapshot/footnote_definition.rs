@@ -0,0 +1,19 @@
+use super::Snapshot;
+use unimarkup_commons::test_runner::as_snapshot::AsSnapshot;
+use unimarkup_parser::elements::definitions::FootnoteDefinition;
+
+impl AsSnapshot for Snapshot<&FootnoteDefinition> {
+    fn as_snapshot(&self) -> String {
+        let content: String = self
+            .content
+            .iter()
+            .map(|block| Snapshot(block).as_snapshot() + "\n")
+            .collect();
+
+        let content: String = content
+            .lines()
+            .fold(String::new(), |s, line| s + "  " + line + "\n");
+
+        format!("FootnoteDefinition({}\n{content})", self.id)
+    }
+}
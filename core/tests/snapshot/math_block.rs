@@ -0,0 +1,17 @@
+use super::Snapshot;
+use unimarkup_commons::test_runner::as_snapshot::AsSnapshot;
+use unimarkup_parser::elements::enclosed::MathBlock;
+
+impl AsSnapshot for Snapshot<&MathBlock> {
+    fn as_snapshot(&self) -> String {
+        let content: String = self
+            .content
+            .lines()
+            .fold(String::new(), |s, line| s + "\t" + line + "\n");
+
+        match &self.id {
+            Some(id) => format!("MathBlock({id}\n{content})"),
+            None => format!("MathBlock(\n{content})"),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use super::Snapshot;
+use unimarkup_commons::test_runner::as_snapshot::AsSnapshot;
+use unimarkup_parser::elements::inserts::MediaInsert;
+
+impl AsSnapshot for Snapshot<&MediaInsert> {
+    fn as_snapshot(&self) -> String {
+        let mut s = format!(
+            "MediaInsert-{}({})(\n  Source({})\n",
+            self.kind.as_str(),
+            self.alt,
+            self.source
+        );
+
+        for line in Snapshot(&self.decorations).as_snapshot().lines() {
+            s.push_str(&format!("  {line}\n"));
+        }
+
+        s.push(')');
+        s
+    }
+}
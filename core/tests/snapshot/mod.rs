@@ -3,11 +3,24 @@ use std::ops::Deref;
 use unimarkup_commons::lexer::token::TokenKind;
 use unimarkup_commons::test_runner::as_snapshot::AsSnapshot;
 use unimarkup_parser::elements::blocks::Block;
-use unimarkup_parser::elements::Blocks;
+use unimarkup_parser::elements::{BlockElement, Blocks};
 
+mod abbreviation_definition;
 mod bullet_list;
+mod columns;
+mod decorations;
+mod definition_list;
+mod footnote_definition;
 mod heading;
+mod line_block;
+mod math_block;
+mod media_insert;
+mod numbered_list;
 mod paragraph;
+mod quotation;
+mod render_block;
+mod table;
+mod text_block;
 mod verbatim_block;
 
 #[derive(Debug)]
@@ -40,13 +53,34 @@ impl AsSnapshot for Snapshot<Blocks> {
 
 impl AsSnapshot for Snapshot<&Block> {
     fn as_snapshot(&self) -> String {
-        match **self {
+        let snapshot = match **self {
             Block::Paragraph(block) => Snapshot(block).as_snapshot(),
             Block::BulletList(block) => Snapshot(block).as_snapshot(),
+            Block::NumberedList(block) => Snapshot(block).as_snapshot(),
             Block::VerbatimBlock(block) => Snapshot(block).as_snapshot(),
+            Block::DefinitionList(block) => Snapshot(block).as_snapshot(),
+            Block::Table(block) => Snapshot(block).as_snapshot(),
+            Block::Quotation(block) => Snapshot(block).as_snapshot(),
+            Block::LineBlock(block) => Snapshot(block).as_snapshot(),
+            Block::MathBlock(block) => Snapshot(block).as_snapshot(),
+            Block::Columns(block) => Snapshot(block).as_snapshot(),
+            Block::TextBlock(block) => Snapshot(block).as_snapshot(),
+            Block::MediaInsert(block) => Snapshot(block).as_snapshot(),
+            Block::RenderBlock(block) => Snapshot(block).as_snapshot(),
+            Block::FootnoteDefinition(block) => Snapshot(block).as_snapshot(),
+            Block::AbbreviationDefinition(block) => Snapshot(block).as_snapshot(),
             Block::Heading(block) => Snapshot(block).as_snapshot(),
+            Block::HorizontalLine(block) => format!("HorizontalLine({})", block.as_unimarkup()),
+            Block::LineBreak(block) => format!("LineBreak({})", block.as_unimarkup()),
+            Block::PageBreak(block) => format!("PageBreak({})", block.as_unimarkup()),
+            Block::Comment(block) => format!("Comment({})", block.as_unimarkup()),
             Block::Blankline(_) => String::from(TokenKind::Blankline),
             _ => unimplemented!("TODO: Implement snapshot for {:?}", self),
+        };
+
+        match self.attributes() {
+            Some(attributes) => format!("{snapshot}\nAttributes: {}", attributes.as_unimarkup()),
+            None => snapshot,
         }
     }
 }
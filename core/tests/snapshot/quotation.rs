@@ -0,0 +1,24 @@
+use super::Snapshot;
+use unimarkup_commons::test_runner::as_snapshot::AsSnapshot;
+use unimarkup_inline::element::InlineElement;
+use unimarkup_parser::elements::indents::Quotation;
+
+impl AsSnapshot for Snapshot<&Quotation> {
+    fn as_snapshot(&self) -> String {
+        let content: String = self
+            .content
+            .iter()
+            .map(|block| Snapshot(block).as_snapshot() + "\n")
+            .collect();
+
+        let mut content: String = content
+            .lines()
+            .fold(String::new(), |s, line| s + "  " + line + "\n");
+
+        if let Some(author) = &self.author {
+            content.push_str(&format!("  Author({})\n", author.as_unimarkup()));
+        }
+
+        format!("Quotation(\n{content})")
+    }
+}
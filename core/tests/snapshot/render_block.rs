@@ -0,0 +1,14 @@
+use super::Snapshot;
+use unimarkup_commons::test_runner::as_snapshot::AsSnapshot;
+use unimarkup_parser::elements::enclosed::RenderBlock;
+
+impl AsSnapshot for Snapshot<&RenderBlock> {
+    fn as_snapshot(&self) -> String {
+        let content: String = self
+            .content
+            .lines()
+            .fold(String::new(), |s, line| s + "\t" + line + "\n");
+
+        format!("RenderBlock({}\n{content})", self.lang)
+    }
+}
@@ -0,0 +1,53 @@
+use super::Snapshot;
+use unimarkup_commons::test_runner::as_snapshot::AsSnapshot;
+use unimarkup_parser::elements::enclosed::{Table, TableCell, TableRow};
+
+impl AsSnapshot for Snapshot<&Table> {
+    fn as_snapshot(&self) -> String {
+        let mut content = Snapshot(&self.decorations).as_snapshot();
+
+        for row in &self.rows {
+            content.push_str(&Snapshot(row).as_snapshot());
+            content.push('\n');
+        }
+
+        let content: String = content
+            .lines()
+            .fold(String::new(), |s, line| s + "  " + line + "\n");
+        format!("Table(columns: {}\n{content})", self.column_count)
+    }
+}
+
+impl AsSnapshot for Snapshot<&TableRow> {
+    fn as_snapshot(&self) -> String {
+        let mut content = String::new();
+
+        for cell in &self.cells {
+            content.push_str(&Snapshot(cell).as_snapshot());
+            content.push('\n');
+        }
+
+        let content: String = content
+            .lines()
+            .fold(String::new(), |s, line| s + "  " + line + "\n");
+        format!("TableRow(\n{content})")
+    }
+}
+
+impl AsSnapshot for Snapshot<&TableCell> {
+    fn as_snapshot(&self) -> String {
+        let content: String = self
+            .content
+            .iter()
+            .map(|block| Snapshot(block).as_snapshot() + "\n")
+            .collect();
+
+        let content: String = content
+            .lines()
+            .fold(String::new(), |s, line| s + "  " + line + "\n");
+        format!(
+            "TableCell({:?}, {:?}, colspan: {}, rowspan: {}\n{content})",
+            self.kind, self.alignment, self.column_span, self.row_span
+        )
+    }
+}
@@ -0,0 +1,19 @@
+use super::Snapshot;
+use unimarkup_commons::test_runner::as_snapshot::AsSnapshot;
+use unimarkup_parser::elements::enclosed::TextBlock;
+
+impl AsSnapshot for Snapshot<&TextBlock> {
+    fn as_snapshot(&self) -> String {
+        let content: String = self
+            .content
+            .iter()
+            .map(|block| Snapshot(block).as_snapshot() + "\n")
+            .collect();
+
+        let content: String = content
+            .lines()
+            .fold(String::new(), |s, line| s + "  " + line + "\n");
+
+        format!("TextBlock(\n{content})")
+    }
+}
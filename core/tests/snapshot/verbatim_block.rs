@@ -5,12 +5,14 @@ use unimarkup_parser::elements::enclosed::VerbatimBlock;
 impl AsSnapshot for Snapshot<&VerbatimBlock> {
     fn as_snapshot(&self) -> String {
         let content = &self.content;
+        let decorations = Snapshot(&self.decorations).as_snapshot();
 
         let is_multiline = content.lines().count() > 1;
 
-        if is_multiline {
-            let content: String = content
+        if is_multiline || !decorations.is_empty() {
+            let content: String = decorations
                 .lines()
+                .chain(content.lines())
                 .fold(String::new(), |s, line| s + "\t" + line + "\n");
             format!("VerbatimBlock(\n{content})")
         } else {
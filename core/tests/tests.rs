@@ -33,5 +33,11 @@ fn collect_tests() -> impl IntoIterator<Item = libtest_mimic::Trial> {
     [
         test_fn!(general::metadata::create_metadata_from_memory),
         test_fn!(general::unimarkup::compile_empty_content),
+        test_fn!(general::unimarkup::render_kept_comments),
+        test_fn!(general::unimarkup::render_markdown),
+        test_fn!(general::unimarkup::render_latex),
+        test_fn!(general::unimarkup::render_plain_text),
+        test_fn!(general::unimarkup::render_epub),
+        test_fn!(general::unimarkup::render_epub_with_images),
     ]
 }
@@ -1,6 +1,9 @@
 //! Contains the inline parser for base elements like plain text, whitespace, or implicit substitution.
 
-use unimarkup_commons::lexer::{position::Position, token::iterator::Itertools};
+use unimarkup_commons::lexer::{
+    position::Position,
+    token::{iterator::Itertools, COMMENT_KEYWORD},
+};
 
 use crate::{
     element::InlineElement,
@@ -56,6 +59,9 @@ pub(crate) fn parse_base<'s, 'i>(
         // Keyword did not lead to inline element in inline parser => convert token to plain
         next.kind = InlineTokenKind::Plain;
         parser.iter.set_prev_token(next); // update prev token, because next changed afterwards
+    } else if parser.context.flags.logic_only && matches!(kind, InlineTokenKind::Comment { .. }) {
+        // Comments are kept as written in logic-only content (e.g. verbatim)
+        next.kind = InlineTokenKind::Plain;
     } else if !parser.context.flags.allow_implicits
         && matches!(
             kind,
@@ -115,6 +121,12 @@ fn to_inline(context: &InlineContext, token: InlineToken<'_>) -> Inline {
         InlineTokenKind::ImplicitSubstitution(subst) => {
             Inline::ImplicitSubstitution(ImplicitSubstitution::new(subst, token.start, token.end))
         }
+        InlineTokenKind::Comment { implicit_close } => Inline::Comment(Comment::new(
+            token.as_str().to_string(),
+            implicit_close,
+            token.start,
+            token.end,
+        )),
         _ => {
             debug_assert!(
                 matches!(
@@ -191,6 +203,65 @@ base_inlines!(
     EscapedNewline
 );
 
+/// Represents a comment (e.g. `;; some comment ;;`).
+/// Comments without closing `;;` end at the end of the line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    /// The content between the comment keywords.
+    content: String,
+    /// Marks that this comment was implicitly closed at the end of the line.
+    implicit_close: bool,
+    start: Position,
+    end: Position,
+}
+
+impl Comment {
+    pub fn new(content: String, implicit_close: bool, start: Position, end: Position) -> Self {
+        Self {
+            content,
+            implicit_close,
+            start,
+            end,
+        }
+    }
+
+    /// Returns the content between the comment keywords.
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Returns `true` if this comment was implicitly closed at the end of the line.
+    pub fn implicit_close(&self) -> bool {
+        self.implicit_close
+    }
+}
+
+impl From<Comment> for Inline {
+    fn from(value: Comment) -> Self {
+        Inline::Comment(value)
+    }
+}
+
+impl InlineElement for Comment {
+    fn as_unimarkup(&self) -> String {
+        let close = if self.implicit_close {
+            ""
+        } else {
+            COMMENT_KEYWORD
+        };
+
+        format!("{COMMENT_KEYWORD}{}{close}", self.content)
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
 impl Plain {
     pub(crate) fn push_token(&mut self, token: InlineToken<'_>) {
         debug_assert!(
@@ -1,8 +1,11 @@
 //! Contains the parser for ambiguous formats like [`Bold`](super::Bold) and [`Italic`](super::Italic), or [`Underline`](super::Underline) and [`Subscript`](super::Subscript).
 
-use unimarkup_commons::lexer::{
-    position::{Offset, Position},
-    token::iterator::PeekingNext,
+use unimarkup_commons::{
+    attributes::Attributes,
+    lexer::{
+        position::{Offset, Position},
+        token::iterator::PeekingNext,
+    },
 };
 
 use crate::{
@@ -92,8 +95,12 @@ fn resolve_closing<'slice, 'input>(
                         .next()
                         .expect("Peeked before, so `next` must return Some.");
 
-                    // check for optional attributes here
-                    (None, close_token.end, false)
+                    match crate::element::helper::parse_attributes(&mut parser.iter) {
+                        Some((attributes, attributes_end)) => {
+                            (Some(attributes), attributes_end, false)
+                        }
+                        None => (None, close_token.end, false),
+                    }
                 } else {
                     // e.g. bold implicitly closed by italic close: *italic **b+i*
                     (None, close_token.start, true)
@@ -214,8 +221,10 @@ fn resolve_closing<'slice, 'input>(
                     close_token.kind,
                     updated_open.kind
                 );
-                // check for optional attributes here
-                (None, close_token.end)
+                match crate::element::helper::parse_attributes(&mut parser.iter) {
+                    Some((attributes, attributes_end)) => (Some(attributes), attributes_end),
+                    None => (None, close_token.end),
+                }
             };
 
             return (
@@ -254,7 +263,7 @@ fn to_inline<'input>(
     open_token: InlineToken<'input>,
     input: &mut InlineTokenIterator<'_, 'input>,
     inner: Vec<Inline>,
-    attributes: Option<Vec<Inline>>,
+    attributes: Option<Attributes>,
     end: Position,
     implicit_end: bool,
 ) -> Inline {
@@ -1,6 +1,9 @@
 //! Contains formatting elements like [`Bold`], [`Italic`], [`Underline`], ...
 
-use unimarkup_commons::lexer::{position::Position, token::iterator::PeekingNext};
+use unimarkup_commons::{
+    attributes::Attributes,
+    lexer::{position::Position, token::iterator::PeekingNext},
+};
 
 use crate::{element::InlineElement, parser::InlineParser, tokenize::kind::InlineTokenKind};
 
@@ -33,7 +36,7 @@ pub(crate) fn parse_distinct_format<'s, 'i>(
     let (updated_parser, inner) = InlineParser::parse(parser);
     parser = updated_parser;
 
-    let attributes = None;
+    let mut attributes = None;
     let mut implicit_end = true;
 
     // Only consuming token on open/close match, because closing token might be reserved for an outer open format.
@@ -45,8 +48,13 @@ pub(crate) fn parse_distinct_format<'s, 'i>(
                 .expect("Peeked before, so `next` must return Some.");
             implicit_end = false;
 
-            //TODO: check for optional attributes here
-            close_token.end
+            match super::helper::parse_attributes(&mut parser.iter) {
+                Some((attrs, attrs_end)) => {
+                    attributes = Some(attrs);
+                    attrs_end
+                }
+                None => close_token.end,
+            }
         } else {
             close_token.start
         }
@@ -79,7 +87,7 @@ macro_rules! inline_formats {
         #[derive(Debug, Clone, PartialEq, Eq)]
         pub struct $format {
             inner: Vec<Inline>,
-            attributes: Option<Vec<Inline>>,
+            attributes: Option<Attributes>,
             start: Position,
             end: Position,
             implicit_end: bool,
@@ -93,7 +101,13 @@ macro_rules! inline_formats {
 
         impl InlineElement for $format {
             fn as_unimarkup(&self) -> String {
-                format!("{}{}{}", InlineTokenKind::$format.as_str(), self.inner.as_unimarkup(), if self.implicit_end {""} else {InlineTokenKind::$format.as_str()})
+                format!(
+                    "{}{}{}{}",
+                    InlineTokenKind::$format.as_str(),
+                    self.inner.as_unimarkup(),
+                    if self.implicit_end {""} else {InlineTokenKind::$format.as_str()},
+                    self.attributes.as_ref().map(Attributes::as_unimarkup).unwrap_or_default()
+                )
             }
 
             fn start(&self) -> Position {
@@ -108,7 +122,7 @@ macro_rules! inline_formats {
         impl $format {
             pub fn new(
                 inner: Vec<Inline>,
-                attributes: Option<Vec<Inline>>,
+                attributes: Option<Attributes>,
                 start: Position,
                 end: Position,
                 implicit_end: bool,
@@ -126,7 +140,7 @@ macro_rules! inline_formats {
                 &self.inner
             }
 
-            pub fn attributes(&self) -> Option<&Vec<Inline>> {
+            pub fn attributes(&self) -> Option<&Attributes> {
                 self.attributes.as_ref()
             }
 
@@ -142,7 +156,7 @@ macro_rules! format_to_inline{
         pub(crate) fn to_formatting(
             kind: InlineTokenKind,
             inner: Vec<Inline>,
-            attributes: Option<Vec<Inline>>,
+            attributes: Option<Attributes>,
             start: Position,
             end: Position,
             implicit_end: bool,
@@ -47,8 +47,10 @@ macro_rules! scoped_parser {
             );
 
             let (attributes, end, implicit_end) = if end_reached {
-                //TODO: Check for optional attributes here
-                (None, prev_token.end, false)
+                match $crate::element::helper::parse_attributes(&mut parser.iter) {
+                    Some((attributes, attributes_end)) => (Some(attributes), attributes_end, false),
+                    None => (None, prev_token.end, false),
+                }
             } else {
                 (
                     None,
@@ -1,8 +1,8 @@
 //! Contains helper functions for inline parsing.
 
-use unimarkup_commons::lexer::position::Position;
+use unimarkup_commons::{attributes::Attributes, lexer::position::Position};
 
-use crate::tokenize::{kind::InlineTokenKind, InlineToken};
+use crate::tokenize::{iterator::InlineTokenIterator, kind::InlineTokenKind, InlineToken};
 
 /// Returns the end position for implicitly closed elements.
 pub(super) fn implicit_end_using_prev(prev_token: &InlineToken<'_>) -> Position {
@@ -14,3 +14,53 @@ pub(super) fn implicit_end_using_prev(prev_token: &InlineToken<'_>) -> Position
         _ => prev_token.end,
     }
 }
+
+/// Tries to parse attributes (e.g. `{ "id" : "my-id" }`) directly following an inline element.
+///
+/// Returns the attributes, and the end of the closing brace.
+/// The iterator is not advanced if no valid attributes follow.
+pub(super) fn parse_attributes(
+    iter: &mut InlineTokenIterator<'_, '_>,
+) -> Option<(Attributes, Position)> {
+    if iter.peek_kind() != Some(InlineTokenKind::OpenBrace) {
+        return None;
+    }
+
+    let checkpoint = iter.checkpoint();
+    let mut attrb_tokens = Vec::new();
+    let mut depth = 0usize;
+
+    for token in iter.by_ref() {
+        attrb_tokens.push(token);
+
+        match token.kind {
+            InlineTokenKind::OpenBrace => depth += 1,
+            InlineTokenKind::CloseBrace => {
+                depth -= 1;
+
+                if depth == 0 {
+                    break;
+                }
+            }
+            InlineTokenKind::Newline | InlineTokenKind::EscapedNewline | InlineTokenKind::Eoi => {
+                break
+            }
+            _ => {}
+        }
+    }
+
+    let attributes = match attrb_tokens.last() {
+        Some(close_token) if depth == 0 && close_token.kind == InlineTokenKind::CloseBrace => {
+            InlineToken::flatten(&attrb_tokens)
+                .and_then(|raw| Attributes::try_from(raw).ok())
+                .map(|attributes| (attributes, close_token.end))
+        }
+        _ => None,
+    };
+
+    if attributes.is_none() {
+        iter.rollback(checkpoint);
+    }
+
+    attributes
+}
@@ -2,18 +2,24 @@
 
 use crate::element::substitution::DistinctReference;
 use unimarkup_commons::{
+    attributes::Attributes,
     lexer::{position::Position, span::Span},
     parsing::Element,
 };
 
 use self::{
-    base::{EscapedNewline, EscapedPlain, EscapedWhitespace, Newline, Plain},
+    base::{Comment, EscapedNewline, EscapedPlain, EscapedWhitespace, Newline, Plain},
     formatting::{
         Bold, Highlight, Italic, Math, Overline, Quote, Strikethrough, Subscript, Superscript,
         Underline, Verbatim,
     },
-    substitution::{named::NamedSubstitution, DirectUri, ImplicitSubstitution},
-    textbox::{citation::Citation, hyperlink::Hyperlink, TextBox},
+    substitution::{
+        direct::DirectUnicode, named::NamedSubstitution, DirectUri, ImplicitSubstitution,
+    },
+    textbox::{
+        abbreviation::AbbreviationReference, citation::Citation, footnote::FootnoteReference,
+        hyperlink::Hyperlink, reference::Reference, TextBox,
+    },
 };
 
 mod helper;
@@ -96,9 +102,19 @@ pub enum Inline {
     /// Citation element `[&&cite-id]`
     Citation(Citation),
 
+    /// Footnote reference element `[^^footnote-id]_`
+    FootnoteReference(FootnoteReference),
+    /// Abbreviation reference element `[::abbr]_`
+    AbbreviationReference(AbbreviationReference),
+    /// Cross-reference element `[##element-id]_`
+    Reference(Reference),
+
     /// Named substitution ( i.e. `::heart::`).
     NamedSubstitution(NamedSubstitution),
 
+    /// Direct Unicode substitution (i.e. `&U+1F642;`).
+    DirectUnicode(DirectUnicode),
+
     /// Verbatim (monospaced) content.
     Verbatim(Verbatim),
 
@@ -129,6 +145,9 @@ pub enum Inline {
 
     /// Distinct reference
     DistinctReference(DistinctReference),
+
+    /// Comment that is only rendered if comments should be kept.
+    Comment(Comment),
 }
 
 impl Inline {
@@ -136,6 +155,41 @@ impl Inline {
         matches!(self, Inline::Plain(_))
     }
 
+    /// Returns the attributes of this inline, if attributes were set.
+    pub fn attributes(&self) -> Option<&Attributes> {
+        match self {
+            Inline::Bold(inline) => inline.attributes(),
+            Inline::Italic(inline) => inline.attributes(),
+            Inline::Underline(inline) => inline.attributes(),
+            Inline::Subscript(inline) => inline.attributes(),
+            Inline::Superscript(inline) => inline.attributes(),
+            Inline::Overline(inline) => inline.attributes(),
+            Inline::Strikethrough(inline) => inline.attributes(),
+            Inline::Highlight(inline) => inline.attributes(),
+            Inline::Quote(inline) => inline.attributes(),
+            Inline::Math(inline) => inline.attributes(),
+            Inline::Verbatim(inline) => inline.attributes(),
+            Inline::TextBox(inline) => inline.attributes(),
+            Inline::Hyperlink(inline) => inline.attributes(),
+            Inline::AbbreviationReference(inline) => inline.attributes(),
+            Inline::Citation(_)
+            | Inline::FootnoteReference(_)
+            | Inline::Reference(_)
+            | Inline::NamedSubstitution(_)
+            | Inline::DirectUnicode(_)
+            | Inline::Newline(_)
+            | Inline::ImplicitNewline(_)
+            | Inline::EscapedNewline(_)
+            | Inline::EscapedWhitespace(_)
+            | Inline::Plain(_)
+            | Inline::EscapedPlain(_)
+            | Inline::ImplicitSubstitution(_)
+            | Inline::DirectUri(_)
+            | Inline::DistinctReference(_)
+            | Inline::Comment(_) => None,
+        }
+    }
+
     pub fn variant_str(&self) -> &'static str {
         match self {
             Inline::Bold(_) => "Bold",
@@ -151,6 +205,9 @@ impl Inline {
             Inline::TextBox(_) => "TextBox",
             Inline::Hyperlink(_) => "Hyperlink",
             Inline::Citation(_) => "Citation",
+            Inline::FootnoteReference(_) => "FootnoteReference",
+            Inline::Reference(_) => "Reference",
+            Inline::AbbreviationReference(_) => "AbbreviationReference",
             Inline::Verbatim(_) => "Verbatim",
             Inline::Newline(_) => "Newline",
             Inline::ImplicitNewline(_) => "ImplicitNewline",
@@ -160,8 +217,10 @@ impl Inline {
             Inline::EscapedPlain(_) => "EscapedPlain",
             Inline::DirectUri(_) => "DirectUri",
             Inline::NamedSubstitution(_) => "NamedSubstitution",
+            Inline::DirectUnicode(_) => "DirectUnicode",
             Inline::ImplicitSubstitution(_) => "ImplicitSubstitution",
             Inline::DistinctReference(_) => "DistinctReference",
+            Inline::Comment(_) => "Comment",
         }
     }
 }
@@ -182,6 +241,9 @@ impl InlineElement for Inline {
             Inline::TextBox(inline) => inline.as_unimarkup(),
             Inline::Hyperlink(inline) => inline.as_unimarkup(),
             Inline::Citation(inline) => inline.as_unimarkup(),
+            Inline::FootnoteReference(inline) => inline.as_unimarkup(),
+            Inline::Reference(inline) => inline.as_unimarkup(),
+            Inline::AbbreviationReference(inline) => inline.as_unimarkup(),
             Inline::Verbatim(inline) => inline.as_unimarkup(),
             Inline::Newline(inline) => inline.as_unimarkup(),
             Inline::ImplicitNewline(inline) => inline.as_unimarkup(),
@@ -192,8 +254,10 @@ impl InlineElement for Inline {
             Inline::DirectUri(inline) => inline.as_unimarkup(),
             Inline::ImplicitSubstitution(inline) => inline.as_unimarkup(),
             Inline::DistinctReference(inline) => inline.as_unimarkup(),
+            Inline::Comment(inline) => inline.as_unimarkup(),
 
-            Inline::NamedSubstitution(_) => todo!(),
+            Inline::NamedSubstitution(inline) => inline.as_unimarkup(),
+            Inline::DirectUnicode(inline) => inline.as_unimarkup(),
         }
     }
 
@@ -212,6 +276,9 @@ impl InlineElement for Inline {
             Inline::TextBox(inline) => inline.start(),
             Inline::Hyperlink(inline) => inline.start(),
             Inline::Citation(inline) => inline.start(),
+            Inline::FootnoteReference(inline) => inline.start(),
+            Inline::Reference(inline) => inline.start(),
+            Inline::AbbreviationReference(inline) => inline.start(),
             Inline::Verbatim(inline) => inline.start(),
             Inline::Newline(inline) => inline.start(),
             Inline::ImplicitNewline(inline) => inline.start(),
@@ -222,8 +289,10 @@ impl InlineElement for Inline {
             Inline::DirectUri(inline) => inline.start(),
             Inline::ImplicitSubstitution(inline) => inline.start(),
             Inline::DistinctReference(inline) => inline.start(),
+            Inline::Comment(inline) => inline.start(),
 
-            Inline::NamedSubstitution(_) => todo!(),
+            Inline::NamedSubstitution(inline) => inline.start(),
+            Inline::DirectUnicode(inline) => inline.start(),
         }
     }
 
@@ -242,6 +311,9 @@ impl InlineElement for Inline {
             Inline::TextBox(inline) => inline.end(),
             Inline::Hyperlink(inline) => inline.end(),
             Inline::Citation(inline) => inline.end(),
+            Inline::FootnoteReference(inline) => inline.end(),
+            Inline::Reference(inline) => inline.end(),
+            Inline::AbbreviationReference(inline) => inline.end(),
             Inline::Verbatim(inline) => inline.end(),
             Inline::Newline(inline) => inline.end(),
             Inline::ImplicitNewline(inline) => inline.end(),
@@ -252,8 +324,10 @@ impl InlineElement for Inline {
             Inline::DirectUri(inline) => inline.end(),
             Inline::ImplicitSubstitution(inline) => inline.end(),
             Inline::DistinctReference(inline) => inline.end(),
+            Inline::Comment(inline) => inline.end(),
 
-            Inline::NamedSubstitution(_) => todo!(),
+            Inline::NamedSubstitution(inline) => inline.end(),
+            Inline::DirectUnicode(inline) => inline.end(),
         }
     }
 }
@@ -0,0 +1,195 @@
+//! Contains the [`DirectUnicode`] element and its parser.
+
+use unimarkup_commons::lexer::{position::Position, token::iterator::PeekingNext};
+
+use crate::{
+    element::{Inline, InlineElement},
+    parser::InlineParser,
+    InlineTokenKind,
+};
+
+/// Maximum number of bytes between `&` and `;` of a direct Unicode substitution.
+/// Prevents scanning the whole remaining input if no closing `;` is near.
+const MAX_CODE_LEN: usize = 32;
+
+/// Named HTML entities that may be used as direct Unicode substitution (e.g. `&nbsp;`).
+const HTML_ENTITIES: &[(&str, char)] = &[
+    ("amp", '&'),
+    ("lt", '<'),
+    ("gt", '>'),
+    ("quot", '"'),
+    ("apos", '\''),
+    ("nbsp", '\u{00A0}'),
+    ("shy", '\u{00AD}'),
+    ("copy", '©'),
+    ("reg", '®'),
+    ("trade", '™'),
+    ("deg", '°'),
+    ("plusmn", '±'),
+    ("times", '×'),
+    ("divide", '÷'),
+    ("micro", 'µ'),
+    ("para", '¶'),
+    ("sect", '§'),
+    ("middot", '·'),
+    ("bull", '•'),
+    ("hellip", '…'),
+    ("ndash", '–'),
+    ("mdash", '—'),
+    ("lsquo", '‘'),
+    ("rsquo", '’'),
+    ("ldquo", '“'),
+    ("rdquo", '”'),
+    ("laquo", '«'),
+    ("raquo", '»'),
+    ("euro", '€'),
+    ("pound", '£'),
+    ("yen", '¥'),
+    ("cent", '¢'),
+    ("ensp", '\u{2002}'),
+    ("emsp", '\u{2003}'),
+    ("thinsp", '\u{2009}'),
+    ("zwnj", '\u{200C}'),
+    ("zwj", '\u{200D}'),
+];
+
+/// Represents a Unicode character given directly by its code point (e.g. `&U+1F642;`),
+/// or by a named or numeric HTML entity (e.g. `&hellip;` or `&#x1F642;`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectUnicode {
+    /// The code between `&` and `;` as written in the original content (e.g. `U+1F642`).
+    code: String,
+    /// The character the code resolves to.
+    character: char,
+    /// The start of this substitution in the original content.
+    start: Position,
+    /// The end of this substitution in the original content.
+    end: Position,
+}
+
+impl DirectUnicode {
+    /// Creates a new direct Unicode substitution, or returns `None` if the code does not resolve to a character.
+    pub fn new(code: String, start: Position, end: Position) -> Option<Self> {
+        let character = resolve(&code)?;
+
+        Some(Self {
+            code,
+            character,
+            start,
+            end,
+        })
+    }
+
+    /// Returns the code between `&` and `;` as written in the original content.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns the character this substitution resolves to.
+    pub fn character(&self) -> char {
+        self.character
+    }
+
+    pub(crate) fn parse<'slice, 'input>(
+        mut parser: InlineParser<'slice, 'input>,
+    ) -> (InlineParser<'slice, 'input>, Option<Inline>) {
+        let Some(open_token) = parser.iter.peeking_next(|_| true) else {
+            return (parser, None);
+        };
+
+        debug_assert_eq!(
+            open_token.kind,
+            InlineTokenKind::DirectUnicode,
+            "Called direct Unicode parser on kind '{:?}'.",
+            open_token.kind
+        );
+
+        let remaining = &open_token.input[open_token.offset.end..];
+        let Some(code_len) = remaining
+            .bytes()
+            .take(MAX_CODE_LEN + 1)
+            .position(|b| b == b';')
+        else {
+            return (parser, None);
+        };
+        let code = &remaining[..code_len];
+
+        // `;` is lexed as its own token, so the code always ends at a token boundary
+        let end_offset = open_token.offset.end + code_len + 1;
+
+        let Some(element) = DirectUnicode::new(code.to_string(), open_token.start, open_token.end)
+        else {
+            return (parser, None);
+        };
+
+        parser.iter.next(); // consume open token
+
+        while let Some(token) = parser.iter.next() {
+            if token.offset.end == end_offset {
+                return (
+                    parser,
+                    Some(
+                        DirectUnicode {
+                            end: token.end,
+                            ..element
+                        }
+                        .into(),
+                    ),
+                );
+            } else if token.offset.end > end_offset {
+                break;
+            }
+        }
+
+        (parser, None)
+    }
+}
+
+/// Resolves the given code to its character.
+///
+/// Supported codes are Unicode code points (`U+1F642`), numeric HTML entities (`#128578` or `#x1F642`),
+/// and common named HTML entities (`hellip`).
+fn resolve(code: &str) -> Option<char> {
+    let (digits, radix) = if let Some(hex) = code
+        .strip_prefix("U+")
+        .or_else(|| code.strip_prefix("#x"))
+        .or_else(|| code.strip_prefix("#X"))
+    {
+        (hex, 16)
+    } else if let Some(dec) = code.strip_prefix('#') {
+        (dec, 10)
+    } else {
+        return HTML_ENTITIES
+            .iter()
+            .find(|(name, _)| *name == code)
+            .map(|(_, character)| *character);
+    };
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+
+    u32::from_str_radix(digits, radix)
+        .ok()
+        .and_then(char::from_u32)
+}
+
+impl From<DirectUnicode> for Inline {
+    fn from(value: DirectUnicode) -> Self {
+        Inline::DirectUnicode(value)
+    }
+}
+
+impl InlineElement for DirectUnicode {
+    fn as_unimarkup(&self) -> String {
+        format!("&{};", self.code)
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
@@ -5,6 +5,7 @@ use unimarkup_commons::lexer::{position::Position, token::implicit::ImplicitSubs
 
 use super::{Inline, InlineElement};
 
+pub mod direct;
 pub mod named;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
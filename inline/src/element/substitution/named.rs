@@ -1,4 +1,148 @@
+//! Contains the [`NamedSubstitution`] element and its parser.
+
+use std::rc::Rc;
+
+use unimarkup_commons::lexer::{
+    position::Position,
+    token::iterator::{EndMatcher, PeekingNext},
+};
+
+use crate::{
+    element::{Inline, InlineElement},
+    parser::InlineParser,
+    tokenize::InlineToken,
+    InlineTokenKind,
+};
+
+/// Represents a named substitution (e.g. `::monocle_face::`).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NamedSubstitution {
+    /// The alias between the colons.
     alias: String,
+    /// The start of this substitution in the original content.
+    start: Position,
+    /// The end of this substitution in the original content.
+    end: Position,
+}
+
+impl NamedSubstitution {
+    pub fn new(alias: String, start: Position, end: Position) -> Self {
+        Self { alias, start, end }
+    }
+
+    /// Returns the alias of this substitution.
+    pub fn alias(&self) -> &str {
+        &self.alias
+    }
+
+    /// Returns the substitution for the alias, or `None` if the alias is unknown.
+    ///
+    /// Aliases are either GitHub shortcodes (e.g. `monocle_face`),
+    /// or CLDR short names with underscores instead of spaces and punctuation (e.g. `face_with_monocle`).
+    pub fn subst(&self) -> Option<&'static str> {
+        substitution(&self.alias)
+    }
+
+    pub(crate) fn parse<'slice, 'input>(
+        mut parser: InlineParser<'slice, 'input>,
+    ) -> (InlineParser<'slice, 'input>, Option<Inline>) {
+        let Some(open_token) = parser.iter.peeking_next(|_| true) else {
+            return (parser, None);
+        };
+
+        debug_assert_eq!(
+            open_token.kind,
+            InlineTokenKind::NamedSubstitution,
+            "Called named substitution parser on kind '{:?}'.",
+            open_token.kind
+        );
+
+        // Aliases must directly follow the opening colons
+        if parser.iter.peek_kind().is_none_or(|t| t.is_space()) {
+            return (parser, None);
+        }
+
+        parser.iter.next(); // consume open token
+
+        let (mut scoped_parser, outer_open_formats) =
+            parser.nest_scoped(Some(Rc::new(|matcher: &mut dyn EndMatcher| {
+                !matcher.prev_is_space()
+                    && matcher.consumed_matches(&[InlineTokenKind::NamedSubstitution.into()])
+            })));
+
+        let alias_parts = scoped_parser.iter.take_to_end();
+        let end_reached = scoped_parser.iter.end_reached();
+        parser = scoped_parser.unfold_scoped(outer_open_formats);
+
+        // Aliases must not contain spaces, and the substitution must be closed
+        let alias = match InlineToken::flatten(&alias_parts) {
+            Some(alias)
+                if end_reached
+                    && !alias_parts.iter().any(|t| {
+                        matches!(
+                            t.kind,
+                            InlineTokenKind::Whitespace
+                                | InlineTokenKind::Newline
+                                | InlineTokenKind::EscapedWhitespace
+                                | InlineTokenKind::EscapedNewline
+                        )
+                    }) =>
+            {
+                alias.to_string()
+            }
+            _ => return (parser, None),
+        };
+
+        // Closing colons were already consumed as end of the scope
+        let end = parser
+            .iter
+            .prev_token()
+            .expect("Closing colons were matched as end of the named substitution.")
+            .end;
+
+        (
+            parser,
+            Some(NamedSubstitution::new(alias, open_token.start, end).into()),
+        )
+    }
+}
+
+/// Returns the substitution for the given alias, or `None` if the alias is unknown.
+fn substitution(alias: &str) -> Option<&'static str> {
+    if let Some(emoji) = emojis::get_by_shortcode(alias) {
+        return Some(emoji.as_str());
+    }
+
+    emojis::iter()
+        .find(|emoji| as_alias(emoji.name()) == alias)
+        .map(emojis::Emoji::as_str)
+}
+
+/// Converts a CLDR short name (e.g. "smiling face with heart-eyes") to its alias form (e.g. `smiling_face_with_heart_eyes`).
+fn as_alias(name: &str) -> String {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+        .to_lowercase()
+}
+
+impl From<NamedSubstitution> for Inline {
+    fn from(value: NamedSubstitution) -> Self {
+        Inline::NamedSubstitution(value)
+    }
+}
+
+impl InlineElement for NamedSubstitution {
+    fn as_unimarkup(&self) -> String {
+        format!("::{}::", self.alias)
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
 }
@@ -0,0 +1,177 @@
+use std::rc::Rc;
+
+use unimarkup_commons::{
+    attributes::Attributes,
+    lexer::{position::Position, token::iterator::EndMatcher},
+};
+
+use crate::{
+    element::{Inline, InlineElement},
+    parser::InlineParser,
+    tokenize::InlineToken,
+    InlineTokenKind,
+};
+
+/// Defines how a referenced abbreviation is displayed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AbbreviationDisplay {
+    /// The abbreviation is shown, and its expansion is available as additional information.
+    #[default]
+    Abbreviation,
+    /// The abbreviation is replaced by its expansion.
+    Replace,
+}
+
+/// Represents a reference to an abbreviation (e.g. `[::xml]_`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbbreviationReference {
+    /// The referenced abbreviation.
+    abbreviation: String,
+    /// Defines how the abbreviation is displayed.
+    display: AbbreviationDisplay,
+    /// Optional attributes of the reference (e.g. `{ "display" : "replace" }`).
+    attributes: Option<Attributes>,
+    /// The start of this abbreviation reference in the original content.
+    start: Position,
+    /// The end of this abbreviation reference in the original content.
+    end: Position,
+}
+
+impl AbbreviationReference {
+    pub fn new(
+        abbreviation: String,
+        attributes: Option<Attributes>,
+        start: Position,
+        end: Position,
+    ) -> Self {
+        let display = match attributes.as_ref().and_then(|attrs| attrs.get("display")) {
+            Some("replace") => AbbreviationDisplay::Replace,
+            _ => AbbreviationDisplay::Abbreviation,
+        };
+
+        Self {
+            abbreviation,
+            display,
+            attributes,
+            start,
+            end,
+        }
+    }
+
+    /// Returns the referenced abbreviation.
+    pub fn abbreviation(&self) -> &str {
+        &self.abbreviation
+    }
+
+    /// Returns how the abbreviation should be displayed.
+    pub fn display(&self) -> AbbreviationDisplay {
+        self.display
+    }
+
+    /// Returns the optional attributes of the reference.
+    pub fn attributes(&self) -> Option<&Attributes> {
+        self.attributes.as_ref()
+    }
+
+    pub(crate) fn parse<'slice, 'input>(
+        mut parser: InlineParser<'slice, 'input>,
+    ) -> (InlineParser<'slice, 'input>, Option<Inline>) {
+        let open_bracket = parser
+            .iter
+            .prev_token()
+            .expect("OpenBracket was consumed before parsing AbbreviationReference.");
+
+        let (mut scoped_parser, outer_open_formats) =
+            parser.nest_scoped(Some(Rc::new(|matcher: &mut dyn EndMatcher| {
+                matcher.consumed_matches(&[InlineTokenKind::CloseBracket.into()])
+            })));
+
+        let keyword_token_opt = scoped_parser.iter.next();
+        debug_assert_eq!(
+            keyword_token_opt.map(|t| t.kind),
+            Some(InlineTokenKind::NamedSubstitution),
+            "Called abbreviation reference parser on kind '{:?}'.",
+            keyword_token_opt.map(|t| t.kind)
+        );
+
+        let abbr_parts = scoped_parser.iter.take_to_end();
+        let end_reached = scoped_parser.iter.end_reached();
+        parser = scoped_parser.unfold_scoped(outer_open_formats);
+
+        // Abbreviations may contain spaces (e.g. `[::OPC UA TSN]_`), but must be on one line
+        let abbreviation = match InlineToken::flatten(&abbr_parts) {
+            Some(abbr)
+                if end_reached
+                    && abbr.trim() == abbr
+                    && !abbr_parts.iter().any(|t| {
+                        matches!(
+                            t.kind,
+                            InlineTokenKind::Newline | InlineTokenKind::EscapedNewline
+                        )
+                    }) =>
+            {
+                abbr.to_string()
+            }
+            _ => return (parser, None),
+        };
+
+        // Closing bracket was already consumed as end of the scope
+        let mut end = parser
+            .iter
+            .prev_token()
+            .expect("Closing bracket was matched as end of the abbreviation reference.")
+            .end;
+
+        // The reference marker `_` is optional
+        if parser.iter.peek_kind() == Some(InlineTokenKind::Subscript) {
+            end = parser
+                .iter
+                .next()
+                .expect("Peeked before, so `next` must return Some.")
+                .end;
+        }
+
+        let mut attributes = None;
+
+        if let Some((attrs, attrs_end)) = crate::element::helper::parse_attributes(&mut parser.iter)
+        {
+            attributes = Some(attrs);
+            end = attrs_end;
+        }
+
+        (
+            parser,
+            Some(
+                AbbreviationReference::new(abbreviation, attributes, open_bracket.start, end)
+                    .into(),
+            ),
+        )
+    }
+}
+
+impl From<AbbreviationReference> for Inline {
+    fn from(value: AbbreviationReference) -> Self {
+        Inline::AbbreviationReference(value)
+    }
+}
+
+impl InlineElement for AbbreviationReference {
+    fn as_unimarkup(&self) -> String {
+        format!(
+            "[::{}]_{}",
+            self.abbreviation,
+            self.attributes
+                .as_ref()
+                .map(Attributes::as_unimarkup)
+                .unwrap_or_default()
+        )
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
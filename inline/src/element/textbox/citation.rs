@@ -134,6 +134,11 @@ pub struct CitationEntry {
 }
 
 impl CitationEntry {
+    /// Returns the ID of the cited entry.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
     pub fn as_unimarkup(&self) -> String {
         self.id.clone() // TODO: add attributes once implemented
     }
@@ -153,7 +158,8 @@ impl CitationEntry {
                 || matcher.matches(&[InlineTokenKind::Whitespace.into()])
                 || matcher.matches(&[InlineTokenKind::EscapedWhitespace.into()])
                 || matcher.matches(&[InlineTokenKind::Dot.into()])
-                || matcher.matches(&[InlineTokenKind::Cite.into()])
+                // Entries may also be separated like `[&&id-1&&id-2]`
+                || matcher.consumed_matches(&[InlineTokenKind::Cite.into()])
                 || matcher.outer_end() // TODO: match for attributes
         })));
 
@@ -169,7 +175,6 @@ impl CitationEntry {
                             | Some(InlineTokenKind::Whitespace)
                             | Some(InlineTokenKind::EscapedWhitespace)
                             | Some(InlineTokenKind::Dot)
-                            | Some(InlineTokenKind::Cite)
                     )
                 {
                     return (id_parser.into_inner(), None);
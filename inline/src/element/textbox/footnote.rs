@@ -0,0 +1,120 @@
+use std::rc::Rc;
+
+use unimarkup_commons::lexer::{position::Position, token::iterator::EndMatcher};
+
+use crate::{
+    element::{Inline, InlineElement},
+    parser::InlineParser,
+    tokenize::InlineToken,
+    InlineTokenKind,
+};
+
+/// Represents a reference to a footnote (e.g. `[^^footnote-id]_`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FootnoteReference {
+    /// The ID of the referenced footnote.
+    id: String,
+    /// The start of this footnote reference in the original content.
+    start: Position,
+    /// The end of this footnote reference in the original content.
+    end: Position,
+}
+
+impl FootnoteReference {
+    pub fn new(id: String, start: Position, end: Position) -> Self {
+        Self { id, start, end }
+    }
+
+    /// Returns the ID of the referenced footnote.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub(crate) fn parse<'slice, 'input>(
+        mut parser: InlineParser<'slice, 'input>,
+    ) -> (InlineParser<'slice, 'input>, Option<Inline>) {
+        let open_bracket = parser
+            .iter
+            .prev_token()
+            .expect("OpenBracket was consumed before parsing FootnoteReference.");
+
+        let (mut scoped_parser, outer_open_formats) =
+            parser.nest_scoped(Some(Rc::new(|matcher: &mut dyn EndMatcher| {
+                matcher.consumed_matches(&[InlineTokenKind::CloseBracket.into()])
+            })));
+
+        let footnote_token_opt = scoped_parser.iter.next();
+        debug_assert_eq!(
+            footnote_token_opt.map(|t| t.kind),
+            Some(InlineTokenKind::Footnote),
+            "Called footnote reference parser on kind '{:?}'.",
+            footnote_token_opt.map(|t| t.kind)
+        );
+
+        let id_parts = scoped_parser.iter.take_to_end();
+        let end_reached = scoped_parser.iter.end_reached();
+        parser = scoped_parser.unfold_scoped(outer_open_formats);
+
+        // Footnote IDs must not contain spaces, and the reference must be closed
+        let id = match InlineToken::flatten(&id_parts) {
+            Some(id)
+                if end_reached
+                    && !id_parts.iter().any(|t| {
+                        matches!(
+                            t.kind,
+                            InlineTokenKind::Whitespace
+                                | InlineTokenKind::Newline
+                                | InlineTokenKind::EscapedWhitespace
+                                | InlineTokenKind::EscapedNewline
+                        )
+                    }) =>
+            {
+                id.to_string()
+            }
+            _ => return (parser, None),
+        };
+
+        // Closing bracket was already consumed as end of the scope
+        let mut end = parser
+            .iter
+            .prev_token()
+            .expect("Closing bracket was matched as end of the footnote reference.")
+            .end;
+
+        // The reference marker `_` is optional
+        if parser.iter.peek_kind() == Some(InlineTokenKind::Subscript) {
+            end = parser
+                .iter
+                .next()
+                .expect("Peeked before, so `next` must return Some.")
+                .end;
+        }
+
+        parser.context.footnote_references.push(id.clone());
+
+        (
+            parser,
+            Some(FootnoteReference::new(id, open_bracket.start, end).into()),
+        )
+    }
+}
+
+impl From<FootnoteReference> for Inline {
+    fn from(value: FootnoteReference) -> Self {
+        Inline::FootnoteReference(value)
+    }
+}
+
+impl InlineElement for FootnoteReference {
+    fn as_unimarkup(&self) -> String {
+        format!("[^^{}]_", self.id)
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
@@ -1,6 +1,6 @@
 //! Contains the [`Hyperlink`] element.
 
-use unimarkup_commons::lexer::position::Position;
+use unimarkup_commons::{attributes::Attributes, lexer::position::Position};
 
 use crate::element::{Inline, InlineElement};
 
@@ -10,7 +10,7 @@ pub struct Hyperlink {
     inner: Vec<Inline>,
     link: String,
     link_text: Option<String>,
-    attributes: Option<Vec<Inline>>,
+    attributes: Option<Attributes>,
     start: Position,
     end: Position,
 }
@@ -20,7 +20,7 @@ impl Hyperlink {
         inner: Vec<Inline>,
         link: String,
         link_text: Option<String>,
-        attributes: Option<Vec<Inline>>,
+        attributes: Option<Attributes>,
         start: Position,
         end: Position,
     ) -> Self {
@@ -49,7 +49,8 @@ impl Hyperlink {
         self.link_text.as_deref()
     }
 
-    pub fn attributes(&self) -> Option<&Vec<Inline>> {
+    /// Returns the optional attributes of this hyperlink.
+    pub fn attributes(&self) -> Option<&Attributes> {
         self.attributes.as_ref()
     }
 }
@@ -62,7 +63,15 @@ impl From<Hyperlink> for Inline {
 
 impl InlineElement for Hyperlink {
     fn as_unimarkup(&self) -> String {
-        format!("[{}]({})", self.inner.as_unimarkup(), self.link)
+        format!(
+            "[{}]({}){}",
+            self.inner.as_unimarkup(),
+            self.link,
+            self.attributes
+                .as_ref()
+                .map(Attributes::as_unimarkup)
+                .unwrap_or_default()
+        )
     }
 
     fn start(&self) -> Position {
@@ -2,19 +2,28 @@
 
 use std::rc::Rc;
 
-use unimarkup_commons::lexer::{
-    position::Position,
-    token::{iterator::EndMatcher, TokenKind},
+use unimarkup_commons::{
+    attributes::Attributes,
+    lexer::{
+        position::Position,
+        token::{iterator::EndMatcher, TokenKind},
+    },
 };
 
 use crate::{parser::InlineParser, tokenize::kind::InlineTokenKind};
 
-use self::{citation::Citation, hyperlink::Hyperlink};
+use self::{
+    abbreviation::AbbreviationReference, citation::Citation, footnote::FootnoteReference,
+    hyperlink::Hyperlink, reference::Reference,
+};
 
 use super::{Inline, InlineElement};
 
+pub mod abbreviation;
 pub mod citation;
+pub mod footnote;
 pub mod hyperlink;
+pub mod reference;
 
 /// Represents the text box element.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -22,7 +31,7 @@ pub struct TextBox {
     /// The content inside brackets.
     inner: Vec<Inline>,
     /// Optional attributes of the text box.
-    attributes: Option<Vec<Inline>>,
+    attributes: Option<Attributes>,
     /// The start of this text box in the original content.
     start: Position,
     /// The end of this text box in the original content.
@@ -32,7 +41,7 @@ pub struct TextBox {
 impl TextBox {
     pub fn new(
         inner: Vec<Inline>,
-        attributes: Option<Vec<Inline>>,
+        attributes: Option<Attributes>,
         start: Position,
         end: Position,
     ) -> Self {
@@ -49,7 +58,8 @@ impl TextBox {
         &self.inner
     }
 
-    pub fn attributes(&self) -> Option<&Vec<Inline>> {
+    /// Returns the optional attributes of the text box.
+    pub fn attributes(&self) -> Option<&Attributes> {
         self.attributes.as_ref()
     }
 }
@@ -133,7 +143,8 @@ pub(crate) fn parse<'slice, 'input>(
                     combined
                 });
 
-        let link_close_token = if link_text.is_empty() && !link_parser.iter.end_reached() {
+        let link_parser_end_reached = link_parser.iter.end_reached();
+        let link_close_token = if link_text.is_empty() && !link_parser_end_reached {
             prev_token
         } else {
             link_parser.iter.prev_token().expect(
@@ -143,6 +154,18 @@ pub(crate) fn parse<'slice, 'input>(
 
         parser = link_parser.unfold_scoped(outer_open_formats);
 
+        let mut end = crate::element::helper::implicit_end_using_prev(&link_close_token);
+        let mut attributes = None;
+
+        if link_parser_end_reached {
+            if let Some((attrs, attrs_end)) =
+                crate::element::helper::parse_attributes(&mut parser.iter)
+            {
+                attributes = Some(attrs);
+                end = attrs_end;
+            }
+        }
+
         return (
             parser,
             Some(
@@ -154,23 +177,34 @@ pub(crate) fn parse<'slice, 'input>(
                     } else {
                         Some(link_text)
                     },
-                    None,
+                    attributes,
                     open_token.start,
-                    crate::element::helper::implicit_end_using_prev(&link_close_token),
+                    end,
                 )
                 .into(),
             ),
         );
     }
 
+    let mut end = crate::element::helper::implicit_end_using_prev(&prev_token);
+    let mut attributes = None;
+
+    if end_reached {
+        if let Some((attrs, attrs_end)) = crate::element::helper::parse_attributes(&mut parser.iter)
+        {
+            attributes = Some(attrs);
+            end = attrs_end;
+        }
+    }
+
     (
         parser,
         Some(
             TextBox {
                 inner,
-                attributes: None,
+                attributes,
                 start: open_token.start,
-                end: crate::element::helper::implicit_end_using_prev(&prev_token),
+                end,
             }
             .into(),
         ),
@@ -184,6 +218,12 @@ fn parse_box_variant<'slice, 'input>(
     let kind = parser.iter.peek_kind();
     if kind == Some(InlineTokenKind::Cite) {
         return Citation::parse(parser);
+    } else if kind == Some(InlineTokenKind::Footnote) {
+        return FootnoteReference::parse(parser);
+    } else if kind == Some(InlineTokenKind::NamedSubstitution) {
+        return AbbreviationReference::parse(parser);
+    } else if kind == Some(InlineTokenKind::Reference) {
+        return Reference::parse(parser);
     }
 
     (parser, None)
@@ -197,7 +237,14 @@ impl From<TextBox> for Inline {
 
 impl InlineElement for TextBox {
     fn as_unimarkup(&self) -> String {
-        format!("[{}]", self.inner.as_unimarkup())
+        format!(
+            "[{}]{}",
+            self.inner.as_unimarkup(),
+            self.attributes
+                .as_ref()
+                .map(Attributes::as_unimarkup)
+                .unwrap_or_default()
+        )
     }
 
     fn start(&self) -> Position {
@@ -72,6 +72,8 @@ impl ParsedInlines {
 pub struct InlineContext {
     pub flags: InlineContextFlags,
     pub citations: Vec<Vec<String>>,
+    /// IDs of referenced footnotes in document flow.
+    pub footnote_references: Vec<String>,
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -223,6 +225,12 @@ fn get_scoped_parser(kind: InlineTokenKind, logic_only: bool) -> Option<InlinePa
         InlineTokenKind::Cite if !logic_only => {
             Some(crate::element::substitution::parse_distinct_reference)
         }
+        InlineTokenKind::NamedSubstitution if !logic_only => {
+            Some(crate::element::substitution::named::NamedSubstitution::parse)
+        }
+        InlineTokenKind::DirectUnicode if !logic_only => {
+            Some(crate::element::substitution::direct::DirectUnicode::parse)
+        }
         _ => None,
     }
 }
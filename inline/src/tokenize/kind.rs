@@ -50,6 +50,12 @@ pub enum InlineTokenKind {
     /// Citation delimiter token (`&&`)
     Cite,
 
+    /// Footnote reference delimiter token (`^^`)
+    Footnote,
+
+    /// Cross-reference delimiter token (`##`)
+    Reference,
+
     /// Single comma token (`,`)
     Comma,
 
@@ -74,6 +80,9 @@ pub enum InlineTokenKind {
     /// Double colon for substitution (`::`).
     NamedSubstitution,
 
+    /// Single ampersand for direct Unicode substitution (`&`).
+    DirectUnicode,
+
     /// End of line - regular newline token ('\n').
     Newline,
 
@@ -133,6 +142,8 @@ impl InlineTokenKind {
             InlineTokenKind::Math => "$$",
             InlineTokenKind::Dot => ".",
             InlineTokenKind::Cite => "&&",
+            InlineTokenKind::Footnote => "^^",
+            InlineTokenKind::Reference => "##",
             InlineTokenKind::Comma => ",",
             InlineTokenKind::OpenParenthesis => "(",
             InlineTokenKind::CloseParenthesis => ")",
@@ -141,6 +152,7 @@ impl InlineTokenKind {
             InlineTokenKind::OpenBrace => "{",
             InlineTokenKind::CloseBrace => "}",
             InlineTokenKind::NamedSubstitution => "::",
+            InlineTokenKind::DirectUnicode => "&",
             InlineTokenKind::Eoi => "",
             InlineTokenKind::Plain
             | InlineTokenKind::EscapedPlain
@@ -246,6 +258,7 @@ impl InlineTokenKind {
             InlineTokenKind::Verbatim
                 | InlineTokenKind::Math
                 | InlineTokenKind::NamedSubstitution
+                | InlineTokenKind::DirectUnicode
                 | InlineTokenKind::Cite
         )
     }
@@ -267,6 +280,9 @@ pub const VERBATIM_KEYWORD_LEN: usize = 1;
 pub const OVERLINE_KEYWORD_LEN: usize = 1;
 pub const DOT_KEYWORD_LEN: usize = 1;
 pub const CITE_KEYWORD_LEN: usize = 2;
+pub const DIRECT_UNICODE_KEYWORD_LEN: usize = 1;
+pub const FOOTNOTE_KEYWORD_LEN: usize = 2;
+pub const REFERENCE_KEYWORD_LEN: usize = 2;
 pub const COMMA_KEYWORD_LEN: usize = 1;
 
 impl From<TokenKind> for InlineTokenKind {
@@ -335,6 +351,8 @@ impl From<TokenKind> for InlineTokenKind {
             TokenKind::Caret(len) => {
                 if len == SUPERSCRIPT_KEYWORD_LEN {
                     InlineTokenKind::Superscript
+                } else if len == FOOTNOTE_KEYWORD_LEN {
+                    InlineTokenKind::Footnote
                 } else {
                     InlineTokenKind::Plain
                 }
@@ -363,6 +381,8 @@ impl From<TokenKind> for InlineTokenKind {
             TokenKind::Ampersand(len) => {
                 if len == CITE_KEYWORD_LEN {
                     InlineTokenKind::Cite
+                } else if len == DIRECT_UNICODE_KEYWORD_LEN {
+                    InlineTokenKind::DirectUnicode
                 } else {
                     InlineTokenKind::Plain
                 }
@@ -374,6 +394,13 @@ impl From<TokenKind> for InlineTokenKind {
                     InlineTokenKind::Plain
                 }
             }
+            TokenKind::Hash(len) => {
+                if len == REFERENCE_KEYWORD_LEN {
+                    InlineTokenKind::Reference
+                } else {
+                    InlineTokenKind::Plain
+                }
+            }
 
             TokenKind::OpenParenthesis => InlineTokenKind::OpenParenthesis,
             TokenKind::CloseParenthesis => InlineTokenKind::CloseParenthesis,
@@ -384,6 +411,7 @@ impl From<TokenKind> for InlineTokenKind {
             TokenKind::Whitespace => InlineTokenKind::Whitespace,
             TokenKind::Newline => InlineTokenKind::Newline,
             TokenKind::Eoi | TokenKind::Blankline => InlineTokenKind::Eoi, // Blankline is not allowed in inlines => treat as inline end
+            TokenKind::EscapedBlankline => InlineTokenKind::Eoi, // Directly followed by a blankline
             TokenKind::EscapedPlain => InlineTokenKind::EscapedPlain,
             TokenKind::EscapedWhitespace => InlineTokenKind::EscapedWhitespace,
             TokenKind::EscapedNewline => InlineTokenKind::EscapedNewline,
@@ -398,9 +426,11 @@ impl From<TokenKind> for InlineTokenKind {
             TokenKind::PossibleAttributes => InlineTokenKind::PossibleAttributes,
 
             TokenKind::Plain
-            | TokenKind::Hash(_)
             | TokenKind::Minus(_)
             | TokenKind::Plus(_)
+            | TokenKind::GreaterThan(_)
+            | TokenKind::SingleQuote(_)
+            | TokenKind::Equals(_)
             | TokenKind::EnclosedBlockEnd
             | TokenKind::PossibleDecorator
             | TokenKind::TerminalPunctuation => InlineTokenKind::Plain,
@@ -428,6 +458,8 @@ impl From<InlineTokenKind> for TokenKind {
             InlineTokenKind::Math => TokenKind::Dollar(MATH_KEYWORD_LEN),
             InlineTokenKind::Dot => TokenKind::Dot(DOT_KEYWORD_LEN),
             InlineTokenKind::Cite => TokenKind::Ampersand(CITE_KEYWORD_LEN),
+            InlineTokenKind::Footnote => TokenKind::Caret(FOOTNOTE_KEYWORD_LEN),
+            InlineTokenKind::Reference => TokenKind::Hash(REFERENCE_KEYWORD_LEN),
             InlineTokenKind::Comma => TokenKind::Comma(COMMA_KEYWORD_LEN),
             InlineTokenKind::OpenParenthesis => TokenKind::OpenParenthesis,
             InlineTokenKind::CloseParenthesis => TokenKind::CloseParenthesis,
@@ -436,6 +468,7 @@ impl From<InlineTokenKind> for TokenKind {
             InlineTokenKind::OpenBrace => TokenKind::OpenBrace,
             InlineTokenKind::CloseBrace => TokenKind::CloseBrace,
             InlineTokenKind::NamedSubstitution => TokenKind::Colon(NAMED_SUBSTITUTION_KEYWORD_LEN),
+            InlineTokenKind::DirectUnicode => TokenKind::Ampersand(DIRECT_UNICODE_KEYWORD_LEN),
             InlineTokenKind::Newline => TokenKind::Newline,
             InlineTokenKind::EscapedNewline => TokenKind::EscapedNewline,
             InlineTokenKind::Whitespace => TokenKind::Whitespace,
@@ -64,8 +64,11 @@ impl<'input> InlineToken<'input> {
             | InlineTokenKind::Strikethrough
             | InlineTokenKind::Dot
             | InlineTokenKind::Cite
+            | InlineTokenKind::Footnote
+            | InlineTokenKind::Reference
             | InlineTokenKind::Comma
             | InlineTokenKind::NamedSubstitution
+            | InlineTokenKind::DirectUnicode
             | InlineTokenKind::OpenBrace
             | InlineTokenKind::OpenBracket
             | InlineTokenKind::OpenParenthesis
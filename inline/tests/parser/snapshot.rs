@@ -117,6 +117,16 @@ fn inner_snapshot(inline: &Inline) -> String {
                     s
                 })
         }
+        Inline::FootnoteReference(inline) => format!("id='{}'", inline.id()),
+        Inline::Reference(inline) => format!("id='{}'", inline.id()),
+        Inline::AbbreviationReference(inline) => match inline.attributes() {
+            Some(attributes) => format!(
+                "abbr='{}' {}",
+                inline.abbreviation(),
+                attributes.as_unimarkup()
+            ),
+            None => format!("abbr='{}'", inline.abbreviation()),
+        },
         Inline::Verbatim(inline) => Snapshot::snap(inline),
         Inline::Newline(inline) => Snapshot::snap(inline.as_str()),
         Inline::ImplicitNewline(inline) => Snapshot::snap(inline.as_str()),
@@ -126,9 +136,16 @@ fn inner_snapshot(inline: &Inline) -> String {
         Inline::EscapedPlain(inline) => inline.content().clone(),
         Inline::DirectUri(inline) => inline.uri().to_string(),
 
-        Inline::NamedSubstitution(_) => todo!(),
+        Inline::NamedSubstitution(inline) => match inline.subst() {
+            Some(subst) => format!("alias='{}' subst='{}'", inline.alias(), subst),
+            None => format!("alias='{}'", inline.alias()),
+        },
+        Inline::DirectUnicode(inline) => {
+            format!("code='{}' char='{}'", inline.code(), inline.character())
+        }
         Inline::ImplicitSubstitution(impl_subst) => impl_subst.subst().to_string(),
         Inline::DistinctReference(inline) => inline.as_unimarkup(),
+        Inline::Comment(inline) => inline.content().to_string(),
     }
 }
 
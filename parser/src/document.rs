@@ -1,6 +1,13 @@
 //! Contains the Unimarkup Document structure used to store all information of a Unimarkup document in one structure.
 
-use crate::{elements::Blocks, metadata::Metadata};
+use crate::{
+    elements::{
+        definitions::{AbbreviationDefinition, FootnoteDefinition},
+        references::ReferenceTarget,
+        Blocks,
+    },
+    metadata::Metadata,
+};
 use unimarkup_commons::config::{output::OutputFormatKind, Config};
 
 /// Struct representing a Unimarkup document
@@ -14,6 +21,14 @@ pub struct Document {
     /// The citations are added in document flow.
     /// Every citation may contain one or more citation entry IDs.
     pub citations: Vec<Vec<String>>,
+    /// Footnotes referenced in the Unimarkup content.
+    /// The footnotes are ordered by their first reference,
+    /// so the number of a footnote is its position in this list starting at `1`.
+    pub footnotes: Vec<FootnoteDefinition>,
+    /// Abbreviations defined in the Unimarkup content, ordered by their definition.
+    pub abbreviations: Vec<AbbreviationDefinition>,
+    /// Elements with an ID that may be referenced in the Unimarkup content, ordered by their occurrence.
+    pub references: Vec<ReferenceTarget>,
 
     // Below fields not yet used!
     /// Field containing all macros defined in this Unimarkup document
@@ -31,4 +46,27 @@ impl Document {
     pub fn output_formats(&self) -> impl Iterator<Item = &OutputFormatKind> {
         self.config.output.formats.iter()
     }
+
+    /// Returns the number and definition of the footnote with the given ID,
+    /// or `None` if the footnote is not defined.
+    pub fn footnote(&self, id: &str) -> Option<(usize, &FootnoteDefinition)> {
+        self.footnotes
+            .iter()
+            .position(|footnote| footnote.id == id)
+            .map(|index| (index + 1, &self.footnotes[index]))
+    }
+
+    /// Returns the definition of the given abbreviation,
+    /// or `None` if the abbreviation is not defined.
+    pub fn abbreviation(&self, abbreviation: &str) -> Option<&AbbreviationDefinition> {
+        self.abbreviations
+            .iter()
+            .find(|abbr| abbr.abbreviation == abbreviation)
+    }
+
+    /// Returns the element with the given ID,
+    /// or `None` if no element has this ID.
+    pub fn reference_target(&self, id: &str) -> Option<&ReferenceTarget> {
+        self.references.iter().find(|target| target.id == id)
+    }
 }
@@ -0,0 +1,85 @@
+//! Contains the structs and parsers to parse comments that span a whole line.
+
+use unimarkup_commons::lexer::position::Position;
+use unimarkup_commons::lexer::token::{TokenKind, COMMENT_KEYWORD};
+
+use crate::elements::blocks::Block;
+use crate::elements::BlockElement;
+use crate::BlockParser;
+
+/// Structure of a Unimarkup comment that is the only element in its line (e.g. `;; some comment`).
+///
+/// Comments that share their line with other content are parsed as inline comments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    /// The content between the comment keywords.
+    pub content: String,
+    /// Marks that this comment was implicitly closed at the end of the line.
+    pub implicit_close: bool,
+    /// The start of this comment in the original content.
+    pub start: Position,
+    /// The end of this comment in the original content.
+    pub end: Position,
+}
+
+impl BlockElement for Comment {
+    fn as_unimarkup(&self) -> String {
+        let close = if self.implicit_close {
+            ""
+        } else {
+            COMMENT_KEYWORD
+        };
+
+        format!("{COMMENT_KEYWORD}{}{close}", self.content)
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+impl Comment {
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<Block>) {
+        let Some(comment_token) = parser.iter.next() else {
+            return (parser, None);
+        };
+
+        let TokenKind::Comment { implicit_close } = comment_token.kind else {
+            return (parser, None);
+        };
+
+        // Only spaces are allowed until the end of the line
+        if parser
+            .iter
+            .by_ref()
+            .take_while(|t| !matches!(t.kind, TokenKind::Blankline | TokenKind::Newline))
+            .any(|t| !t.kind.is_space())
+        {
+            return (parser, None);
+        }
+
+        let raw = &comment_token.input[comment_token.offset.start..comment_token.offset.end];
+        let content = raw.strip_prefix(COMMENT_KEYWORD).unwrap_or(raw);
+        let content = if implicit_close {
+            content
+        } else {
+            content.strip_suffix(COMMENT_KEYWORD).unwrap_or(content)
+        };
+
+        (
+            parser,
+            Some(Block::Comment(Comment {
+                content: content.to_string(),
+                implicit_close,
+                start: comment_token.start,
+                end: comment_token.end,
+            })),
+        )
+    }
+}
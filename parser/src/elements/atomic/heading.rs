@@ -3,12 +3,13 @@
 use std::rc::Rc;
 
 use strum_macros::*;
+use unimarkup_commons::attributes::Attributes;
 use unimarkup_commons::lexer::token::iterator::{EndMatcher, Itertools, PrefixMatcher};
 use unimarkup_commons::lexer::token::TokenKind;
 use unimarkup_inline::element::{Inline, InlineElement};
 use unimarkup_inline::parser;
 
-use crate::elements::BlockElement;
+use crate::elements::{attributes, BlockElement};
 use crate::{elements::blocks::Block, BlockParser};
 use unimarkup_commons::lexer::position::Position;
 
@@ -118,8 +119,8 @@ pub struct Heading {
     /// The content of the heading line.
     pub content: Vec<Inline>,
 
-    /// Attributes of the heading.
-    pub attributes: Option<String>,
+    /// Optional attributes of the heading.
+    pub attributes: Option<Attributes>,
 
     /// The start of this block in the original content.
     pub start: Position,
@@ -135,7 +136,10 @@ impl BlockElement for Heading {
             .as_unimarkup()
             .lines()
             .join(&" ".repeat(prefix.len()));
-        format!("{prefix}{content}")
+        match &self.attributes {
+            Some(attributes) => format!("{prefix}{content} {}", attributes.as_unimarkup()),
+            None => format!("{prefix}{content}"),
+        }
     }
 
     fn start(&self) -> Position {
@@ -173,33 +177,28 @@ impl Heading {
             return (parser, None);
         }
 
-        let (hashes_prefix, spaces_prefix) = heading_prefix_sequences(hashes_len);
-        let sub_heading_prefix = sub_heading_start(hashes_len);
+        // Attributes may be set at the end of the heading line, e.g. `# Heading { "id": "custom-id" }`
+        let checkpoint = parser.iter.checkpoint();
+        let (updated_parser, mut content_opt) = parse_content(parser, hashes_len, true);
+        parser = updated_parser;
+        let mut attributes = None;
 
-        let (iter, inline_context, parsed_inlines) = parser::parse_inlines(
-            parser.iter,
-            (&parser.context).into(),
-            Some(Rc::new(|matcher: &mut dyn PrefixMatcher| {
-                matcher.consumed_prefix(hashes_prefix) || matcher.consumed_prefix(spaces_prefix)
-            })),
-            Some(Rc::new(|matcher: &mut dyn EndMatcher| {
-                matcher.consumed_is_blank_line()
-                    || matcher.matches(sub_heading_prefix)
-                    || matcher.outer_end()
-            })),
-        );
-        parser.iter = iter;
-        parser.context.update_from(inline_context);
+        if content_opt.is_some() && parser.iter.consumed_matches(&[TokenKind::Space]) {
+            attributes = attributes::parse_attributes(&mut parser);
 
-        if parsed_inlines.prefix_mismatch() {
-            return (parser, None);
+            if attributes.is_none() {
+                parser.iter.rollback(checkpoint);
+                let (updated_parser, reparsed_content) = parse_content(parser, hashes_len, false);
+                parser = updated_parser;
+                content_opt = reparsed_content;
+            }
         }
 
-        let content = parsed_inlines.to_inlines();
+        let Some(content) = content_opt else {
+            return (parser, None);
+        };
         let id = as_id(&content);
 
-        //TODO: implement optional attribute parsing here
-
         let heading_end = parser
             .iter
             .prev()
@@ -213,7 +212,7 @@ impl Heading {
                 level: HeadingLevel::try_from(hashes_len)
                     .expect("Correct heading level ensured above."),
                 content,
-                attributes: None,
+                attributes,
                 start: hashes.start,
                 end: heading_end,
             })),
@@ -221,6 +220,41 @@ impl Heading {
     }
 }
 
+/// Parses the content of a heading, and returns `None` if the heading prefix did not match on a following line.
+///
+/// If `attributes_end` is `true`, the content ends before a space followed by an opening brace.
+fn parse_content<'s, 'i>(
+    mut parser: BlockParser<'s, 'i>,
+    hashes_len: usize,
+    attributes_end: bool,
+) -> (BlockParser<'s, 'i>, Option<Vec<Inline>>) {
+    let (hashes_prefix, spaces_prefix) = heading_prefix_sequences(hashes_len);
+    let sub_heading_prefix = sub_heading_start(hashes_len);
+
+    let (iter, inline_context, parsed_inlines) = parser::parse_inlines(
+        parser.iter,
+        (&parser.context).into(),
+        Some(Rc::new(|matcher: &mut dyn PrefixMatcher| {
+            matcher.consumed_prefix(hashes_prefix) || matcher.consumed_prefix(spaces_prefix)
+        })),
+        Some(Rc::new(move |matcher: &mut dyn EndMatcher| {
+            matcher.consumed_is_blank_line()
+                || matcher.matches(sub_heading_prefix)
+                || matcher.matches(&[TokenKind::Newline, TokenKind::OpenBrace])
+                || (attributes_end && matcher.matches(&[TokenKind::Space, TokenKind::OpenBrace]))
+                || matcher.outer_end()
+        })),
+    );
+    parser.iter = iter;
+    parser.context.update_from(inline_context);
+
+    if parsed_inlines.prefix_mismatch() {
+        return (parser, None);
+    }
+
+    (parser, Some(parsed_inlines.to_inlines()))
+}
+
 /// Converts the heading content into a valid ID.
 ///
 /// Whitespaces are replaced with `-`, quotes and backslash are removed,
@@ -1,9 +1,13 @@
 //! Contains atomic elements
 
+mod comment;
 mod heading;
 mod paragraph;
+mod separator;
 
+pub use comment::Comment;
 pub use heading::{Heading, HeadingLevel};
 pub use paragraph::Paragraph;
+pub use separator::{HorizontalLine, LineBreak, PageBreak};
 
 pub mod log_id;
@@ -3,12 +3,14 @@
 use std::fmt::Debug;
 use std::rc::Rc;
 
+use unimarkup_commons::attributes::Attributes;
 use unimarkup_commons::lexer::token::iterator::EndMatcher;
+use unimarkup_commons::lexer::token::TokenKind;
 use unimarkup_inline::element::{Inline, InlineElement};
 use unimarkup_inline::parser;
 
 use crate::elements::blocks::Block;
-use crate::elements::BlockElement;
+use crate::elements::{attributes, BlockElement};
 use crate::BlockParser;
 
 /// Structure of a Unimarkup paragraph element.
@@ -16,6 +18,8 @@ use crate::BlockParser;
 pub struct Paragraph {
     /// The content of the paragraph.
     pub content: Vec<Inline>,
+    /// Optional attributes of the paragraph.
+    pub attributes: Option<Attributes>,
 }
 
 impl BlockElement for Paragraph {
@@ -34,18 +38,58 @@ impl BlockElement for Paragraph {
 
 impl Paragraph {
     pub(crate) fn parse<'s, 'i>(mut parser: BlockParser<'s, 'i>) -> (BlockParser<'s, 'i>, Block) {
-        let (iter, inline_context, parsed_inlines) = parser::parse_inlines(
-            parser.iter,
-            (&parser.context).into(),
-            None,
-            Some(Rc::new(|matcher: &mut dyn EndMatcher| {
-                matcher.consumed_is_blank_line() || matcher.outer_end()
-            })),
-        );
-        parser.iter = iter;
-        parser.context.update_from(inline_context);
-        let inlines = parsed_inlines.to_inlines();
-
-        (parser, Block::Paragraph(Paragraph { content: inlines }))
+        // Attributes may be set in the line directly after the paragraph
+        let checkpoint = parser.iter.checkpoint();
+        let (updated_parser, mut content) = parse_content(parser, true);
+        parser = updated_parser;
+        let mut attributes = None;
+
+        if parser
+            .iter
+            .matches(&[TokenKind::Newline, TokenKind::OpenBrace])
+        {
+            parser.iter.next(); // Consume newline before attributes
+            attributes = attributes::parse_attributes(&mut parser);
+
+            if attributes.is_none() {
+                parser.iter.rollback(checkpoint);
+                let (updated_parser, reparsed_content) = parse_content(parser, false);
+                parser = updated_parser;
+                content = reparsed_content;
+            }
+        }
+
+        (
+            parser,
+            Block::Paragraph(Paragraph {
+                content,
+                attributes,
+            }),
+        )
     }
 }
+
+/// Parses the content of a paragraph.
+///
+/// If `attributes_end` is `true`, the content ends before a new line starting with an opening brace.
+fn parse_content<'s, 'i>(
+    mut parser: BlockParser<'s, 'i>,
+    attributes_end: bool,
+) -> (BlockParser<'s, 'i>, Vec<Inline>) {
+    let (iter, inline_context, parsed_inlines) = parser::parse_inlines(
+        parser.iter,
+        (&parser.context).into(),
+        None,
+        Some(Rc::new(move |matcher: &mut dyn EndMatcher| {
+            matcher.consumed_is_blank_line()
+                // A line only containing a backslash is a line break after the paragraph
+                || matcher.matches(&[TokenKind::Newline, TokenKind::EscapedBlankline])
+                || (attributes_end && matcher.matches(&[TokenKind::Newline, TokenKind::OpenBrace]))
+                || matcher.outer_end()
+        })),
+    );
+    parser.iter = iter;
+    parser.context.update_from(inline_context);
+
+    (parser, parsed_inlines.to_inlines())
+}
@@ -0,0 +1,172 @@
+//! Contains the structs and parsers to parse horizontal lines, line breaks, and page breaks.
+
+use unimarkup_commons::attributes::Attributes;
+use unimarkup_commons::lexer::position::Position;
+use unimarkup_commons::lexer::symbol::SymbolKind;
+use unimarkup_commons::lexer::token::TokenKind;
+
+use crate::elements::blocks::Block;
+use crate::elements::BlockElement;
+use crate::BlockParser;
+
+/// Number of colons of a page break (`:::`).
+const PAGE_BREAK_LEN: usize = 3;
+
+/// Structure of a Unimarkup horizontal line (`---`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HorizontalLine {
+    /// The number of minus signs the horizontal line was created with.
+    pub minus_len: usize,
+    /// Optional attributes of the horizontal line.
+    pub attributes: Option<Attributes>,
+    /// The start of this horizontal line in the original content.
+    pub start: Position,
+    /// The end of this horizontal line in the original content.
+    pub end: Position,
+}
+
+impl BlockElement for HorizontalLine {
+    fn as_unimarkup(&self) -> String {
+        SymbolKind::Minus.as_str().repeat(self.minus_len)
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+impl HorizontalLine {
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<Block>) {
+        let Some(open_token) = parser.iter.next() else {
+            return (parser, None);
+        };
+
+        let minus_len = match open_token.kind {
+            TokenKind::Minus(len) if len >= 3 => len,
+            _ => return (parser, None),
+        };
+
+        if !line_ends(&mut parser) {
+            return (parser, None);
+        }
+
+        (
+            parser,
+            Some(Block::HorizontalLine(HorizontalLine {
+                minus_len,
+                attributes: None,
+                start: open_token.start,
+                end: open_token.end,
+            })),
+        )
+    }
+}
+
+/// Structure of a Unimarkup line break, which is a line only containing a backslash (`\`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineBreak {
+    /// The start of this line break in the original content.
+    pub start: Position,
+    /// The end of this line break in the original content.
+    pub end: Position,
+}
+
+impl BlockElement for LineBreak {
+    fn as_unimarkup(&self) -> String {
+        SymbolKind::Backslash.as_str().to_string()
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+impl LineBreak {
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<Block>) {
+        match parser.iter.next() {
+            // Escaped newline already includes the newline, so the next block starts at a new line.
+            // A backslash before a blankline is followed by the blankline token, which is kept to end blocks.
+            Some(token)
+                if matches!(
+                    token.kind,
+                    TokenKind::EscapedNewline | TokenKind::EscapedBlankline
+                ) =>
+            {
+                (
+                    parser,
+                    Some(Block::LineBreak(LineBreak {
+                        start: token.start,
+                        end: token.start + SymbolKind::Backslash.as_str().len(),
+                    })),
+                )
+            }
+            _ => (parser, None),
+        }
+    }
+}
+
+/// Structure of a Unimarkup page break (`:::`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageBreak {
+    /// The start of this page break in the original content.
+    pub start: Position,
+    /// The end of this page break in the original content.
+    pub end: Position,
+}
+
+impl BlockElement for PageBreak {
+    fn as_unimarkup(&self) -> String {
+        SymbolKind::Colon.as_str().repeat(PAGE_BREAK_LEN)
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+impl PageBreak {
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<Block>) {
+        let Some(open_token) = parser.iter.next() else {
+            return (parser, None);
+        };
+
+        if open_token.kind != TokenKind::Colon(PAGE_BREAK_LEN) || !line_ends(&mut parser) {
+            return (parser, None);
+        }
+
+        (
+            parser,
+            Some(Block::PageBreak(PageBreak {
+                start: open_token.start,
+                end: open_token.end,
+            })),
+        )
+    }
+}
+
+/// Consumes the remaining line, and returns `true` if it only contained spaces.
+fn line_ends(parser: &mut BlockParser<'_, '_>) -> bool {
+    !parser
+        .iter
+        .by_ref()
+        .take_while(|t| !matches!(t.kind, TokenKind::Blankline | TokenKind::Newline))
+        .any(|t| !t.kind.is_space())
+}
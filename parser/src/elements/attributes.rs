@@ -0,0 +1,57 @@
+//! Contains the parser for attributes that may be set for block elements.
+
+use unimarkup_commons::{attributes::Attributes, lexer::token::TokenKind};
+
+use crate::BlockParser;
+
+/// Tries to parse attributes (e.g. `{ "id" : "my-id" }`) at the current position of the given [`BlockParser`].
+/// Attributes may span multiple lines, but only spaces are allowed after the closing brace until the end of the line.
+///
+/// The parser is not advanced if no valid attributes were found.
+pub(crate) fn parse_attributes(parser: &mut BlockParser<'_, '_>) -> Option<Attributes> {
+    if parser.iter.peek_kind() != Some(TokenKind::OpenBrace) {
+        return None;
+    }
+
+    let checkpoint = parser.iter.checkpoint();
+    let mut raw = String::new();
+    let mut depth = 0usize;
+    let mut closed = false;
+
+    for token in parser.iter.by_ref() {
+        match token.kind {
+            TokenKind::OpenBrace => depth += 1,
+            TokenKind::CloseBrace => depth -= 1,
+            TokenKind::Blankline | TokenKind::Eoi => break,
+            _ => {}
+        }
+
+        raw.push_str(&String::from(token));
+
+        if depth == 0 {
+            closed = true;
+            break;
+        }
+    }
+
+    while parser.iter.peek_kind() == Some(TokenKind::Whitespace) {
+        parser.iter.next();
+    }
+
+    let line_end = matches!(
+        parser.iter.peek_kind(),
+        None | Some(TokenKind::Newline | TokenKind::Blankline | TokenKind::Eoi)
+    );
+
+    let attributes = if closed && line_end {
+        Attributes::try_from(raw.as_str()).ok()
+    } else {
+        None
+    };
+
+    if attributes.is_none() {
+        parser.iter.rollback(checkpoint);
+    }
+
+    attributes
+}
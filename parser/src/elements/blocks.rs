@@ -1,11 +1,20 @@
 //! Defines the generic Unimarkup Block that is the base for all block elements.
 
-use unimarkup_commons::lexer::{position::Position, span::Span, symbol::SymbolKind};
+use unimarkup_commons::{
+    attributes::Attributes,
+    lexer::{position::Position, span::Span, symbol::SymbolKind},
+};
 
 use super::{
-    atomic::{Heading, Paragraph},
-    enclosed::VerbatimBlock,
-    indents::{BulletList, BulletListEntry},
+    atomic::{Comment, Heading, HorizontalLine, LineBreak, PageBreak, Paragraph},
+    decorations::Decorations,
+    definitions::{AbbreviationDefinition, FootnoteDefinition},
+    enclosed::{Columns, MathBlock, RenderBlock, Table, TextBlock, VerbatimBlock},
+    indents::{
+        BulletList, BulletListEntry, DefinitionList, LineBlock, NumberedList, NumberedListEntry,
+        Quotation,
+    },
+    inserts::MediaInsert,
     BlockElement,
 };
 
@@ -25,6 +34,40 @@ pub enum Block {
     BulletList(BulletList),
     /// Represents the bullet list entry block
     BulletListEntry(BulletListEntry),
+    /// Represents the numbered list block
+    NumberedList(NumberedList),
+    /// Represents the numbered list entry block
+    NumberedListEntry(NumberedListEntry),
+    /// Represents the definition list block
+    DefinitionList(DefinitionList),
+    /// Represents the table block
+    Table(Table),
+    /// Represents the quotation block
+    Quotation(Quotation),
+    /// Represents the line block
+    LineBlock(LineBlock),
+    /// Represents the math block
+    MathBlock(MathBlock),
+    /// Represents the column block
+    Columns(Columns),
+    /// Represents the text block
+    TextBlock(TextBlock),
+    /// Represents the media insert
+    MediaInsert(MediaInsert),
+    /// Represents the render block
+    RenderBlock(RenderBlock),
+    /// Represents the footnote definition
+    FootnoteDefinition(FootnoteDefinition),
+    /// Represents the abbreviation definition block
+    AbbreviationDefinition(AbbreviationDefinition),
+    /// Represents the horizontal line
+    HorizontalLine(HorizontalLine),
+    /// Represents the line break
+    LineBreak(LineBreak),
+    /// Represents the page break
+    PageBreak(PageBreak),
+    /// Represents a comment that is the only element in its line
+    Comment(Comment),
 }
 
 impl Block {
@@ -38,19 +81,150 @@ impl Block {
             Block::VerbatimBlock(_) => "VerbatimBlock",
             Block::BulletList(_) => "BulletList",
             Block::BulletListEntry(_) => "BulletListEntry",
+            Block::NumberedList(_) => "NumberedList",
+            Block::NumberedListEntry(_) => "NumberedListEntry",
+            Block::DefinitionList(_) => "DefinitionList",
+            Block::Table(_) => "Table",
+            Block::Quotation(_) => "Quotation",
+            Block::LineBlock(_) => "LineBlock",
+            Block::MathBlock(_) => "MathBlock",
+            Block::Columns(_) => "Columns",
+            Block::TextBlock(_) => "TextBlock",
+            Block::MediaInsert(_) => "MediaInsert",
+            Block::RenderBlock(_) => "RenderBlock",
+            Block::FootnoteDefinition(_) => "FootnoteDefinition",
+            Block::AbbreviationDefinition(_) => "AbbreviationDefinition",
+            Block::HorizontalLine(_) => "HorizontalLine",
+            Block::LineBreak(_) => "LineBreak",
+            Block::PageBreak(_) => "PageBreak",
+            Block::Comment(_) => "Comment",
+        }
+    }
+
+    /// Returns the attributes of this block, if any were set.
+    pub fn attributes(&self) -> Option<&Attributes> {
+        match self {
+            Block::Blankline(_) | Block::LineBreak(_) | Block::PageBreak(_) | Block::Comment(_) => {
+                None
+            }
+            Block::Heading(block) => block.attributes.as_ref(),
+            Block::Paragraph(block) => block.attributes.as_ref(),
+            Block::VerbatimBlock(block) => block.attributes.as_ref(),
+            Block::BulletList(block) => block.attributes.as_ref(),
+            Block::BulletListEntry(block) => block.attributes.as_ref(),
+            Block::NumberedList(block) => block.attributes.as_ref(),
+            Block::NumberedListEntry(block) => block.attributes.as_ref(),
+            Block::DefinitionList(block) => block.attributes.as_ref(),
+            Block::Table(block) => block.attributes.as_ref(),
+            Block::Quotation(block) => block.attributes.as_ref(),
+            Block::LineBlock(block) => block.attributes.as_ref(),
+            Block::MathBlock(block) => block.attributes.as_ref(),
+            Block::Columns(block) => block.attributes.as_ref(),
+            Block::TextBlock(block) => block.attributes.as_ref(),
+            Block::MediaInsert(block) => block.attributes.as_ref(),
+            Block::RenderBlock(block) => block.attributes.as_ref(),
+            Block::FootnoteDefinition(block) => block.attributes.as_ref(),
+            Block::AbbreviationDefinition(block) => block.attributes.as_ref(),
+            Block::HorizontalLine(block) => block.attributes.as_ref(),
+        }
+    }
+
+    /// Returns the title and caption of this block, if this block may be decorated.
+    pub fn decorations(&self) -> Option<&Decorations> {
+        match self {
+            Block::VerbatimBlock(block) => Some(&block.decorations),
+            Block::Table(block) => Some(&block.decorations),
+            Block::MediaInsert(block) => Some(&block.decorations),
+            _ => None,
         }
     }
+
+    pub(crate) fn decorations_mut(&mut self) -> Option<&mut Decorations> {
+        match self {
+            Block::VerbatimBlock(block) => Some(&mut block.decorations),
+            Block::Table(block) => Some(&mut block.decorations),
+            Block::MediaInsert(block) => Some(&mut block.decorations),
+            _ => None,
+        }
+    }
+
+    /// Sets the attributes of this block, replacing previously set attributes.
+    ///
+    /// Returns `false` if this block cannot have attributes.
+    pub(crate) fn set_attributes(&mut self, attributes: Attributes) -> bool {
+        let block_attributes = match self {
+            Block::Blankline(_) | Block::LineBreak(_) | Block::PageBreak(_) | Block::Comment(_) => {
+                return false
+            }
+            Block::Heading(block) => &mut block.attributes,
+            Block::Paragraph(block) => &mut block.attributes,
+            Block::VerbatimBlock(block) => &mut block.attributes,
+            Block::BulletList(block) => &mut block.attributes,
+            Block::BulletListEntry(block) => &mut block.attributes,
+            Block::NumberedList(block) => &mut block.attributes,
+            Block::NumberedListEntry(block) => &mut block.attributes,
+            Block::DefinitionList(block) => &mut block.attributes,
+            Block::Table(block) => &mut block.attributes,
+            Block::Quotation(block) => &mut block.attributes,
+            Block::LineBlock(block) => &mut block.attributes,
+            Block::MathBlock(block) => &mut block.attributes,
+            Block::Columns(block) => &mut block.attributes,
+            Block::TextBlock(block) => &mut block.attributes,
+            Block::MediaInsert(block) => &mut block.attributes,
+            Block::RenderBlock(block) => &mut block.attributes,
+            Block::FootnoteDefinition(block) => &mut block.attributes,
+            Block::AbbreviationDefinition(block) => &mut block.attributes,
+            Block::HorizontalLine(block) => &mut block.attributes,
+        };
+
+        *block_attributes = Some(attributes);
+        true
+    }
 }
 
 impl BlockElement for Block {
     fn as_unimarkup(&self) -> String {
-        match self {
+        let s = match self {
             Block::Blankline(_) => String::default(), // Newline is pushed after every block, so blankline is empty on its own
             Block::Heading(block) => block.as_unimarkup(),
             Block::Paragraph(block) => block.as_unimarkup(),
             Block::VerbatimBlock(block) => block.as_unimarkup(),
             Block::BulletList(block) => block.as_unimarkup(),
             Block::BulletListEntry(block) => block.as_unimarkup(),
+            Block::NumberedList(block) => block.as_unimarkup(),
+            Block::NumberedListEntry(block) => block.as_unimarkup(),
+            Block::DefinitionList(block) => block.as_unimarkup(),
+            Block::Table(block) => block.as_unimarkup(),
+            Block::Quotation(block) => block.as_unimarkup(),
+            Block::LineBlock(block) => block.as_unimarkup(),
+            Block::MathBlock(block) => block.as_unimarkup(),
+            Block::Columns(block) => block.as_unimarkup(),
+            Block::TextBlock(block) => block.as_unimarkup(),
+            Block::MediaInsert(block) => block.as_unimarkup(),
+            Block::RenderBlock(block) => block.as_unimarkup(),
+            Block::FootnoteDefinition(block) => block.as_unimarkup(),
+            Block::AbbreviationDefinition(block) => block.as_unimarkup(),
+            Block::HorizontalLine(block) => block.as_unimarkup(),
+            Block::LineBreak(block) => block.as_unimarkup(),
+            Block::PageBreak(block) => block.as_unimarkup(),
+            Block::Comment(block) => block.as_unimarkup(),
+        };
+
+        match self.attributes() {
+            // Headings, verbatim blocks, and text blocks set attributes in their first line
+            Some(attributes)
+                if !matches!(
+                    self,
+                    Block::Heading(_) | Block::VerbatimBlock(_) | Block::TextBlock(_)
+                ) =>
+            {
+                format!(
+                    "{s}{}{}",
+                    SymbolKind::Newline.as_str(),
+                    attributes.as_unimarkup()
+                )
+            }
+            _ => s,
         }
     }
 
@@ -62,6 +236,23 @@ impl BlockElement for Block {
             Block::VerbatimBlock(block) => block.start(),
             Block::BulletList(block) => block.start(),
             Block::BulletListEntry(block) => block.start(),
+            Block::NumberedList(block) => block.start(),
+            Block::NumberedListEntry(block) => block.start(),
+            Block::DefinitionList(block) => block.start(),
+            Block::Table(block) => block.start(),
+            Block::Quotation(block) => block.start(),
+            Block::LineBlock(block) => block.start(),
+            Block::MathBlock(block) => block.start(),
+            Block::Columns(block) => block.start(),
+            Block::TextBlock(block) => block.start(),
+            Block::MediaInsert(block) => block.start(),
+            Block::RenderBlock(block) => block.start(),
+            Block::FootnoteDefinition(block) => block.start(),
+            Block::AbbreviationDefinition(block) => block.start(),
+            Block::HorizontalLine(block) => block.start(),
+            Block::LineBreak(block) => block.start(),
+            Block::PageBreak(block) => block.start(),
+            Block::Comment(block) => block.start(),
         }
     }
 
@@ -73,6 +264,23 @@ impl BlockElement for Block {
             Block::VerbatimBlock(block) => block.end(),
             Block::BulletList(block) => block.end(),
             Block::BulletListEntry(block) => block.end(),
+            Block::NumberedList(block) => block.end(),
+            Block::NumberedListEntry(block) => block.end(),
+            Block::DefinitionList(block) => block.end(),
+            Block::Table(block) => block.end(),
+            Block::Quotation(block) => block.end(),
+            Block::LineBlock(block) => block.end(),
+            Block::MathBlock(block) => block.end(),
+            Block::Columns(block) => block.end(),
+            Block::TextBlock(block) => block.end(),
+            Block::MediaInsert(block) => block.end(),
+            Block::RenderBlock(block) => block.end(),
+            Block::FootnoteDefinition(block) => block.end(),
+            Block::AbbreviationDefinition(block) => block.end(),
+            Block::HorizontalLine(block) => block.end(),
+            Block::LineBreak(block) => block.end(),
+            Block::PageBreak(block) => block.end(),
+            Block::Comment(block) => block.end(),
         }
     }
 }
@@ -140,3 +348,20 @@ impl_from!(VerbatimBlock from VerbatimBlock);
 impl_from!(Paragraph from Paragraph);
 impl_from!(BulletList from BulletList);
 impl_from!(BulletListEntry from BulletListEntry);
+impl_from!(NumberedList from NumberedList);
+impl_from!(NumberedListEntry from NumberedListEntry);
+impl_from!(DefinitionList from DefinitionList);
+impl_from!(Table from Table);
+impl_from!(Quotation from Quotation);
+impl_from!(LineBlock from LineBlock);
+impl_from!(MathBlock from MathBlock);
+impl_from!(Columns from Columns);
+impl_from!(TextBlock from TextBlock);
+impl_from!(MediaInsert from MediaInsert);
+impl_from!(RenderBlock from RenderBlock);
+impl_from!(FootnoteDefinition from FootnoteDefinition);
+impl_from!(AbbreviationDefinition from AbbreviationDefinition);
+impl_from!(HorizontalLine from HorizontalLine);
+impl_from!(LineBreak from LineBreak);
+impl_from!(PageBreak from PageBreak);
+impl_from!(Comment from Comment);
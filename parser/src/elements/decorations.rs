@@ -0,0 +1,218 @@
+//! Contains the structs and parsers for decorations (`===` titles and `+++` captions) of block elements.
+
+use std::rc::Rc;
+
+use unimarkup_commons::lexer::{
+    symbol::SymbolKind,
+    token::{iterator::EndMatcher, TokenKind},
+};
+use unimarkup_inline::{
+    element::{Inline, InlineElement},
+    parser,
+};
+
+use crate::{elements::blocks::Block, BlockContext, BlockParser};
+
+/// Number of symbols that open and close a decoration.
+const DECORATION_LEN: usize = 3;
+
+/// Title and caption of a block element.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Decorations {
+    /// Optional title set with `===`.
+    pub title: Option<Vec<Inline>>,
+    /// Optional caption set with `+++`.
+    pub caption: Option<Vec<Inline>>,
+    /// Number of the decorated element, counted per block kind in document order starting at `1`.
+    /// Only set if a title or caption is set.
+    pub number: Option<usize>,
+}
+
+impl Decorations {
+    /// Returns `true` if neither title nor caption is set.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.caption.is_none()
+    }
+
+    /// Returns the given Unimarkup content of the decorated element, with the title set before,
+    /// and the caption set after the content.
+    pub fn wrap_unimarkup(&self, content: String) -> String {
+        let mut s = String::new();
+
+        if let Some(title) = &self.title {
+            let equals = SymbolKind::Equals.as_str().repeat(DECORATION_LEN);
+            s.push_str(&format!("{equals}\n{}\n{equals}\n", title.as_unimarkup()));
+        }
+
+        s.push_str(&content);
+
+        if let Some(caption) = &self.caption {
+            let plus = SymbolKind::Plus.as_str().repeat(DECORATION_LEN);
+            s.push_str(&format!("\n{plus}\n{}\n{plus}", caption.as_unimarkup()));
+        }
+
+        s
+    }
+}
+
+/// Enum representing the kinds of decorations.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DecorationKind {
+    /// Title set with `===`.
+    Title,
+    /// Caption set with `+++`.
+    Caption,
+}
+
+impl DecorationKind {
+    fn token_kind(&self) -> TokenKind {
+        match self {
+            DecorationKind::Title => TokenKind::Equals(DECORATION_LEN),
+            DecorationKind::Caption => TokenKind::Plus(DECORATION_LEN),
+        }
+    }
+}
+
+/// One parsed decoration that is not yet attached to a block element.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub(crate) struct Decoration {
+    kind: DecorationKind,
+    content: Vec<Inline>,
+}
+
+impl Decoration {
+    /// Tries to parse a decoration at the current position of the given [`BlockParser`].
+    ///
+    /// The closing line of the decoration is consumed, but the line end after it is not consumed.
+    pub(crate) fn parse(parser: &mut BlockParser<'_, '_>) -> Option<Self> {
+        let kind = match parser.iter.next()?.kind {
+            TokenKind::Equals(DECORATION_LEN) => DecorationKind::Title,
+            TokenKind::Plus(DECORATION_LEN) => DecorationKind::Caption,
+            _ => return None,
+        };
+        let close = [TokenKind::Newline, kind.token_kind()];
+
+        if !line_ends(parser) || parser.iter.next()?.kind != TokenKind::Newline {
+            return None;
+        }
+
+        let (iter, inline_context, parsed_inlines) = parser::parse_inlines(
+            parser.iter.clone(),
+            (&parser.context).into(),
+            None,
+            Some(Rc::new(move |matcher: &mut dyn EndMatcher| {
+                matcher.matches(&close) || matcher.is_blank_line() || matcher.outer_end()
+            })),
+        );
+        parser.iter = iter;
+        parser.context.update_from(inline_context);
+
+        let content = parsed_inlines.to_inlines();
+
+        if content.is_empty() || !parser.iter.consumed_matches(&close) || !line_ends(parser) {
+            return None;
+        }
+
+        Some(Decoration { kind, content })
+    }
+
+    /// Attaches this decoration to the given block, and numbers the block if it was not decorated before.
+    ///
+    /// Returns `false` if the block cannot be decorated, or already has a decoration of the same kind.
+    pub(crate) fn attach_to(self, block: &mut Block, context: &mut BlockContext) -> bool {
+        let kind = block.variant_str();
+        let Some(decorations) = block.decorations_mut() else {
+            return false;
+        };
+
+        let slot = match self.kind {
+            DecorationKind::Title => &mut decorations.title,
+            DecorationKind::Caption => &mut decorations.caption,
+        };
+
+        if slot.is_some() {
+            return false;
+        }
+
+        *slot = Some(self.content);
+
+        if decorations.number.is_none() {
+            let number = context.decoration_numbers.entry(kind).or_default();
+            *number += 1;
+            decorations.number = Some(*number);
+        }
+
+        true
+    }
+}
+
+/// Tries to parse decorations followed by the block element they belong to.
+///
+/// Returns the block parser, and the optional decorated block.
+pub(crate) fn parse_decorated<'s, 'i>(
+    mut parser: BlockParser<'s, 'i>,
+) -> (BlockParser<'s, 'i>, Option<Block>) {
+    let mut decorations = Vec::new();
+
+    while matches!(
+        parser.iter.peek_kind(),
+        Some(TokenKind::Equals(DECORATION_LEN) | TokenKind::Plus(DECORATION_LEN))
+    ) {
+        let Some(decoration) = Decoration::parse(&mut parser) else {
+            return (parser, None);
+        };
+
+        if decorations
+            .iter()
+            .any(|prev: &Decoration| prev.kind == decoration.kind)
+        {
+            return (parser, None);
+        }
+        decorations.push(decoration);
+
+        // The decorated block must start in the line directly after the decoration
+        if parser.iter.next().map(|t| t.kind) != Some(TokenKind::Newline) {
+            return (parser, None);
+        }
+    }
+
+    let Some(kind) = parser.iter.peek_kind() else {
+        return (parser, None);
+    };
+
+    for parser_fn in crate::parser::get_parser_fn(kind.into(), &parser.context) {
+        let checkpoint = parser.iter.checkpoint();
+        let (updated_parser, block_opt) = parser_fn(parser);
+        parser = updated_parser;
+
+        if let Some(mut block) = block_opt {
+            let decorable = block.decorations_mut().is_some_and(|d| d.is_empty());
+            if decorable
+                && decorations
+                    .into_iter()
+                    .all(|decoration| decoration.attach_to(&mut block, &mut parser.context))
+            {
+                return (parser, Some(block));
+            }
+
+            return (parser, None);
+        }
+
+        parser.iter.rollback(checkpoint);
+    }
+
+    (parser, None)
+}
+
+/// Consumes spaces until the end of the line, and returns `true` if no other token is in this line.
+/// The line end is not consumed.
+fn line_ends(parser: &mut BlockParser<'_, '_>) -> bool {
+    while parser.iter.peek_kind() == Some(TokenKind::Whitespace) {
+        parser.iter.next();
+    }
+
+    matches!(
+        parser.iter.peek_kind(),
+        None | Some(TokenKind::Newline | TokenKind::Blankline | TokenKind::Eoi)
+    )
+}
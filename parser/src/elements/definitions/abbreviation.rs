@@ -0,0 +1,165 @@
+//! Contains the structs and parsers to parse abbreviation definitions.
+
+use std::rc::Rc;
+
+use unimarkup_commons::attributes::Attributes;
+use unimarkup_commons::lexer::{
+    position::Position,
+    symbol::SymbolKind,
+    token::{
+        iterator::{EndMatcher, PrefixMatcher},
+        Token, TokenKind,
+    },
+};
+use unimarkup_inline::element::{Inline, InlineElement};
+
+use crate::{
+    elements::{blocks::Block, BlockElement},
+    BlockParser,
+};
+
+use super::{LINE_PREFIX, NEXT_DEFINITION};
+
+/// Structure of a Unimarkup abbreviation definition (e.g. `_[::xml] Extensible Markup Language`).
+/// The expansion is shown wherever the abbreviation is referenced (e.g. `[::xml]_`).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AbbreviationDefinition {
+    /// The abbreviation that is defined.
+    pub abbreviation: String,
+    /// The expansion of the abbreviation.
+    pub expansion: Vec<Inline>,
+    /// Optional attributes of this abbreviation definition.
+    pub attributes: Option<Attributes>,
+    /// The start of this abbreviation definition in the original content.
+    pub start: Position,
+    /// The end of this abbreviation definition in the original content.
+    pub end: Position,
+}
+
+impl BlockElement for AbbreviationDefinition {
+    fn as_unimarkup(&self) -> String {
+        let prefix = SymbolKind::Underline.as_str();
+
+        self.expansion
+            .as_unimarkup()
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                if i == 0 {
+                    format!("{prefix}[::{}] {line}", self.abbreviation)
+                } else {
+                    format!("{prefix} {line}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(SymbolKind::Newline.as_str())
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+const ABBREVIATION_DEFINITION_START: &[TokenKind] = &[
+    TokenKind::Underline(1),
+    TokenKind::OpenBracket,
+    TokenKind::Colon(2),
+];
+
+impl AbbreviationDefinition {
+    /// Tries to create an abbreviation definition from the current position of the given [`BlockParser`].
+    ///
+    /// Returns the block parser, and the optional abbreviation definition.
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<Block>) {
+        if !parser.iter.matches(ABBREVIATION_DEFINITION_START) {
+            return (parser, None);
+        }
+
+        let start_token = parser
+            .iter
+            .next()
+            .expect("Abbreviation definition start matched above.");
+        parser.iter.next(); // Consume open bracket
+        parser.iter.next(); // Consume colons
+
+        let abbr_tokens: Vec<_> = parser
+            .iter
+            .by_ref()
+            .take_while(|t| t.kind != TokenKind::CloseBracket)
+            .copied()
+            .collect();
+
+        // The abbreviation must be on one line, and must be followed by at least one space
+        if parser.iter.prev_kind() != Some(TokenKind::CloseBracket)
+            || abbr_tokens
+                .iter()
+                .any(|t| matches!(t.kind, TokenKind::Newline | TokenKind::Blankline))
+            || parser.iter.next().map(|t| t.kind) != Some(TokenKind::Whitespace)
+        {
+            return (parser, None);
+        }
+
+        let Some(abbreviation) = Token::flatten(&abbr_tokens)
+            .filter(|abbr| abbr.trim() == *abbr)
+            .map(String::from)
+        else {
+            return (parser, None);
+        };
+
+        let content_parser = parser.nest(
+            Some(Rc::new(|matcher: &mut dyn PrefixMatcher| {
+                matcher.consumed_prefix(LINE_PREFIX)
+            })),
+            Some(Rc::new(|matcher: &mut dyn EndMatcher| {
+                matcher.matches(NEXT_DEFINITION)
+            })),
+        );
+        let (updated_parser, content) = BlockParser::parse(content_parser);
+        parser = updated_parser.into_inner();
+
+        // The expansion is inline content, so it must form exactly one paragraph
+        let [Block::Paragraph(paragraph)] = content.as_slice() else {
+            return (parser, None);
+        };
+
+        let definition = AbbreviationDefinition {
+            abbreviation,
+            expansion: paragraph.content.clone(),
+            attributes: None,
+            start: start_token.start,
+            end: paragraph.end(),
+        };
+        parser
+            .context
+            .abbreviation_definitions
+            .push(definition.clone());
+
+        (parser, Some(Block::AbbreviationDefinition(definition)))
+    }
+}
+
+/// Returns the given abbreviation definitions without duplicates.
+///
+/// If an abbreviation is defined multiple times, the first definition is used.
+pub(crate) fn abbreviation_table(
+    definitions: Vec<AbbreviationDefinition>,
+) -> Vec<AbbreviationDefinition> {
+    let mut abbreviations: Vec<AbbreviationDefinition> = Vec::new();
+
+    for definition in definitions {
+        if !abbreviations
+            .iter()
+            .any(|abbr| abbr.abbreviation == definition.abbreviation)
+        {
+            abbreviations.push(definition);
+        }
+    }
+
+    abbreviations
+}
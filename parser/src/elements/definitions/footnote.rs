@@ -0,0 +1,177 @@
+//! Contains the structs and parsers to parse footnote definitions.
+
+use std::rc::Rc;
+
+use logid::log;
+use unimarkup_commons::attributes::Attributes;
+use unimarkup_commons::lexer::{
+    position::Position,
+    symbol::SymbolKind,
+    token::{
+        iterator::{EndMatcher, PrefixMatcher},
+        Token, TokenKind,
+    },
+};
+
+use crate::{
+    elements::{blocks::Block, BlockElement, Blocks},
+    log_id::ParserWarning,
+    BlockParser,
+};
+
+use super::{LINE_PREFIX, NEXT_DEFINITION};
+
+/// Structure of a Unimarkup footnote definition.
+/// The content is shown at the position of the first reference to this footnote (e.g. `[^^footnote-id]_`).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FootnoteDefinition {
+    /// The ID used to reference this footnote.
+    pub id: String,
+    /// The content of this footnote.
+    pub content: Blocks,
+    /// Optional attributes of this footnote definition.
+    pub attributes: Option<Attributes>,
+    /// The start of this footnote definition in the original content.
+    pub start: Position,
+    /// The end of this footnote definition in the original content.
+    pub end: Position,
+}
+
+impl BlockElement for FootnoteDefinition {
+    fn as_unimarkup(&self) -> String {
+        let prefix = SymbolKind::Underline.as_str();
+
+        self.content
+            .as_unimarkup()
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                if i == 0 {
+                    format!("{prefix}[^^{}] {line}", self.id)
+                } else if line.is_empty() {
+                    prefix.to_string()
+                } else {
+                    format!("{prefix} {line}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(SymbolKind::Newline.as_str())
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+const FOOTNOTE_DEFINITION_START: &[TokenKind] = &[
+    TokenKind::Underline(1),
+    TokenKind::OpenBracket,
+    TokenKind::Caret(2),
+];
+
+impl FootnoteDefinition {
+    /// Tries to create a footnote definition from the current position of the given [`BlockParser`].
+    ///
+    /// Returns the block parser, and the optional footnote definition.
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<Block>) {
+        if !parser.iter.matches(FOOTNOTE_DEFINITION_START) {
+            return (parser, None);
+        }
+
+        let start_token = parser
+            .iter
+            .next()
+            .expect("Footnote definition start matched above.");
+        parser.iter.next(); // Consume open bracket
+        parser.iter.next(); // Consume carets
+
+        let id_tokens: Vec<_> = parser
+            .iter
+            .by_ref()
+            .take_while(|t| t.kind != TokenKind::CloseBracket)
+            .copied()
+            .collect();
+
+        // The ID must not contain spaces, and must be followed by at least one space
+        if parser.iter.prev_kind() != Some(TokenKind::CloseBracket)
+            || id_tokens.iter().any(|t| t.kind.is_space())
+            || parser.iter.next().map(|t| t.kind) != Some(TokenKind::Whitespace)
+        {
+            return (parser, None);
+        }
+
+        let Some(id) = Token::flatten(&id_tokens).map(String::from) else {
+            return (parser, None);
+        };
+
+        let content_parser = parser.nest(
+            Some(Rc::new(|matcher: &mut dyn PrefixMatcher| {
+                // `_` without space is allowed for empty lines inside the footnote
+                matcher.consumed_prefix(LINE_PREFIX)
+                    || matcher.consumed_prefix(&[TokenKind::Underline(1)])
+            })),
+            Some(Rc::new(|matcher: &mut dyn EndMatcher| {
+                matcher.matches(NEXT_DEFINITION)
+            })),
+        );
+        let (updated_parser, content) = BlockParser::parse(content_parser);
+        parser = updated_parser.into_inner();
+
+        let Some(last_block) = content.last() else {
+            return (parser, None);
+        };
+        let end = last_block.end();
+
+        let definition = FootnoteDefinition {
+            id,
+            content,
+            attributes: None,
+            start: start_token.start,
+            end,
+        };
+        parser.context.footnote_definitions.push(definition.clone());
+
+        (parser, Some(Block::FootnoteDefinition(definition)))
+    }
+}
+
+/// Returns the definitions of all referenced footnotes, ordered by their first reference.
+///
+/// If a footnote is defined multiple times, the first definition is used.
+/// Definitions without reference are not returned.
+pub(crate) fn referenced_footnotes(
+    definitions: Vec<FootnoteDefinition>,
+    references: &[String],
+) -> Vec<FootnoteDefinition> {
+    let mut unique_definitions: Vec<FootnoteDefinition> = Vec::new();
+
+    for definition in definitions {
+        if unique_definitions.iter().any(|def| def.id == definition.id) {
+            log!(
+                ParserWarning::DuplicateFootnoteDefinition,
+                format!(
+                    "Footnote '{}' is defined multiple times. Only the first definition is used.",
+                    definition.id
+                )
+            );
+        } else {
+            unique_definitions.push(definition);
+        }
+    }
+
+    let mut footnotes = Vec::new();
+
+    for id in references {
+        if let Some(index) = unique_definitions.iter().position(|def| &def.id == id) {
+            footnotes.push(unique_definitions.remove(index));
+        }
+    }
+
+    footnotes
+}
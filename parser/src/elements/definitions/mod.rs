@@ -0,0 +1,18 @@
+//! Contains definition elements that are referenced from other parts of a Unimarkup document.
+
+use unimarkup_commons::lexer::token::TokenKind;
+
+mod abbreviation;
+mod footnote;
+
+pub use abbreviation::*;
+pub use footnote::*;
+
+/// Prefix of every line of a definition after the first one.
+const LINE_PREFIX: &[TokenKind] = &[TokenKind::Underline(1), TokenKind::Space];
+/// A new definition must start at a new line, and ends the content of the previous one.
+const NEXT_DEFINITION: &[TokenKind] = &[
+    TokenKind::Newline,
+    TokenKind::Underline(1),
+    TokenKind::OpenBracket,
+];
@@ -0,0 +1,312 @@
+//! Contains structs and parsers to create column blocks.
+
+use std::rc::Rc;
+
+use unimarkup_commons::attributes::Attributes;
+use unimarkup_commons::lexer::{
+    position::Position,
+    symbol::SymbolKind,
+    token::{iterator::EndMatcher, TokenKind},
+};
+
+use crate::{
+    elements::{atomic::HeadingLevel, blocks::Block, BlockElement, Blocks},
+    BlockParser,
+};
+
+/// Structure of a Unimarkup column block.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Columns {
+    /// The content of each column.
+    pub columns: Vec<Blocks>,
+    /// Defines how the content was split into columns.
+    pub kind: ColumnsKind,
+    /// The number of pipes this column block was created with.
+    pub pipe_len: usize,
+    /// Marks that this column block was implicitly closed.
+    pub implicit_closed: bool,
+    /// Optional attributes of this column block.
+    pub attributes: Option<Attributes>,
+    /// The start of this block in the original content.
+    pub start: Position,
+    /// The end of this block in the original content.
+    pub end: Position,
+}
+
+/// Defines how the content of a [`Columns`] block is split into columns.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ColumnsKind {
+    /// Columns are explicitly separated by `:::` lines.
+    Explicit,
+    /// Content is automatically split at headings, and distributed over the given number of columns.
+    Implicit {
+        /// The number of columns set with `|||N|`.
+        count: usize,
+        /// Headings of this level or higher start a new section that may be moved to the next column.
+        split_level: HeadingLevel,
+    },
+}
+
+impl Columns {
+    /// Returns the number of columns the content should be laid out in.
+    pub fn column_count(&self) -> usize {
+        match self.kind {
+            ColumnsKind::Explicit => self.columns.len(),
+            ColumnsKind::Implicit { count, .. } => count,
+        }
+    }
+}
+
+impl BlockElement for Columns {
+    fn as_unimarkup(&self) -> String {
+        let pipes = SymbolKind::Pipe.as_str().repeat(self.pipe_len);
+
+        match self.kind {
+            ColumnsKind::Explicit => {
+                let separator = format!("\n\n{}\n\n", SymbolKind::Colon.as_str().repeat(3));
+                let content = self
+                    .columns
+                    .iter()
+                    .map(|column| column.as_unimarkup())
+                    .collect::<Vec<_>>()
+                    .join(&separator);
+
+                format!(
+                    "{}\n{}\n{}",
+                    &pipes,
+                    content,
+                    if self.implicit_closed { "" } else { &pipes }
+                )
+            }
+            ColumnsKind::Implicit { count, split_level } => {
+                let content = self
+                    .columns
+                    .iter()
+                    .map(|column| column.as_unimarkup())
+                    .collect::<Vec<_>>()
+                    .join(SymbolKind::Newline.as_str());
+                let close = if self.implicit_closed {
+                    String::default()
+                } else {
+                    format!(
+                        "{}{}{}",
+                        &pipes,
+                        split_level.as_str(),
+                        SymbolKind::Pipe.as_str()
+                    )
+                };
+
+                format!(
+                    "{}{}{}\n{}\n{}",
+                    &pipes,
+                    count,
+                    SymbolKind::Pipe.as_str(),
+                    content,
+                    close
+                )
+            }
+        }
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+const COLUMN_SEPARATOR: &[TokenKind] = &[
+    TokenKind::Newline,
+    TokenKind::Colon(3),
+    TokenKind::EnclosedBlockEnd,
+];
+
+impl Columns {
+    /// Tries to create a column block from the current position of the given [`BlockParser`].
+    ///
+    /// Returns the block parser, and the optional column block.
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<Block>) {
+        let Some(open_token) = parser.iter.next() else {
+            return (parser, None);
+        };
+
+        let pipe_len = match open_token.kind {
+            TokenKind::Pipe(len) if len >= 3 => len,
+            _ => return (parser, None),
+        };
+
+        // `|||N|` sets the number of columns for implicit columns
+        let mut implicit_count = None;
+        if parser.iter.peek_kind() == Some(TokenKind::Plain) {
+            let count_token = parser.iter.next().expect("Peeked above to be some token.");
+            let count = String::from(count_token)
+                .parse::<usize>()
+                .ok()
+                .filter(|count| *count > 0);
+
+            match (count, parser.iter.next().map(|t| t.kind)) {
+                (Some(count), Some(TokenKind::Pipe(1))) => implicit_count = Some(count),
+                _ => return (parser, None),
+            }
+        }
+
+        // Only spaces are allowed until the end of the opening line
+        if parser
+            .iter
+            .by_ref()
+            .take_while(|t| !matches!(t.kind, TokenKind::Blankline | TokenKind::Newline))
+            .any(|t| !t.kind.is_space())
+        {
+            return (parser, None);
+        }
+
+        let (columns, kind, closed) = match implicit_count {
+            Some(count) => {
+                let (updated_parser, content) = Self::parse_column(parser, pipe_len, false);
+                parser = updated_parser;
+
+                let close_level = (1..=6).find(|level| {
+                    parser
+                        .iter
+                        .consumed_matches(&implicit_close_sequence(pipe_len, *level))
+                });
+                let closed = close_level.is_some()
+                    || parser
+                        .iter
+                        .consumed_matches(&explicit_close_sequence(pipe_len));
+                let split_level = close_level
+                    .and_then(|level| HeadingLevel::try_from(level).ok())
+                    .unwrap_or(HeadingLevel::Level1);
+
+                (
+                    split_at_headings(content, count, split_level),
+                    ColumnsKind::Implicit { count, split_level },
+                    closed,
+                )
+            }
+            None => {
+                let mut columns = Vec::new();
+
+                loop {
+                    let (updated_parser, content) = Self::parse_column(parser, pipe_len, true);
+                    parser = updated_parser;
+                    columns.push(content);
+
+                    if !parser.iter.consumed_matches(COLUMN_SEPARATOR) {
+                        break;
+                    }
+                }
+
+                let closed = parser
+                    .iter
+                    .consumed_matches(&explicit_close_sequence(pipe_len));
+                (columns, ColumnsKind::Explicit, closed)
+            }
+        };
+        let implicit_closed = !closed;
+
+        let prev = parser
+            .iter
+            .prev()
+            .expect("Must be some token, because at least start tokens came before.");
+        let block_end = if implicit_closed {
+            prev.end
+        } else {
+            prev.start // Start position, because previous was either blankline, or end of input
+        };
+
+        (
+            parser,
+            Some(Block::Columns(Columns {
+                columns,
+                kind,
+                pipe_len,
+                implicit_closed,
+                attributes: None,
+                start: open_token.start,
+                end: block_end,
+            })),
+        )
+    }
+
+    /// Parses blocks until the closing sequence, or a column separator for explicit columns is reached.
+    fn parse_column<'s, 'i>(
+        parser: BlockParser<'s, 'i>,
+        pipe_len: usize,
+        allow_separator: bool,
+    ) -> (BlockParser<'s, 'i>, Blocks) {
+        let content_parser = parser.nest_scoped(
+            None,
+            Some(Rc::new(move |matcher: &mut dyn EndMatcher| {
+                if allow_separator {
+                    matcher.matches(COLUMN_SEPARATOR)
+                        || matcher.matches(&explicit_close_sequence(pipe_len))
+                } else {
+                    matcher.matches(&explicit_close_sequence(pipe_len))
+                        || (1..=6)
+                            .any(|level| matcher.matches(&implicit_close_sequence(pipe_len, level)))
+                }
+            })),
+        );
+
+        let (updated_parser, content) = BlockParser::parse(content_parser);
+        (updated_parser.into_inner(), content)
+    }
+}
+
+/// Token sequence closing a column block with `|||`.
+fn explicit_close_sequence(pipe_len: usize) -> [TokenKind; 3] {
+    [
+        TokenKind::Newline,
+        TokenKind::Pipe(pipe_len),
+        TokenKind::EnclosedBlockEnd,
+    ]
+}
+
+/// Token sequence closing implicit columns with `|||#|`, where the hashes set the split level.
+fn implicit_close_sequence(pipe_len: usize, level: usize) -> [TokenKind; 5] {
+    [
+        TokenKind::Newline,
+        TokenKind::Pipe(pipe_len),
+        TokenKind::Hash(level),
+        TokenKind::Pipe(1),
+        TokenKind::EnclosedBlockEnd,
+    ]
+}
+
+/// Splits the given blocks into sections starting at headings of the given level or higher,
+/// and distributes the sections evenly over at most `count` columns.
+///
+/// Blocks before the first heading are part of the first section.
+fn split_at_headings(blocks: Blocks, count: usize, split_level: HeadingLevel) -> Vec<Blocks> {
+    let mut sections: Vec<Blocks> = Vec::new();
+    let mut section = Vec::new();
+    let mut heading_in_section = false;
+
+    for block in blocks {
+        if let Block::Heading(heading) = &block {
+            if u8::from(heading.level) <= u8::from(split_level) {
+                if heading_in_section {
+                    sections.push(std::mem::take(&mut section));
+                }
+                heading_in_section = true;
+            }
+        }
+
+        section.push(block);
+    }
+
+    if !section.is_empty() {
+        sections.push(section);
+    }
+
+    let sections_per_column = sections.len().div_ceil(count).max(1);
+    sections
+        .chunks(sections_per_column)
+        .map(|sections| sections.concat())
+        .collect()
+}
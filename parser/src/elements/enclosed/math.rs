@@ -0,0 +1,173 @@
+//! Contains structs and parsers to create math blocks.
+
+use std::rc::Rc;
+
+use unimarkup_commons::attributes::Attributes;
+use unimarkup_commons::lexer::position::Position;
+use unimarkup_commons::lexer::token::iterator::EndMatcher;
+use unimarkup_commons::lexer::token::{Token, TokenKind};
+
+use crate::elements::{attributes, BlockElement};
+use crate::{elements::blocks::Block, BlockParser};
+use unimarkup_commons::lexer::symbol::SymbolKind;
+
+/// Structure of a Unimarkup math block element.
+/// The content is AsciiMath notation that is rendered in display mode.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MathBlock {
+    /// The math content of the block.
+    pub content: String,
+    /// Optional equation id set after the opening dollar signs, used to reference the equation.
+    pub id: Option<String>,
+    /// Marks that this math block was implicitly closed.
+    pub implicit_closed: bool,
+    /// The number of dollar signs this math block was created with.
+    pub dollar_len: usize,
+    /// Optional attributes of this math block.
+    pub attributes: Option<Attributes>,
+    /// The start of this block in the original content.
+    pub start: Position,
+    /// The end of this block in the original content.
+    pub end: Position,
+}
+
+impl BlockElement for MathBlock {
+    fn as_unimarkup(&self) -> String {
+        let dollars = SymbolKind::Dollar.as_str().repeat(self.dollar_len);
+        let id = match (&self.id, &self.attributes) {
+            (Some(id), _) => id.clone(),
+            (None, Some(attributes)) => attributes.as_unimarkup().to_string(),
+            (None, None) => String::new(),
+        };
+        format!(
+            "{}{}\n{}\n{}",
+            &dollars,
+            id,
+            self.content,
+            if self.implicit_closed { "" } else { &dollars }
+        )
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+impl MathBlock {
+    /// Returns the id of the equation.
+    /// An id set with attributes takes precedence over the one set after the opening dollar signs.
+    pub fn id(&self) -> Option<&str> {
+        self.attributes
+            .as_ref()
+            .and_then(Attributes::id)
+            .or(self.id.as_deref())
+    }
+
+    /// Tries to create a math block from the current position of the given [`BlockParser`].
+    ///
+    /// Returns the block parser, and the optional math block.
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<Block>) {
+        let Some(open_token) = parser.iter.next() else {
+            return (parser, None);
+        };
+
+        let dollar_len = match open_token.kind {
+            TokenKind::Dollar(len) if len >= 3 => len,
+            _ => return (parser, None),
+        };
+
+        let mut attributes = None;
+
+        let id = if parser.iter.peek_kind() == Some(TokenKind::OpenBrace) {
+            // Attributes may be set instead of the id, e.g. `$$${ "id": "eq-fraction" }`
+            attributes = attributes::parse_attributes(&mut parser);
+            None
+        } else {
+            let id_tokens: Vec<_> = parser
+                .iter
+                .by_ref()
+                .take_while(|s| !s.kind.is_space())
+                .copied()
+                .collect();
+            Token::flatten(&id_tokens).map(String::from)
+        };
+
+        // invalid id => invalid math block, take as paragraph
+        if id.as_deref().is_some_and(|id| !is_identifier(id)) {
+            return (parser, None);
+        }
+
+        // exit if non-space content is given after the id ended
+        // => invalid math block, take as paragraph
+        if !matches!(
+            parser.iter.prev_kind(),
+            Some(TokenKind::Blankline) | Some(TokenKind::Newline)
+        ) && parser
+            .iter
+            .by_ref()
+            .take_while(|t| !matches!(t.kind, TokenKind::Blankline | TokenKind::Newline))
+            .any(|t| !t.kind.is_space())
+        {
+            return (parser, None);
+        }
+
+        let prev_context_flags = parser.context.flags;
+        let mut content_parser = parser.nest_scoped(
+            None,
+            Some(Rc::new(move |matcher: &mut dyn EndMatcher| {
+                matcher.consumed_matches(&[
+                    TokenKind::Newline,
+                    TokenKind::Dollar(dollar_len),
+                    TokenKind::EnclosedBlockEnd,
+                ])
+            })),
+        );
+        content_parser.context.flags.logic_only = true;
+        content_parser.context.flags.keep_whitespaces = true;
+        content_parser.context.flags.keep_newline = true;
+
+        let (updated_content_parser, content) = BlockParser::parse(content_parser);
+        content_parser = updated_content_parser;
+        let implicit_closed = !content_parser.iter.end_reached();
+
+        parser = content_parser.into_inner();
+        parser.context.flags = prev_context_flags;
+
+        let prev = parser
+            .iter
+            .prev()
+            .expect("Must be some token, because at least start tokens came before.");
+        let block_end = if implicit_closed {
+            prev.end
+        } else {
+            prev.start // Start position, because previous was either blankline, attribute start, or decorator start
+        };
+
+        (
+            parser,
+            Some(Block::MathBlock(MathBlock {
+                content: content.as_unimarkup(),
+                id,
+                implicit_closed,
+                dollar_len,
+                attributes,
+                start: open_token.start,
+                end: block_end,
+            })),
+        )
+    }
+}
+
+/// Returns `true` if the given id only consists of alphanumeric characters, `-`, `_`, `.`, or `:`.
+fn is_identifier(id: &str) -> bool {
+    !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'))
+}
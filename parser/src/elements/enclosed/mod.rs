@@ -1,5 +1,15 @@
 //! Contains enclosed elements
 
+mod columns;
+mod math;
+mod render;
+mod table;
+mod text_block;
 mod verbatim;
 
+pub use columns::*;
+pub use math::*;
+pub use render::*;
+pub use table::*;
+pub use text_block::*;
 pub use verbatim::*;
@@ -0,0 +1,144 @@
+//! Contains structs and parsers to create render blocks.
+
+use std::rc::Rc;
+
+use unimarkup_commons::attributes::Attributes;
+use unimarkup_commons::lexer::position::Position;
+use unimarkup_commons::lexer::token::iterator::EndMatcher;
+use unimarkup_commons::lexer::token::{Token, TokenKind};
+
+use crate::elements::BlockElement;
+use crate::{elements::blocks::Block, BlockParser};
+use unimarkup_commons::lexer::symbol::SymbolKind;
+
+/// Structure of a Unimarkup render block element.
+/// The content is passed to a renderer for the given language (e.g. `mermaid` or `dot`),
+/// instead of being shown as is.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RenderBlock {
+    /// The content of the render block.
+    pub content: String,
+    /// The language used to render the content.
+    pub lang: String,
+    /// Marks that this render block was implicitly closed.
+    pub implicit_closed: bool,
+    /// The number of single quotes this render block was created with.
+    pub quote_len: usize,
+    /// Optional attributes of this render block.
+    pub attributes: Option<Attributes>,
+    /// The start of this block in the original content.
+    pub start: Position,
+    /// The end of this block in the original content.
+    pub end: Position,
+}
+
+impl BlockElement for RenderBlock {
+    fn as_unimarkup(&self) -> String {
+        let quotes = SymbolKind::SingleQuote.as_str().repeat(self.quote_len);
+        format!(
+            "{}{}\n{}\n{}",
+            &quotes,
+            self.lang,
+            self.content,
+            if self.implicit_closed { "" } else { &quotes }
+        )
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+impl RenderBlock {
+    /// Tries to create a render block from the current position of the given [`BlockParser`].
+    ///
+    /// Returns the block parser, and the optional render block.
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<Block>) {
+        let Some(open_token) = parser.iter.next() else {
+            return (parser, None);
+        };
+
+        let quote_len = match open_token.kind {
+            TokenKind::SingleQuote(len) if len >= 3 => len,
+            _ => return (parser, None),
+        };
+
+        let lang_tokens: Vec<_> = parser
+            .iter
+            .by_ref()
+            .take_while(|s| !s.kind.is_space())
+            .copied()
+            .collect();
+
+        // the language is required to select how the content is rendered
+        // => invalid render block, take as paragraph
+        let Some(lang) = Token::flatten(&lang_tokens).map(String::from) else {
+            return (parser, None);
+        };
+
+        // exit if non-space content is given after the language ended
+        // => invalid render block, take as paragraph
+        if !matches!(
+            parser.iter.prev_kind(),
+            Some(TokenKind::Blankline) | Some(TokenKind::Newline)
+        ) && parser
+            .iter
+            .by_ref()
+            .take_while(|t| !matches!(t.kind, TokenKind::Blankline | TokenKind::Newline))
+            .any(|t| !t.kind.is_space())
+        {
+            return (parser, None);
+        }
+
+        let prev_context_flags = parser.context.flags;
+        let mut content_parser = parser.nest_scoped(
+            None,
+            Some(Rc::new(move |matcher: &mut dyn EndMatcher| {
+                matcher.consumed_matches(&[
+                    TokenKind::Newline,
+                    TokenKind::SingleQuote(quote_len),
+                    TokenKind::EnclosedBlockEnd,
+                ])
+            })),
+        );
+        content_parser.context.flags.logic_only = true;
+        content_parser.context.flags.keep_whitespaces = true;
+        content_parser.context.flags.keep_newline = true;
+
+        let (updated_content_parser, content) = BlockParser::parse(content_parser);
+        content_parser = updated_content_parser;
+        let implicit_closed = !content_parser.iter.end_reached();
+
+        parser = content_parser.into_inner();
+        parser.context.flags = prev_context_flags;
+
+        let prev = parser
+            .iter
+            .prev()
+            .expect("Must be some token, because at least start tokens came before.");
+        let block_end = if implicit_closed {
+            prev.end
+        } else {
+            prev.start // Start position, because previous was either blankline, attribute start, or decorator start
+        };
+
+        (
+            parser,
+            Some(Block::RenderBlock(RenderBlock {
+                content: content.as_unimarkup(),
+                lang,
+                implicit_closed,
+                quote_len,
+                attributes: None,
+                start: open_token.start,
+                end: block_end,
+            })),
+        )
+    }
+}
@@ -0,0 +1,649 @@
+//! Contains the structs and parsers to parse table elements.
+
+use unimarkup_commons::attributes::Attributes;
+use unimarkup_commons::lexer::{
+    position::Position,
+    symbol::SymbolKind,
+    token::{iterator::TokenIterator, Token, TokenKind},
+};
+
+use crate::{
+    elements::{blocks::Block, decorations::Decorations, BlockElement, Blocks},
+    BlockContext, BlockParser,
+};
+
+/// Structure of a Unimarkup table element.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Table {
+    /// The rows of this table.
+    pub rows: Vec<TableRow>,
+    /// The number of columns of this table.
+    /// It is defined by the segments of the first border line.
+    pub column_count: usize,
+    /// Optional attributes of this table.
+    pub attributes: Option<Attributes>,
+    /// Optional title and caption of this table.
+    pub decorations: Decorations,
+    /// The start of this table in the original content.
+    pub start: Position,
+    /// The end of this table in the original content.
+    pub end: Position,
+}
+
+/// Structure of one row of a Unimarkup [`Table`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TableRow {
+    /// The cells starting in this row.
+    /// Cells of previous rows that span into this row are not included.
+    pub cells: Vec<TableCell>,
+    /// The start of this row in the original content.
+    pub start: Position,
+    /// The end of this row in the original content.
+    pub end: Position,
+}
+
+/// Structure of one cell of a Unimarkup [`Table`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TableCell {
+    /// The content of this cell.
+    pub content: Blocks,
+    /// The [`TableCellKind`] of this cell.
+    pub kind: TableCellKind,
+    /// The optional [`ColumnAlignment`] of this cell.
+    pub alignment: Option<ColumnAlignment>,
+    /// The number of columns this cell spans.
+    pub column_span: usize,
+    /// The number of rows this cell spans.
+    pub row_span: usize,
+    /// The start of this cell in the original content.
+    pub start: Position,
+    /// The end of this cell in the original content.
+    pub end: Position,
+}
+
+/// Enum representing the kind of a [`TableCell`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TableCellKind {
+    /// Header cell marked with `=` in the border line above the cell.
+    Header,
+    /// Data cell marked with `-` in the border line above the cell.
+    Data,
+    /// Footer cell marked with `_` in the border line above the cell.
+    Footer,
+}
+
+impl TableCellKind {
+    /// Returns the border symbol used to mark cells of this kind.
+    pub fn as_str(&self) -> &str {
+        match self {
+            TableCellKind::Header => SymbolKind::Equals.as_str(),
+            TableCellKind::Data => SymbolKind::Minus.as_str(),
+            TableCellKind::Footer => SymbolKind::Underline.as_str(),
+        }
+    }
+}
+
+/// Enum representing the content alignment of table columns.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ColumnAlignment {
+    /// Left alignment set with `+:-+`.
+    Left,
+    /// Center alignment set with `+:-:+`.
+    Center,
+    /// Right alignment set with `+-:+`.
+    Right,
+}
+
+impl ColumnAlignment {
+    /// Returns the alignment as CSS `text-align` value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ColumnAlignment::Left => "left",
+            ColumnAlignment::Center => "center",
+            ColumnAlignment::Right => "right",
+        }
+    }
+}
+
+impl Table {
+    /// Returns the header rows of this table.
+    /// Header rows are contiguous rows at the start of the table that only contain header cells.
+    pub fn head(&self) -> &[TableRow] {
+        &self.rows[..self.head_len()]
+    }
+
+    /// Returns the body rows of this table.
+    pub fn body(&self) -> &[TableRow] {
+        let head_len = self.head_len();
+        &self.rows[head_len..(self.rows.len() - self.foot_len()).max(head_len)]
+    }
+
+    /// Returns the footer rows of this table.
+    /// Footer rows are contiguous rows at the end of the table that only contain footer cells.
+    pub fn foot(&self) -> &[TableRow] {
+        let foot_start = (self.rows.len() - self.foot_len()).max(self.head_len());
+        &self.rows[foot_start..]
+    }
+
+    fn head_len(&self) -> usize {
+        self.rows
+            .iter()
+            .take_while(|row| row.only_contains(TableCellKind::Header))
+            .count()
+    }
+
+    fn foot_len(&self) -> usize {
+        self.rows
+            .iter()
+            .rev()
+            .take_while(|row| row.only_contains(TableCellKind::Footer))
+            .count()
+    }
+}
+
+impl TableRow {
+    fn only_contains(&self, kind: TableCellKind) -> bool {
+        !self.cells.is_empty() && self.cells.iter().all(|cell| cell.kind == kind)
+    }
+}
+
+/// Width of one column in border lines created by [`Table::as_unimarkup()`].
+const COLUMN_WIDTH: usize = 3;
+
+impl BlockElement for Table {
+    fn as_unimarkup(&self) -> String {
+        enum Slot<'a> {
+            Cell(&'a TableCell),
+            Spanned,
+            Empty,
+        }
+
+        let column_count = self.column_count.max(1);
+        let mut occupied_until = vec![0; column_count];
+        let mut owner_span = vec![1; column_count];
+        let mut lines = Vec::new();
+
+        for (row_index, row) in self.rows.iter().enumerate() {
+            let mut slots = Vec::new();
+            let mut cells = row.cells.iter();
+            let mut column = 0;
+
+            while column < column_count {
+                if occupied_until[column] > row_index {
+                    slots.push((Slot::Spanned, owner_span[column]));
+                    column += owner_span[column];
+                    continue;
+                }
+
+                match cells.next() {
+                    Some(cell) => {
+                        let span = cell.column_span.clamp(1, column_count - column);
+                        for occupied in &mut occupied_until[column..column + span] {
+                            *occupied = row_index + cell.row_span;
+                        }
+                        owner_span[column] = span;
+                        slots.push((Slot::Cell(cell), span));
+                        column += span;
+                    }
+                    None => {
+                        slots.push((Slot::Empty, 1));
+                        column += 1;
+                    }
+                }
+            }
+
+            let mut border = SymbolKind::Plus.as_str().to_string();
+            let mut contents = Vec::with_capacity(slots.len());
+
+            for (slot, span) in &slots {
+                let width = span * (COLUMN_WIDTH + 1) - 1;
+                let segment = match slot {
+                    Slot::Cell(cell) => {
+                        contents.push(cell.content.as_unimarkup());
+
+                        let fill = cell.kind.as_str();
+                        let colon = SymbolKind::Colon.as_str();
+                        match cell.alignment {
+                            Some(ColumnAlignment::Left) => {
+                                format!("{colon}{}", fill.repeat(width - 1))
+                            }
+                            Some(ColumnAlignment::Center) => {
+                                format!("{colon}{}{colon}", fill.repeat(width - 2))
+                            }
+                            Some(ColumnAlignment::Right) => {
+                                format!("{}{colon}", fill.repeat(width - 1))
+                            }
+                            None => fill.repeat(width),
+                        }
+                    }
+                    Slot::Spanned => {
+                        contents.push(String::default());
+                        SymbolKind::Whitespace.as_str().repeat(width)
+                    }
+                    Slot::Empty => {
+                        contents.push(String::default());
+                        SymbolKind::Minus.as_str().repeat(width)
+                    }
+                };
+
+                border.push_str(&segment);
+                border.push_str(SymbolKind::Plus.as_str());
+            }
+
+            lines.push(border);
+
+            let line_cnt = contents
+                .iter()
+                .map(|content| content.lines().count())
+                .max()
+                .unwrap_or_default()
+                .max(1);
+
+            for line_index in 0..line_cnt {
+                let mut line = SymbolKind::Pipe.as_str().to_string();
+
+                for content in &contents {
+                    line.push_str(&format!(
+                        " {} {}",
+                        content.lines().nth(line_index).unwrap_or_default(),
+                        SymbolKind::Pipe.as_str()
+                    ));
+                }
+
+                lines.push(line);
+            }
+        }
+
+        lines.push(format!(
+            "{}{}{}",
+            SymbolKind::Plus.as_str(),
+            SymbolKind::Minus
+                .as_str()
+                .repeat(column_count * (COLUMN_WIDTH + 1) - 1),
+            SymbolKind::Plus.as_str()
+        ));
+
+        self.decorations
+            .wrap_unimarkup(lines.join(SymbolKind::Newline.as_str()))
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+/// One line of the table in the original content.
+struct TableLine<'slice, 'input> {
+    tokens: Vec<&'slice Token<'input>>,
+    newline: Option<&'slice Token<'input>>,
+}
+
+impl TableLine<'_, '_> {
+    fn is_border(&self) -> bool {
+        self.tokens.first().map(|t| t.kind) == Some(TokenKind::Plus(1))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum SegmentKind {
+    Cell(TableCellKind),
+    /// Segment consisting only of whitespace, meaning that the cell above spans into the next row.
+    Continuation,
+}
+
+/// Segment of a border line between two `+`.
+struct Segment {
+    kind: SegmentKind,
+    alignment: Option<ColumnAlignment>,
+    /// Column of the `+` starting this segment, relative to the start of the border line.
+    start_col: usize,
+    /// Column of the `+` ending this segment, relative to the start of the border line.
+    end_col: usize,
+    start: Position,
+}
+
+impl Segment {
+    fn new(content: &str, start_col: usize, end_col: usize, start: Position) -> Option<Self> {
+        if content.is_empty() {
+            return None;
+        }
+
+        if content.trim().is_empty() {
+            return Some(Segment {
+                kind: SegmentKind::Continuation,
+                alignment: None,
+                start_col,
+                end_col,
+                start,
+            });
+        }
+
+        let colon = SymbolKind::Colon.as_str();
+        let left = content.starts_with(colon);
+        let right = content.ends_with(colon) && content.len() > 1;
+        let fill = content.trim_start_matches(colon).trim_end_matches(colon);
+
+        let fill_char = fill.chars().next()?;
+        let kind = match fill_char {
+            '=' => TableCellKind::Header,
+            '-' => TableCellKind::Data,
+            '_' => TableCellKind::Footer,
+            _ => return None,
+        };
+
+        if fill.chars().any(|c| c != fill_char) {
+            return None;
+        }
+
+        let alignment = match (left, right) {
+            (true, true) => Some(ColumnAlignment::Center),
+            (true, false) => Some(ColumnAlignment::Left),
+            (false, true) => Some(ColumnAlignment::Right),
+            (false, false) => None,
+        };
+
+        Some(Segment {
+            kind: SegmentKind::Cell(kind),
+            alignment,
+            start_col,
+            end_col,
+            start,
+        })
+    }
+}
+
+/// Collects the tokens of one cell, before the content is parsed.
+struct CellBuilder<'input> {
+    tokens: Vec<Token<'input>>,
+    kind: TableCellKind,
+    alignment: Option<ColumnAlignment>,
+    column_span: usize,
+    row_span: usize,
+    start: Position,
+}
+
+impl Table {
+    /// Tries to create a table from the current position of the given [`BlockParser`].
+    ///
+    /// Returns the block parser, and the optional table.
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<Block>) {
+        let mut lines = Vec::new();
+
+        while matches!(
+            parser.iter.peek_kind(),
+            Some(TokenKind::Plus(1) | TokenKind::Pipe(1))
+        ) {
+            let mut tokens = Vec::new();
+
+            while !matches!(
+                parser.iter.peek_kind(),
+                None | Some(TokenKind::Newline | TokenKind::Blankline | TokenKind::Eoi)
+            ) {
+                tokens.push(
+                    parser
+                        .iter
+                        .next()
+                        .expect("Peeked above that a token exists."),
+                );
+            }
+
+            let newline = if parser.iter.peek_kind() == Some(TokenKind::Newline) {
+                parser.iter.next()
+            } else {
+                None
+            };
+
+            lines.push(TableLine { tokens, newline });
+
+            if newline.is_none() {
+                break;
+            }
+        }
+
+        let table = Table::from_lines(&lines, &mut parser.context);
+        (parser, table.map(Block::Table))
+    }
+
+    fn from_lines(lines: &[TableLine], context: &mut BlockContext) -> Option<Self> {
+        let (first_line, last_line) = (lines.first()?, lines.last()?);
+
+        if lines.len() < 2 || !first_line.is_border() || !last_line.is_border() {
+            return None;
+        }
+
+        let first_segments = parse_border(&first_line.tokens)?;
+        let column_count = first_segments.len();
+        let mut boundaries = vec![0];
+        boundaries.extend(first_segments.iter().map(|segment| segment.end_col));
+
+        let mut alignments: Vec<Option<ColumnAlignment>> = vec![None; column_count];
+        let mut owners: Vec<Option<usize>> = vec![None; column_count];
+        let mut cells: Vec<CellBuilder> = Vec::new();
+        let mut rows: Vec<(Vec<usize>, Position, Position)> = Vec::new();
+        let mut row_slots: Vec<usize> = Vec::new();
+
+        for line in &lines[..lines.len() - 1] {
+            let line_end = line.tokens.last()?.end;
+
+            if !line.is_border() {
+                let line_cells = parse_content_line(&line.tokens)?;
+
+                if line_cells.len() != row_slots.len() {
+                    return None;
+                }
+
+                for (cell_tokens, slot) in line_cells.into_iter().zip(&row_slots) {
+                    let cell = &mut cells[*slot];
+
+                    if cell_tokens.is_empty() {
+                        // Empty cell line ends the previous cell line with a blankline, like the lexer does
+                        if let Some(last) = cell.tokens.last_mut() {
+                            if last.kind == TokenKind::Newline {
+                                last.kind = TokenKind::Blankline;
+                            }
+                        }
+                    } else {
+                        cell.tokens.extend(cell_tokens.into_iter().copied());
+                        cell.tokens.extend(line.newline.copied());
+                    }
+                }
+
+                rows.last_mut()?.2 = line_end;
+                continue;
+            }
+
+            let segments = parse_border(&line.tokens)?;
+
+            row_slots.clear();
+            rows.push((Vec::new(), line.tokens.first()?.start, line_end));
+            let row = rows.last_mut()?;
+
+            for (i, segment) in segments.iter().enumerate() {
+                let (first_column, last_column) = if segments.len() == column_count {
+                    (i, i + 1)
+                } else {
+                    (
+                        boundaries.iter().position(|b| *b == segment.start_col)?,
+                        boundaries.iter().position(|b| *b == segment.end_col)?,
+                    )
+                };
+
+                if first_column >= last_column {
+                    return None;
+                }
+
+                if let Some(alignment) = segment.alignment {
+                    alignments[first_column..last_column].fill(Some(alignment));
+                }
+
+                match segment.kind {
+                    SegmentKind::Continuation => {
+                        let owner = owners[first_column]?;
+                        if !row_slots.contains(&owner) {
+                            cells[owner].row_span += 1;
+                        }
+                        row_slots.push(owner);
+                    }
+                    SegmentKind::Cell(kind) => {
+                        let index = cells.len();
+                        cells.push(CellBuilder {
+                            tokens: Vec::new(),
+                            kind,
+                            alignment: alignments[first_column],
+                            column_span: last_column - first_column,
+                            row_span: 1,
+                            start: segment.start,
+                        });
+                        owners[first_column..last_column].fill(Some(index));
+                        row.0.push(index);
+                        row_slots.push(index);
+                    }
+                }
+            }
+        }
+
+        // Closing border line must be valid, but its segments have no further meaning
+        parse_border(&last_line.tokens)?;
+
+        let mut parsed_cells: Vec<Option<TableCell>> = cells
+            .into_iter()
+            .map(|mut cell| {
+                while matches!(
+                    cell.tokens.last().map(|t| t.kind),
+                    Some(TokenKind::Newline | TokenKind::Blankline)
+                ) {
+                    cell.tokens.pop();
+                }
+
+                // Cells share the context of the table, so that citations, definitions
+                // and decoration numbers of nested elements continue in document order
+                let cell_parser =
+                    BlockParser::new(TokenIterator::from(&*cell.tokens), std::mem::take(context));
+                let (cell_parser, content) = BlockParser::parse(cell_parser);
+                *context = cell_parser.context;
+
+                let start = cell.tokens.first().map_or(cell.start, |t| t.start);
+                let end = cell.tokens.last().map_or(cell.start, |t| t.end);
+
+                Some(TableCell {
+                    content,
+                    kind: cell.kind,
+                    alignment: cell.alignment,
+                    column_span: cell.column_span,
+                    row_span: cell.row_span,
+                    start,
+                    end,
+                })
+            })
+            .collect();
+
+        let rows = rows
+            .into_iter()
+            .map(|(cell_indices, start, end)| TableRow {
+                cells: cell_indices
+                    .into_iter()
+                    .filter_map(|index| parsed_cells[index].take())
+                    .collect(),
+                start,
+                end,
+            })
+            .collect();
+
+        Some(Table {
+            rows,
+            column_count,
+            attributes: None,
+            decorations: Decorations::default(),
+            start: first_line.tokens.first()?.start,
+            end: last_line.tokens.last()?.end,
+        })
+    }
+}
+
+/// Parses a border line like `+-+=+:-:+`.
+fn parse_border(tokens: &[&Token]) -> Option<Vec<Segment>> {
+    let (first, rest) = tokens.split_first()?;
+    if first.kind != TokenKind::Plus(1) {
+        return None;
+    }
+
+    let line_start = first.start.col_grapheme;
+    let mut segments = Vec::new();
+    let mut content = String::new();
+    let mut segment_start = (0, first.start);
+
+    for token in rest {
+        match token.kind {
+            TokenKind::Plus(1) => {
+                let col = token.start.col_grapheme - line_start;
+                segments.push(Segment::new(
+                    &content,
+                    segment_start.0,
+                    col,
+                    segment_start.1,
+                )?);
+                content.clear();
+                segment_start = (col, token.start);
+            }
+            TokenKind::Minus(_)
+            | TokenKind::Underline(_)
+            | TokenKind::Colon(_)
+            | TokenKind::Equals(_)
+            | TokenKind::Whitespace => content.push_str(&String::from(*token)),
+            _ => return None,
+        }
+    }
+
+    // Only trailing whitespace is allowed after the last `+`
+    if segments.is_empty() || !content.trim().is_empty() {
+        return None;
+    }
+
+    Some(segments)
+}
+
+/// Parses a content line like `| cell 1 | cell 2 |`, and returns the trimmed tokens per cell.
+fn parse_content_line<'s, 'i>(tokens: &[&'s Token<'i>]) -> Option<Vec<Vec<&'s Token<'i>>>> {
+    let (first, rest) = tokens.split_first()?;
+    if first.kind != TokenKind::Pipe(1) {
+        return None;
+    }
+
+    let mut cells = Vec::new();
+    let mut cell = Vec::new();
+
+    for token in rest {
+        if token.kind == TokenKind::Pipe(1) {
+            cells.push(trim_whitespace(std::mem::take(&mut cell)));
+        } else {
+            cell.push(*token);
+        }
+    }
+
+    // Only trailing whitespace is allowed after the last `|`
+    if cells.is_empty() || !trim_whitespace(cell).is_empty() {
+        return None;
+    }
+
+    Some(cells)
+}
+
+fn trim_whitespace<'s, 'i>(mut tokens: Vec<&'s Token<'i>>) -> Vec<&'s Token<'i>> {
+    while tokens.last().map(|t| t.kind) == Some(TokenKind::Whitespace) {
+        tokens.pop();
+    }
+
+    let leading = tokens
+        .iter()
+        .take_while(|t| t.kind == TokenKind::Whitespace)
+        .count();
+    tokens.drain(..leading);
+
+    tokens
+}
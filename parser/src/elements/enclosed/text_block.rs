@@ -0,0 +1,144 @@
+//! Contains the structs and parsers to parse text blocks.
+
+use std::rc::Rc;
+
+use unimarkup_commons::attributes::Attributes;
+use unimarkup_commons::lexer::{
+    position::Position,
+    symbol::SymbolKind,
+    token::{iterator::EndMatcher, TokenKind},
+};
+
+use crate::{
+    elements::{attributes, blocks::Block, BlockElement, Blocks},
+    BlockParser,
+};
+
+/// Minimum number of brackets to open and close a text block.
+const MIN_BRACKET_LEN: usize = 3;
+
+/// Structure of a Unimarkup text block (`[[[ ... ]]]`).
+/// Text blocks group arbitrary blocks, mainly to set attributes for the whole group.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TextBlock {
+    /// The blocks inside this text block.
+    pub content: Blocks,
+    /// The number of brackets this text block was created with.
+    pub bracket_len: usize,
+    /// Marks that this text block was implicitly closed.
+    pub implicit_closed: bool,
+    /// Optional attributes of this text block.
+    pub attributes: Option<Attributes>,
+    /// The start of this text block in the original content.
+    pub start: Position,
+    /// The end of this text block in the original content.
+    pub end: Position,
+}
+
+impl BlockElement for TextBlock {
+    fn as_unimarkup(&self) -> String {
+        let attributes = self
+            .attributes
+            .as_ref()
+            .map(|attributes| attributes.as_unimarkup())
+            .unwrap_or_default();
+        let close = if self.implicit_closed {
+            String::default()
+        } else {
+            SymbolKind::CloseBracket.as_str().repeat(self.bracket_len)
+        };
+
+        format!(
+            "{}{attributes}\n{}\n{close}",
+            SymbolKind::OpenBracket.as_str().repeat(self.bracket_len),
+            self.content.as_unimarkup(),
+        )
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+impl TextBlock {
+    /// Tries to create a text block from the current position of the given [`BlockParser`].
+    ///
+    /// Returns the block parser, and the optional text block.
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<Block>) {
+        let Some(open_token) = parser.iter.next() else {
+            return (parser, None);
+        };
+
+        if open_token.kind != TokenKind::OpenBracket {
+            return (parser, None);
+        }
+
+        // Brackets are not combined by the lexer, because each bracket may open a new scope
+        let mut bracket_len = 1;
+        while parser.iter.peek_kind() == Some(TokenKind::OpenBracket) {
+            parser.iter.next();
+            bracket_len += 1;
+        }
+
+        if bracket_len < MIN_BRACKET_LEN {
+            return (parser, None);
+        }
+
+        // Attributes may be set directly after the opening brackets, e.g. `[[[{ "id": "main" }`
+        let attributes = attributes::parse_attributes(&mut parser);
+
+        // Only spaces are allowed until the end of the opening line
+        if parser
+            .iter
+            .by_ref()
+            .take_while(|t| !matches!(t.kind, TokenKind::Blankline | TokenKind::Newline))
+            .any(|t| !t.kind.is_space())
+        {
+            return (parser, None);
+        }
+
+        let close_sequence: Vec<TokenKind> = std::iter::once(TokenKind::Newline)
+            .chain(std::iter::repeat_n(TokenKind::CloseBracket, bracket_len))
+            .chain(std::iter::once(TokenKind::EnclosedBlockEnd))
+            .collect();
+
+        let content_parser = parser.nest_scoped(
+            None,
+            Some(Rc::new(move |matcher: &mut dyn EndMatcher| {
+                matcher.consumed_matches(&close_sequence)
+            })),
+        );
+
+        let (updated_content_parser, content) = BlockParser::parse(content_parser);
+        let implicit_closed = !updated_content_parser.iter.end_reached();
+        parser = updated_content_parser.into_inner();
+
+        let prev = parser
+            .iter
+            .prev()
+            .expect("Must be some token, because at least start tokens came before.");
+        let block_end = if implicit_closed {
+            prev.end
+        } else {
+            prev.start // Start position, because previous was either blankline, or end of input
+        };
+
+        (
+            parser,
+            Some(Block::TextBlock(TextBlock {
+                content,
+                bracket_len,
+                implicit_closed,
+                attributes,
+                start: open_token.start,
+                end: block_end,
+            })),
+        )
+    }
+}
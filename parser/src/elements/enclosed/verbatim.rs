@@ -2,11 +2,12 @@
 
 use std::rc::Rc;
 
+use unimarkup_commons::attributes::Attributes;
 use unimarkup_commons::lexer::position::Position;
 use unimarkup_commons::lexer::token::iterator::EndMatcher;
 use unimarkup_commons::lexer::token::TokenKind;
 
-use crate::elements::BlockElement;
+use crate::elements::{attributes, decorations::Decorations, BlockElement};
 use crate::{elements::blocks::Block, BlockParser};
 use unimarkup_commons::lexer::symbol::SymbolKind;
 
@@ -17,9 +18,10 @@ pub struct VerbatimBlock {
     pub content: String,
     /// The language used to highlight the content.
     pub data_lang: Option<String>,
-    /// Attributes of the verbatim block.
-    // TODO: make attributes data structure
-    pub attributes: Option<String>,
+    /// Optional attributes of this verbatim block.
+    pub attributes: Option<Attributes>,
+    /// Optional title and caption of this verbatim block.
+    pub decorations: Decorations,
     /// Marks that this verbatim block was implicitly closed.
     pub implicit_closed: bool,
     /// The number of backticks this verbatim block was created with.
@@ -34,13 +36,19 @@ impl BlockElement for VerbatimBlock {
     fn as_unimarkup(&self) -> String {
         let ticks = SymbolKind::Tick.as_str().repeat(self.tick_len);
         let lang = self.data_lang.clone().unwrap_or_default();
-        format!(
-            "{}{}\n{}\n{}",
+        let attributes = self
+            .attributes
+            .as_ref()
+            .map(|attributes| format!(" {}", attributes.as_unimarkup()))
+            .unwrap_or_default();
+        self.decorations.wrap_unimarkup(format!(
+            "{}{}{}\n{}\n{}",
             &ticks,
             lang,
+            attributes,
             self.content,
             if self.implicit_closed { "" } else { &ticks }
-        )
+        ))
     }
 
     fn start(&self) -> unimarkup_commons::lexer::position::Position {
@@ -82,18 +90,29 @@ impl VerbatimBlock {
             None
         };
 
-        // exit if non-space content is given after data lang ended
-        // => invalid verbatim block, take as paragraph
+        let mut attributes = None;
+
         if !matches!(
             parser.iter.prev_kind(),
             Some(TokenKind::Blankline) | Some(TokenKind::Newline)
-        ) && parser
-            .iter
-            .by_ref()
-            .take_while(|t| !matches!(t.kind, TokenKind::Blankline | TokenKind::Newline))
-            .any(|t| !t.kind.is_space())
-        {
-            return (parser, None);
+        ) {
+            while parser.iter.peek_kind() == Some(TokenKind::Whitespace) {
+                parser.iter.next();
+            }
+
+            // Attributes may be set after the data lang, e.g. "```rust { "id": "example" }"
+            attributes = attributes::parse_attributes(&mut parser);
+
+            // exit if other non-space content is given after data lang ended
+            // => invalid verbatim block, take as paragraph
+            if parser
+                .iter
+                .by_ref()
+                .take_while(|t| !matches!(t.kind, TokenKind::Blankline | TokenKind::Newline))
+                .any(|t| !t.kind.is_space())
+            {
+                return (parser, None);
+            }
         }
 
         let prev_context_flags = parser.context.flags;
@@ -103,7 +122,7 @@ impl VerbatimBlock {
                 matcher.consumed_matches(&[
                     TokenKind::Newline,
                     TokenKind::Tick(tick_len),
-                    TokenKind::EnclosedBlockEnd, //TODO: add PossibleAttributes & Possible Decorators besides blanklines
+                    TokenKind::EnclosedBlockEnd,
                 ])
             })),
         );
@@ -133,7 +152,8 @@ impl VerbatimBlock {
             Some(Block::VerbatimBlock(VerbatimBlock {
                 content: content.as_unimarkup(),
                 data_lang,
-                attributes: None,
+                attributes,
+                decorations: Decorations::default(),
                 implicit_closed,
                 tick_len,
                 start: open_token.start,
@@ -2,6 +2,7 @@
 
 use std::rc::Rc;
 
+use unimarkup_commons::attributes::Attributes;
 use unimarkup_commons::lexer::{
     position::Position,
     symbol::SymbolKind,
@@ -25,6 +26,8 @@ use crate::{
 pub struct BulletList {
     /// The list entries of this bullet list.
     pub entries: Vec<BulletListEntry>,
+    /// Optional attributes of this bullet list.
+    pub attributes: Option<Attributes>,
     /// The start of this bullet list in the original content.
     pub start: Position,
     /// The end of this bullet list in theoriginal content.
@@ -64,6 +67,7 @@ impl BulletList {
         while parser.iter.matches(&STAR_ENTRY_START[1..])
             || parser.iter.matches(&MINUS_ENTRY_START[1..])
             || parser.iter.matches(&PLUS_ENTRY_START[1..])
+            || parser.iter.matches(&TASK_ENTRY_START[1..])
         {
             let checkpoint = parser.iter.checkpoint();
             let (updated_parser, list_entry_opt) = BulletListEntry::parse(parser);
@@ -98,6 +102,7 @@ impl BulletList {
             parser,
             Some(Block::BulletList(BulletList {
                 entries,
+                attributes: None,
                 start,
                 end,
             })),
@@ -110,10 +115,14 @@ impl BulletList {
 pub struct BulletListEntry {
     /// The [`BulletListEntryKeyword`] used to create this entry.
     pub keyword: BulletListEntryKeyword,
+    /// The optional [`TaskMarker`] that makes this entry a task (e.g. `-[x] Completed task`).
+    pub task: Option<TaskMarker>,
     /// The entry heading content of this entry.
     pub heading: Vec<Inline>,
     /// The body of this entry.
     pub body: Vec<Block>,
+    /// Optional attributes of this entry.
+    pub attributes: Option<Attributes>,
     /// The start of this entry in the original content.
     pub start: Position,
     /// The end of this entry in the original content.
@@ -123,7 +132,9 @@ pub struct BulletListEntry {
 impl BlockElement for BulletListEntry {
     fn as_unimarkup(&self) -> String {
         let head_body_separator = match self.body.first() {
-            Some(Block::BulletList(_)) | None => SymbolKind::Newline.as_str().to_string(),
+            Some(Block::BulletList(_)) | Some(Block::NumberedList(_)) | None => {
+                SymbolKind::Newline.as_str().to_string()
+            }
             Some(_) => SymbolKind::Newline.as_str().repeat(2), // to get a blankline between head and body
         };
 
@@ -134,8 +145,9 @@ impl BlockElement for BulletListEntry {
         }; // Two space indentation after newline
 
         format!(
-            "{} {}{}{}",
+            "{}{} {}{}{}",
             self.keyword.as_str(),
+            self.task.map(|task| task.as_str()).unwrap_or_default(),
             self.heading.as_unimarkup(),
             head_body_separator,
             plain_body
@@ -151,6 +163,125 @@ impl BlockElement for BulletListEntry {
     }
 }
 
+impl BulletListEntry {
+    /// Returns the status of this entry, or `None` if this entry is not a task.
+    ///
+    /// The status of tasks marked with `[]` is derived from the tasks of nested bullet lists.
+    pub fn task_status(&self) -> Option<TaskStatus> {
+        match self.task? {
+            TaskMarker::Status(status) => Some(status),
+            TaskMarker::Derived => {
+                let sub_tasks = self
+                    .body
+                    .iter()
+                    .filter_map(|block| match block {
+                        Block::BulletList(list) => Some(list.entries.iter()),
+                        _ => None,
+                    })
+                    .flatten()
+                    .filter_map(BulletListEntry::task_status);
+
+                Some(TaskStatus::derive(sub_tasks))
+            }
+        }
+    }
+}
+
+/// Enum representing the marker that makes a [`BulletListEntry`] a task.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TaskMarker {
+    /// Task with explicit status (e.g. `[x]`).
+    Status(TaskStatus),
+    /// Task with status derived from its sub-tasks: `[]`
+    Derived,
+}
+
+impl TaskMarker {
+    /// String representation of the [`TaskMarker`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskMarker::Status(TaskStatus::Open) => "[ ]",
+            TaskMarker::Status(TaskStatus::Active) => "[a]",
+            TaskMarker::Status(TaskStatus::OnHold) => "[h]",
+            TaskMarker::Status(TaskStatus::Completed) => "[x]",
+            TaskMarker::Status(TaskStatus::Failed) => "[/]",
+            TaskMarker::Derived => "[]",
+        }
+    }
+}
+
+impl TryFrom<&str> for TaskMarker {
+    type Error = ConversionError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "[ ]" => Ok(TaskMarker::Status(TaskStatus::Open)),
+            "[a]" => Ok(TaskMarker::Status(TaskStatus::Active)),
+            "[h]" => Ok(TaskMarker::Status(TaskStatus::OnHold)),
+            "[x]" | "[X]" => Ok(TaskMarker::Status(TaskStatus::Completed)),
+            "[/]" => Ok(TaskMarker::Status(TaskStatus::Failed)),
+            "[]" => Ok(TaskMarker::Derived),
+            _ => Err(ConversionError::CannotConvertToken),
+        }
+    }
+}
+
+/// Enum representing the status of a task.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TaskStatus {
+    /// Task that is not yet started: `[ ]`
+    Open,
+    /// Task that is currently worked on: `[a]`
+    Active,
+    /// Task that is on hold: `[h]`
+    OnHold,
+    /// Task that is completed: `[x]`
+    Completed,
+    /// Task that failed: `[/]`
+    Failed,
+}
+
+impl TaskStatus {
+    /// Name of the [`TaskStatus`] in kebab-case.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskStatus::Open => "open",
+            TaskStatus::Active => "active",
+            TaskStatus::OnHold => "on-hold",
+            TaskStatus::Completed => "completed",
+            TaskStatus::Failed => "failed",
+        }
+    }
+
+    /// Derives the status of a task from the status of its sub-tasks.
+    ///
+    /// A failed sub-task fails the task, before a sub-task on hold puts the task on hold.
+    /// The task is completed once all sub-tasks are completed,
+    /// and active if any sub-task is active or completed.
+    pub fn derive(sub_tasks: impl Iterator<Item = TaskStatus>) -> TaskStatus {
+        let sub_tasks: Vec<_> = sub_tasks.collect();
+
+        if sub_tasks.contains(&TaskStatus::Failed) {
+            TaskStatus::Failed
+        } else if sub_tasks.contains(&TaskStatus::OnHold) {
+            TaskStatus::OnHold
+        } else if !sub_tasks.is_empty()
+            && sub_tasks
+                .iter()
+                .all(|status| *status == TaskStatus::Completed)
+        {
+            TaskStatus::Completed
+        } else if sub_tasks
+            .iter()
+            .any(|status| matches!(status, TaskStatus::Active | TaskStatus::Completed))
+        {
+            TaskStatus::Active
+        } else {
+            TaskStatus::Open
+        }
+    }
+}
+
 /// Enum representing the keyword used to create a [`BulletListEntry`].
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum BulletListEntryKeyword {
@@ -195,10 +326,10 @@ impl TryFrom<TokenKind> for BulletListEntryKeyword {
 }
 
 /// Enum representing possible conversion errors
-/// that may occur when converting [`TokenKind`] to [`BulletListEntryKeyword`].
+/// that may occur when converting [`TokenKind`] to [`BulletListEntryKeyword`], or content to a [`TaskMarker`].
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ConversionError {
-    /// Error denoting that the given [`TokenKind`] could not be converted to a [`BulletListEntryKeyword`].
+    /// Error denoting that the given [`TokenKind`] or content could not be converted.
     CannotConvertToken,
 }
 
@@ -208,28 +339,47 @@ const STAR_ENTRY_START: &[TokenKind] = &[TokenKind::Newline, TokenKind::Star(1),
 const MINUS_ENTRY_START: &[TokenKind] =
     &[TokenKind::Newline, TokenKind::Minus(1), TokenKind::Space];
 const PLUS_ENTRY_START: &[TokenKind] = &[TokenKind::Newline, TokenKind::Plus(1), TokenKind::Space];
+const TASK_ENTRY_START: &[TokenKind] = &[
+    TokenKind::Newline,
+    TokenKind::Minus(1),
+    TokenKind::OpenBracket,
+];
 
-const STAR_SUB_ENTRY_START: &[TokenKind] = &[
+/// A line break in the indented lines of an entry, which ends the entry heading.
+pub(super) const INDENTED_LINE_BREAK: &[TokenKind] = &[
+    TokenKind::Newline,
+    TokenKind::Space,
+    TokenKind::Space,
+    TokenKind::EscapedBlankline,
+];
+pub(super) const STAR_SUB_ENTRY_START: &[TokenKind] = &[
     TokenKind::Newline,
     TokenKind::Space,
     TokenKind::Space,
     TokenKind::Star(1),
     TokenKind::Space,
 ];
-const MINUS_SUB_ENTRY_START: &[TokenKind] = &[
+pub(super) const MINUS_SUB_ENTRY_START: &[TokenKind] = &[
     TokenKind::Newline,
     TokenKind::Space,
     TokenKind::Space,
     TokenKind::Minus(1),
     TokenKind::Space,
 ];
-const PLUS_SUB_ENTRY_START: &[TokenKind] = &[
+pub(super) const PLUS_SUB_ENTRY_START: &[TokenKind] = &[
     TokenKind::Newline,
     TokenKind::Space,
     TokenKind::Space,
     TokenKind::Plus(1),
     TokenKind::Space,
 ];
+pub(super) const TASK_SUB_ENTRY_START: &[TokenKind] = &[
+    TokenKind::Newline,
+    TokenKind::Space,
+    TokenKind::Space,
+    TokenKind::Minus(1),
+    TokenKind::OpenBracket,
+];
 
 impl BulletListEntry {
     /// Tries to create a bullet list entry from the current position of the given [`BlockParser`].
@@ -247,7 +397,45 @@ impl BulletListEntry {
         let entry_keyword = BulletListEntryKeyword::try_from(start_token)
             .expect("Correct list entry start ensured in bullet list parser.");
 
-        parser.iter.next(); // Consume space after keyword
+        let task = if entry_keyword == BulletListEntryKeyword::Minus
+            && parser.iter.peek_kind() == Some(TokenKind::OpenBracket)
+        {
+            let marker_tokens: Vec<_> = parser
+                .iter
+                .by_ref()
+                .take_while(|t| {
+                    !matches!(
+                        t.kind,
+                        TokenKind::CloseBracket | TokenKind::Newline | TokenKind::Blankline
+                    )
+                })
+                .copied()
+                .collect();
+
+            if parser.iter.prev_kind() != Some(TokenKind::CloseBracket) {
+                return (parser, None);
+            }
+
+            // The closing bracket is not part of the taken tokens
+            let marker = format!(
+                "{}{}",
+                Token::flatten(&marker_tokens).unwrap_or_default(),
+                SymbolKind::CloseBracket.as_str()
+            );
+            let Ok(task) = TaskMarker::try_from(marker.as_str()) else {
+                return (parser, None);
+            };
+
+            // Task marker must be followed by a space
+            if parser.iter.next().map(|t| t.kind) != Some(TokenKind::Whitespace) {
+                return (parser, None);
+            }
+
+            Some(task)
+        } else {
+            parser.iter.next(); // Consume space after keyword
+            None
+        };
 
         let indent_sequence = &[TokenKind::Space, TokenKind::Space];
         let mut entry_heading_parser = parser.nest(
@@ -256,13 +444,17 @@ impl BulletListEntry {
             })),
             Some(Rc::new(|matcher: &mut dyn EndMatcher| {
                 matcher.consumed_is_blank_line()
+                    || matcher.matches(INDENTED_LINE_BREAK)
                     || matcher.outer_end()
                     || matcher.matches(STAR_ENTRY_START)
                     || matcher.matches(MINUS_ENTRY_START)
                     || matcher.matches(PLUS_ENTRY_START)
+                    || matcher.matches(TASK_ENTRY_START)
                     || matcher.matches(STAR_SUB_ENTRY_START)
                     || matcher.matches(MINUS_SUB_ENTRY_START)
                     || matcher.matches(PLUS_SUB_ENTRY_START)
+                    || matcher.matches(TASK_SUB_ENTRY_START)
+                    || super::numbered_list::matches_sub_entry_start(matcher)
             })),
         );
 
@@ -288,10 +480,15 @@ impl BulletListEntry {
             //TODO: add blanklines in case newlines should be kept
         }
 
-        if !parser.iter.end_reached()
+        // Attributes of the list may directly follow the last entry heading
+        let attributes_follow = parser.iter.matches(&[TokenKind::OpenBrace]);
+
+        if !attributes_follow
+            && !parser.iter.end_reached()
             && !parser.iter.matches(STAR_ENTRY_START)
             && !parser.iter.matches(MINUS_ENTRY_START)
             && !parser.iter.matches(PLUS_ENTRY_START)
+            && !parser.iter.matches(TASK_ENTRY_START)
         {
             let entry_body_parser = parser.nest(
                 Some(Rc::new(|matcher: &mut dyn PrefixMatcher| {
@@ -310,14 +507,16 @@ impl BulletListEntry {
                     parser,
                     Some(BulletListEntry {
                         keyword: entry_keyword,
+                        task,
                         heading: entry_heading,
                         body: blocks,
+                        attributes: None,
                         start: start_token.start,
                         end,
                     }),
                 );
             }
-        } else {
+        } else if !attributes_follow {
             parser.iter.next(); // Consume "Newline" token of next list entry
         };
 
@@ -330,8 +529,10 @@ impl BulletListEntry {
             parser,
             Some(BulletListEntry {
                 keyword: entry_keyword,
+                task,
                 heading: entry_heading,
                 body: Vec::new(),
+                attributes: None,
                 start: start_token.start,
                 end,
             }),
@@ -0,0 +1,465 @@
+//! Contains the structs and parsers to parse definition lists.
+
+use std::rc::Rc;
+
+use unimarkup_commons::attributes::Attributes;
+use unimarkup_commons::lexer::{
+    position::Position,
+    symbol::SymbolKind,
+    token::{
+        iterator::{EndMatcher, Itertools, PrefixMatcher},
+        TokenKind,
+    },
+};
+use unimarkup_inline::{
+    element::{Inline, InlineElement},
+    parser,
+};
+
+use super::ConversionError;
+use crate::{
+    elements::{blocks::Block, BlockElement, Blocks},
+    BlockParser,
+};
+
+/// Structure of a Unimarkup definition list.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DefinitionList {
+    /// The entries of this definition list.
+    pub entries: Vec<DefinitionListEntry>,
+    /// Optional attributes of this definition list.
+    pub attributes: Option<Attributes>,
+    /// The start of this definition list in the original content.
+    pub start: Position,
+    /// The end of this definition list in the original content.
+    pub end: Position,
+}
+
+impl BlockElement for DefinitionList {
+    fn as_unimarkup(&self) -> String {
+        let separator = match self.entries.first().map(|entry| entry.keyword) {
+            // Entries in colon form must be separated by a blankline
+            Some(DefinitionListEntryKeyword::Colon) => SymbolKind::Newline.as_str().repeat(2),
+            _ => SymbolKind::Newline.as_str().to_string(),
+        };
+
+        self.entries
+            .iter()
+            .map(|entry| entry.as_unimarkup())
+            .join(&separator)
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+impl DefinitionList {
+    /// Tries to create a definition list from the current position of the given [`BlockParser`].
+    ///
+    /// Returns the block parser, and the optional definition list.
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<Block>) {
+        let keyword = if parser.iter.matches(COLON_ENTRY_START) {
+            DefinitionListEntryKeyword::Colon
+        } else if parser.iter.matches(MINUS_ENTRY_START) {
+            DefinitionListEntryKeyword::Minus
+        } else {
+            return (parser, None);
+        };
+
+        let mut entries = Vec::new();
+
+        loop {
+            let checkpoint = parser.iter.checkpoint();
+
+            if !entries.is_empty() {
+                // Entries after the first one must start at a new line
+                if !parser.iter.consumed_is_blank_line()
+                    && !parser.iter.consumed_matches(&[TokenKind::Newline])
+                {
+                    break;
+                }
+
+                while parser.iter.consumed_is_blank_line() {
+                    // skip empty lines between entries
+                }
+            }
+
+            let (updated_parser, entry_opt) = DefinitionListEntry::parse(parser, keyword);
+            parser = updated_parser;
+
+            match entry_opt {
+                Some(entry) => entries.push(entry),
+                None => {
+                    // Reverts last tried entry parsing
+                    parser.iter.rollback(checkpoint);
+                    break;
+                }
+            }
+        }
+
+        if entries.is_empty() {
+            return (parser, None);
+        }
+
+        let start = entries
+            .first()
+            .expect("Ensured above that entries exist.")
+            .start;
+        let end = entries
+            .last()
+            .expect("Ensured above that entries exist.")
+            .end;
+
+        (
+            parser,
+            Some(Block::DefinitionList(DefinitionList {
+                entries,
+                attributes: None,
+                start,
+                end,
+            })),
+        )
+    }
+}
+
+/// Structure of a Unimarkup definition list entry.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DefinitionListEntry {
+    /// The [`DefinitionListEntryKeyword`] used to create this entry.
+    pub keyword: DefinitionListEntryKeyword,
+    /// The defined term.
+    pub term: Vec<Inline>,
+    /// Optional classifier of the term (e.g. `:-- Classifier` or `--Classifier--`).
+    pub classifier: Option<Vec<Inline>>,
+    /// The description of the term.
+    pub description: Blocks,
+    /// The start of this entry in the original content.
+    pub start: Position,
+    /// The end of this entry in the original content.
+    pub end: Position,
+}
+
+impl BlockElement for DefinitionListEntry {
+    fn as_unimarkup(&self) -> String {
+        let description = self.description.as_unimarkup();
+
+        match self.keyword {
+            DefinitionListEntryKeyword::Colon => {
+                let colon = SymbolKind::Colon.as_str();
+                let mut s = format!("{colon} {} {colon}", self.term.as_unimarkup());
+
+                if let Some(classifier) = &self.classifier {
+                    s.push_str(SymbolKind::Newline.as_str());
+                    s.push_str(&format!(
+                        "{colon}{} {}",
+                        SymbolKind::Minus.as_str().repeat(2),
+                        classifier.as_unimarkup()
+                    ));
+                }
+
+                for line in description.lines() {
+                    s.push_str(SymbolKind::Newline.as_str());
+
+                    if line.is_empty() {
+                        s.push_str(colon);
+                    } else {
+                        s.push_str(&format!("{colon} {line}"));
+                    }
+                }
+
+                s
+            }
+            DefinitionListEntryKeyword::Minus => {
+                let classifier = match &self.classifier {
+                    Some(classifier) => format!(
+                        "{minus}{}{minus}",
+                        classifier.as_unimarkup(),
+                        minus = SymbolKind::Minus.as_str().repeat(2)
+                    ),
+                    None => String::default(),
+                };
+
+                format!(
+                    "{} {} {}{classifier} {}",
+                    SymbolKind::Minus.as_str(),
+                    self.term.as_unimarkup(),
+                    SymbolKind::Dot.as_str().repeat(3),
+                    description.lines().join("\n  ") // Two space indentation after newline
+                )
+            }
+        }
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+/// Enum representing the keyword used to create a [`DefinitionListEntry`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DefinitionListEntryKeyword {
+    /// Colon keyword for entries in the form: `: Term :`
+    Colon,
+    /// Minus keyword for entries in the form: `- Term ... description`
+    Minus,
+}
+
+impl DefinitionListEntryKeyword {
+    /// String representation of the [`DefinitionListEntryKeyword`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DefinitionListEntryKeyword::Colon => SymbolKind::Colon.as_str(),
+            DefinitionListEntryKeyword::Minus => SymbolKind::Minus.as_str(),
+        }
+    }
+}
+
+impl TryFrom<TokenKind> for DefinitionListEntryKeyword {
+    type Error = ConversionError;
+
+    fn try_from(value: TokenKind) -> Result<Self, Self::Error> {
+        match value {
+            TokenKind::Colon(1) => Ok(DefinitionListEntryKeyword::Colon),
+            TokenKind::Minus(1) => Ok(DefinitionListEntryKeyword::Minus),
+            _ => Err(ConversionError::CannotConvertToken),
+        }
+    }
+}
+
+// Consts below help with matching to prevent dynamic allocations.
+
+const COLON_ENTRY_START: &[TokenKind] = &[TokenKind::Colon(1), TokenKind::Space];
+const MINUS_ENTRY_START: &[TokenKind] = &[TokenKind::Minus(1), TokenKind::Space];
+const COLON_TERM_END: &[TokenKind] = &[TokenKind::Space, TokenKind::Colon(1)];
+const COLON_CLASSIFIER_START: &[TokenKind] = &[
+    TokenKind::Newline,
+    TokenKind::Colon(1),
+    TokenKind::Minus(2),
+    TokenKind::Space,
+];
+const MINUS_TERM_END: &[TokenKind] = &[TokenKind::Dot(3)];
+const MINUS_CLASSIFIER_DELIMITER: &[TokenKind] = &[TokenKind::Minus(2)];
+
+impl DefinitionListEntry {
+    /// Tries to create a definition list entry with the given keyword from the current position of the given [`BlockParser`].
+    ///
+    /// Returns the block parser, and the optional definition list entry.
+    fn parse<'s, 'i>(
+        parser: BlockParser<'s, 'i>,
+        keyword: DefinitionListEntryKeyword,
+    ) -> (BlockParser<'s, 'i>, Option<DefinitionListEntry>) {
+        match keyword {
+            DefinitionListEntryKeyword::Colon => Self::parse_colon_entry(parser),
+            DefinitionListEntryKeyword::Minus => Self::parse_minus_entry(parser),
+        }
+    }
+
+    /// Parses entries in the form:
+    ///
+    /// ```text
+    /// : Term :
+    /// :-- Classifier
+    /// :
+    /// : Description
+    /// ```
+    fn parse_colon_entry<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<DefinitionListEntry>) {
+        if !parser.iter.matches(COLON_ENTRY_START) {
+            return (parser, None);
+        }
+
+        let start_token = parser.iter.next().expect("Entry start matched above.");
+        parser.iter.next(); // Consume space after keyword
+
+        let (updated_parser, term) =
+            parse_line_inlines(parser, |matcher| matcher.matches(COLON_TERM_END));
+        parser = updated_parser;
+
+        // The term must be closed by ` :` at the end of the line
+        if term.is_empty()
+            || !parser.iter.consumed_matches(COLON_TERM_END)
+            || !matches!(
+                parser.iter.peek_kind(),
+                Some(TokenKind::Newline | TokenKind::Blankline | TokenKind::Eoi) | None
+            )
+        {
+            return (parser, None);
+        }
+
+        let mut end = start_token.end;
+        if let Some(last) = term.last() {
+            end = last.end();
+        }
+
+        let mut classifier = None;
+
+        if parser.iter.consumed_matches(COLON_CLASSIFIER_START) {
+            let (updated_parser, classifier_inlines) =
+                parse_line_inlines(parser, |matcher| matcher.matches(&[TokenKind::Newline]));
+            parser = updated_parser;
+
+            if let Some(last) = classifier_inlines.last() {
+                end = last.end();
+                classifier = Some(classifier_inlines);
+            }
+        }
+
+        let description_parser = parser.nest(
+            Some(Rc::new(|matcher: &mut dyn PrefixMatcher| {
+                // `:` without space is allowed for empty lines inside the description
+                matcher.consumed_prefix(COLON_ENTRY_START)
+                    || matcher.consumed_prefix(&[TokenKind::Colon(1)])
+            })),
+            None,
+        );
+        let (updated_parser, description) = BlockParser::parse(description_parser);
+        parser = updated_parser.into_inner();
+
+        if let Some(last) = description.last() {
+            end = last.end();
+        }
+
+        (
+            parser,
+            Some(DefinitionListEntry {
+                keyword: DefinitionListEntryKeyword::Colon,
+                term,
+                classifier,
+                description,
+                start: start_token.start,
+                end,
+            }),
+        )
+    }
+
+    /// Parses entries in the form:
+    ///
+    /// ```text
+    /// - Term ...--Classifier-- Description
+    ///   that may continue on indented lines.
+    /// ```
+    fn parse_minus_entry<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<DefinitionListEntry>) {
+        if !parser.iter.matches(MINUS_ENTRY_START) {
+            return (parser, None);
+        }
+
+        let start_token = parser.iter.next().expect("Entry start matched above.");
+        parser.iter.next(); // Consume space after keyword
+
+        let (updated_parser, term) = parse_line_inlines(parser, |matcher| {
+            matcher.matches(MINUS_TERM_END)
+                || matcher.matches(&[TokenKind::Space, TokenKind::Dot(3)])
+        });
+        parser = updated_parser;
+
+        // Space before `...` is required to not treat ellipses in bullet list entries as definitions
+        let spaced = parser.iter.consumed_matches(&[TokenKind::Space]);
+
+        if term.is_empty() || !spaced || !parser.iter.consumed_matches(MINUS_TERM_END) {
+            return (parser, None);
+        }
+
+        // `...` must also be followed by the classifier or whitespace, so `- Term ...text` is no definition either
+        if !matches!(
+            parser.iter.peek_kind(),
+            Some(
+                TokenKind::Minus(2)
+                    | TokenKind::Whitespace
+                    | TokenKind::Newline
+                    | TokenKind::Blankline
+                    | TokenKind::Eoi
+            ) | None
+        ) {
+            return (parser, None);
+        }
+
+        let mut end = term.last().expect("Ensured above that term exists.").end();
+        let mut classifier = None;
+
+        if parser.iter.consumed_matches(MINUS_CLASSIFIER_DELIMITER) {
+            let (updated_parser, classifier_inlines) = parse_line_inlines(parser, |matcher| {
+                matcher.matches(MINUS_CLASSIFIER_DELIMITER)
+            });
+            parser = updated_parser;
+
+            if classifier_inlines.is_empty()
+                || !parser.iter.consumed_matches(MINUS_CLASSIFIER_DELIMITER)
+            {
+                return (parser, None);
+            }
+
+            end = classifier_inlines
+                .last()
+                .expect("Ensured above that classifier exists.")
+                .end();
+            classifier = Some(classifier_inlines);
+        }
+
+        let _ = parser.iter.consumed_matches(&[TokenKind::Space]);
+
+        let indent_sequence = &[TokenKind::Space, TokenKind::Space];
+        let description_parser = parser.nest(
+            Some(Rc::new(|matcher: &mut dyn PrefixMatcher| {
+                matcher.consumed_prefix(indent_sequence) || matcher.only_spaces_until_newline()
+            })),
+            Some(Rc::new(|matcher: &mut dyn EndMatcher| {
+                matcher.matches(&[TokenKind::Newline, TokenKind::Minus(1), TokenKind::Space])
+            })),
+        );
+        let (updated_parser, description) = BlockParser::parse(description_parser);
+        parser = updated_parser.into_inner();
+
+        if let Some(last) = description.last() {
+            end = last.end();
+        }
+
+        (
+            parser,
+            Some(DefinitionListEntry {
+                keyword: DefinitionListEntryKeyword::Minus,
+                term,
+                classifier,
+                description,
+                start: start_token.start,
+                end,
+            }),
+        )
+    }
+}
+
+/// Parses inlines until the given end function matches, or the current line ends.
+fn parse_line_inlines<'s, 'i>(
+    parser: BlockParser<'s, 'i>,
+    end_fn: fn(&mut dyn EndMatcher) -> bool,
+) -> (BlockParser<'s, 'i>, Vec<Inline>) {
+    let mut line_parser = parser.nest(
+        None,
+        Some(Rc::new(move |matcher: &mut dyn EndMatcher| {
+            end_fn(matcher)
+                || matcher.matches(&[TokenKind::Newline])
+                || matcher.is_blank_line()
+                || matcher.outer_end()
+        })),
+    );
+
+    let (iter, inline_context, parsed_inlines) =
+        parser::parse_inlines(line_parser.iter, (&line_parser.context).into(), None, None);
+    line_parser.iter = iter;
+    line_parser.context.update_from(inline_context);
+
+    (line_parser.into_inner(), parsed_inlines.to_inlines())
+}
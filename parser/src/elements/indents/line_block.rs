@@ -0,0 +1,107 @@
+//! Contains the structs and parsers to parse line blocks.
+
+use std::rc::Rc;
+
+use unimarkup_commons::attributes::Attributes;
+use unimarkup_commons::lexer::{
+    position::Position,
+    symbol::SymbolKind,
+    token::{
+        iterator::{EndMatcher, PrefixMatcher},
+        TokenKind,
+    },
+};
+
+use crate::{
+    elements::{blocks::Block, BlockElement, Blocks},
+    BlockParser,
+};
+
+/// Structure of a Unimarkup line block.
+/// Whitespace and newlines inside a line block are preserved, but inline formatting is still parsed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LineBlock {
+    /// The blocks inside this line block.
+    pub content: Blocks,
+    /// Optional attributes of this line block.
+    pub attributes: Option<Attributes>,
+    /// The start of this line block in the original content.
+    pub start: Position,
+    /// The end of this line block in the original content.
+    pub end: Position,
+}
+
+impl BlockElement for LineBlock {
+    fn as_unimarkup(&self) -> String {
+        self.content
+            .as_unimarkup()
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    SymbolKind::Pipe.as_str().to_string()
+                } else {
+                    format!("{} {line}", SymbolKind::Pipe.as_str())
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(SymbolKind::Newline.as_str())
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+const LINE_BLOCK_START: &[TokenKind] = &[TokenKind::Pipe(1), TokenKind::Space];
+
+impl LineBlock {
+    /// Tries to create a line block from the current position of the given [`BlockParser`].
+    ///
+    /// Returns the block parser, and the optional line block.
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<Block>) {
+        if !parser.iter.matches(LINE_BLOCK_START) {
+            return (parser, None);
+        }
+
+        let start_token = parser.iter.next().expect("Line block start matched above.");
+        parser.iter.next(); // Consume space after keyword
+
+        let prev_context_flags = parser.context.flags;
+        let mut content_parser = parser.nest(
+            Some(Rc::new(|matcher: &mut dyn PrefixMatcher| {
+                // `|` without space is allowed for empty lines inside the line block
+                matcher.consumed_prefix(LINE_BLOCK_START)
+                    || matcher.consumed_prefix(&[TokenKind::Pipe(1)])
+            })),
+            None,
+        );
+        content_parser.context.flags.logic_only = false;
+        content_parser.context.flags.keep_whitespaces = true;
+        content_parser.context.flags.keep_newline = true;
+
+        let (updated_parser, content) = BlockParser::parse(content_parser);
+        parser = updated_parser.into_inner();
+        parser.context.flags = prev_context_flags;
+
+        let Some(last_block) = content.last() else {
+            return (parser, None);
+        };
+        let end = last_block.end();
+
+        (
+            parser,
+            Some(Block::LineBlock(LineBlock {
+                content,
+                attributes: None,
+                start: start_token.start,
+                end,
+            })),
+        )
+    }
+}
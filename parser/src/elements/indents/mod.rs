@@ -1,5 +1,13 @@
 //! Contains indented elements
 
 mod bullet_list;
+mod definition_list;
+mod line_block;
+mod numbered_list;
+mod quotation;
 
 pub use bullet_list::*;
+pub use definition_list::*;
+pub use line_block::*;
+pub use numbered_list::*;
+pub use quotation::*;
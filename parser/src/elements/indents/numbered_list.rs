@@ -0,0 +1,688 @@
+//! Contains the structs and parsers to parse numbered list elements.
+
+use std::rc::Rc;
+
+use unimarkup_commons::attributes::Attributes;
+use unimarkup_commons::lexer::{
+    position::Position,
+    symbol::SymbolKind,
+    token::{
+        iterator::{EndMatcher, Itertools, PrefixMatcher},
+        TokenKind,
+    },
+};
+use unimarkup_inline::{
+    element::{Inline, InlineElement},
+    parser,
+};
+
+use crate::{
+    elements::{atomic::Paragraph, blocks::Block, BlockElement},
+    BlockParser,
+};
+
+use super::bullet_list::{
+    ConversionError, INDENTED_LINE_BREAK, MINUS_SUB_ENTRY_START, PLUS_SUB_ENTRY_START,
+    STAR_SUB_ENTRY_START, TASK_SUB_ENTRY_START,
+};
+
+/// Structure of a Unimarkup numbered list element.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NumberedList {
+    /// The list entries of this numbered list.
+    pub entries: Vec<NumberedListEntry>,
+    /// The [`EnumerationKind`] used to enumerate the entries of this numbered list.
+    pub kind: EnumerationKind,
+    /// The [`EnumerationDelimiter`] used by the entries of this numbered list.
+    pub delimiter: EnumerationDelimiter,
+    /// The number of the first entry of this numbered list.
+    /// Following entries are numbered automatically, regardless of their enumerators.
+    pub start_number: usize,
+    /// Optional attributes of this numbered list.
+    pub attributes: Option<Attributes>,
+    /// The start of this numbered list in the original content.
+    pub start: Position,
+    /// The end of this numbered list in the original content.
+    pub end: Position,
+}
+
+impl BlockElement for NumberedList {
+    fn as_unimarkup(&self) -> String {
+        let mut s = String::default();
+
+        for entry in &self.entries {
+            s.push_str(&entry.as_unimarkup())
+        }
+
+        s
+    }
+
+    fn start(&self) -> unimarkup_commons::lexer::position::Position {
+        self.start
+    }
+
+    fn end(&self) -> unimarkup_commons::lexer::position::Position {
+        self.end
+    }
+}
+
+impl NumberedList {
+    /// Tries to create a numbered list from the current position of the given [`BlockParser`].
+    ///
+    /// Returns the block parser, and the optional numbered list.
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<Block>) {
+        let mut entries: Vec<NumberedListEntry> = Vec::new();
+
+        // `[1..]` to strip newline match for list start
+        while ENTRY_STARTS
+            .iter()
+            .any(|seq| parser.iter.matches(&seq[1..]))
+        {
+            let checkpoint = parser.iter.checkpoint();
+            let (updated_parser, list_entry_opt) = NumberedListEntry::parse(parser);
+            parser = updated_parser;
+
+            match list_entry_opt {
+                Some(list_entry)
+                    if entries
+                        .first()
+                        .is_none_or(|first| first.keyword.continues(&list_entry.keyword)) =>
+                {
+                    entries.push(list_entry);
+                }
+                _ => {
+                    // Reverts last tried entry parsing
+                    parser.iter.rollback(checkpoint);
+                    break;
+                }
+            }
+        }
+
+        let Some(first) = entries.first() else {
+            return (parser, None);
+        };
+
+        let kind = first.keyword.kind;
+        let delimiter = first.keyword.delimiter;
+        let start_number = first.keyword.number;
+        let start = first.start();
+        let end = entries
+            .last()
+            .expect("Ensured above that entries exist.")
+            .end();
+
+        (
+            parser,
+            Some(Block::NumberedList(NumberedList {
+                entries,
+                kind,
+                delimiter,
+                start_number,
+                attributes: None,
+                start,
+                end,
+            })),
+        )
+    }
+}
+
+/// Structure of a Unimarkup numbered list entry.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NumberedListEntry {
+    /// The [`NumberedListEntryKeyword`] used to create this entry.
+    pub keyword: NumberedListEntryKeyword,
+    /// The entry heading content of this entry.
+    pub heading: Vec<Inline>,
+    /// The body of this entry.
+    pub body: Vec<Block>,
+    /// Optional attributes of this entry.
+    pub attributes: Option<Attributes>,
+    /// The start of this entry in the original content.
+    pub start: Position,
+    /// The end of this entry in the original content.
+    pub end: Position,
+}
+
+impl BlockElement for NumberedListEntry {
+    fn as_unimarkup(&self) -> String {
+        let head_body_separator = match self.body.first() {
+            Some(Block::BulletList(_)) | Some(Block::NumberedList(_)) | None => {
+                SymbolKind::Newline.as_str().to_string()
+            }
+            Some(_) => SymbolKind::Newline.as_str().repeat(2), // to get a blankline between head and body
+        };
+
+        let plain_body = if self.body.is_empty() {
+            String::default()
+        } else {
+            self.body.as_unimarkup().lines().join("\n  ")
+        }; // Two space indentation after newline
+
+        format!(
+            "{} {}{}{}",
+            self.keyword.as_string(),
+            self.heading.as_unimarkup(),
+            head_body_separator,
+            plain_body
+        )
+    }
+
+    fn start(&self) -> unimarkup_commons::lexer::position::Position {
+        self.start
+    }
+
+    fn end(&self) -> unimarkup_commons::lexer::position::Position {
+        self.end
+    }
+}
+
+/// Enum representing the kind of enumerators used in a [`NumberedList`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EnumerationKind {
+    /// Arabic numbers: `1.`
+    Arabic,
+    /// Lower latin letters: `a.`
+    LowerLatin,
+    /// Upper latin letters: `A.`
+    UpperLatin,
+    /// Lower roman numbers prefixed with `R` or `r`: `Ri.`
+    LowerRoman,
+    /// Upper roman numbers prefixed with `r` or `R`: `rI.`
+    UpperRoman,
+}
+
+impl EnumerationKind {
+    /// Tries to convert the given enumerator into its [`EnumerationKind`] and number.
+    ///
+    /// Returns `None` if the enumerator is invalid, or represents the number `0` for non-arabic kinds.
+    pub fn from_enumerator(enumerator: &str) -> Option<(EnumerationKind, usize)> {
+        if enumerator.is_empty() {
+            return None;
+        }
+
+        if enumerator.chars().all(|c| c.is_ascii_digit()) {
+            return enumerator
+                .parse()
+                .ok()
+                .map(|number| (EnumerationKind::Arabic, number));
+        }
+
+        if let Some(roman) = enumerator
+            .strip_prefix('r')
+            .or_else(|| enumerator.strip_prefix('R'))
+        {
+            if !roman.is_empty() && roman.chars().all(|c| ROMAN_NUMERALS.contains(c)) {
+                return roman_to_number(roman).map(|number| (EnumerationKind::UpperRoman, number));
+            } else if !roman.is_empty()
+                && roman
+                    .chars()
+                    .all(|c| ROMAN_NUMERALS.contains(c.to_ascii_uppercase()) && c.is_lowercase())
+            {
+                return roman_to_number(&roman.to_ascii_uppercase())
+                    .map(|number| (EnumerationKind::LowerRoman, number));
+            }
+        }
+
+        // Longer words followed by a dot are most likely the end of a sentence
+        if enumerator.len() > MAX_LATIN_LETTERS {
+            None
+        } else if enumerator.chars().all(|c| c.is_ascii_lowercase()) {
+            latin_to_number(enumerator).map(|number| (EnumerationKind::LowerLatin, number))
+        } else if enumerator.chars().all(|c| c.is_ascii_uppercase()) {
+            latin_to_number(&enumerator.to_ascii_lowercase())
+                .map(|number| (EnumerationKind::UpperLatin, number))
+        } else {
+            None
+        }
+    }
+}
+
+/// Enum representing the delimiter used to mark enumerators of a [`NumberedListEntry`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EnumerationDelimiter {
+    /// Enumerator followed by a dot: `1.`
+    Dot,
+    /// Enumerator followed by a closing parenthesis: `1)`
+    Parenthesis,
+    /// Enumerator enclosed in parentheses: `(1)`
+    EnclosingParentheses,
+}
+
+impl EnumerationDelimiter {
+    /// Returns the given enumerator surrounded by this [`EnumerationDelimiter`].
+    pub fn enclose(&self, enumerator: &str) -> String {
+        match self {
+            EnumerationDelimiter::Dot => format!("{}{}", enumerator, SymbolKind::Dot.as_str()),
+            EnumerationDelimiter::Parenthesis => {
+                format!("{}{}", enumerator, SymbolKind::CloseParenthesis.as_str())
+            }
+            EnumerationDelimiter::EnclosingParentheses => format!(
+                "{}{}{}",
+                SymbolKind::OpenParenthesis.as_str(),
+                enumerator,
+                SymbolKind::CloseParenthesis.as_str()
+            ),
+        }
+    }
+}
+
+/// Structure representing the keyword used to create a [`NumberedListEntry`].
+///
+/// e.g. `1.`, `rI.`, `(a)`, or `1.1.` for sub entries that show the enumerator of their parent entry.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NumberedListEntryKeyword {
+    /// The [`EnumerationKind`] of the last enumerator.
+    pub kind: EnumerationKind,
+    /// The [`EnumerationDelimiter`] used for all enumerators.
+    pub delimiter: EnumerationDelimiter,
+    /// The number represented by the last enumerator.
+    pub number: usize,
+    /// All enumerators of this keyword in the original content.
+    /// Enumerators before the last one are those of the parent entries.
+    pub enumerators: Vec<String>,
+}
+
+impl NumberedListEntryKeyword {
+    /// String representation of the [`NumberedListEntryKeyword`].
+    pub fn as_string(&self) -> String {
+        self.enumerators
+            .iter()
+            .map(|enumerator| self.delimiter.enclose(enumerator))
+            .collect()
+    }
+
+    /// Returns `true` if an entry with the given keyword may follow an entry with this keyword in the same list.
+    pub fn continues(&self, other: &Self) -> bool {
+        self.kind == other.kind
+            && self.delimiter == other.delimiter
+            && self.enumerators.len() == other.enumerators.len()
+    }
+
+    /// Tries to consume a numbered list entry keyword including the space following it.
+    fn parse(parser: &mut BlockParser) -> Option<Self> {
+        let mut enumerators = Vec::new();
+        let mut delimiter = None;
+
+        let (kind, number) = loop {
+            let enclosed = parser.iter.peek_kind() == Some(TokenKind::OpenParenthesis);
+            if enclosed {
+                parser.iter.next();
+            }
+
+            let enumerator_token = parser.iter.next()?;
+            if enumerator_token.kind != TokenKind::Plain {
+                return None;
+            }
+            let enumerator = String::from(enumerator_token);
+
+            let curr_delimiter = match (enclosed, parser.iter.next()?.kind) {
+                (true, TokenKind::CloseParenthesis) => EnumerationDelimiter::EnclosingParentheses,
+                (false, TokenKind::CloseParenthesis) => EnumerationDelimiter::Parenthesis,
+                (false, TokenKind::Dot(1)) => EnumerationDelimiter::Dot,
+                _ => return None,
+            };
+
+            if *delimiter.get_or_insert(curr_delimiter) != curr_delimiter {
+                return None;
+            }
+
+            let enumeration = EnumerationKind::from_enumerator(&enumerator)?;
+            enumerators.push(enumerator);
+
+            if parser.iter.consumed_matches(&[TokenKind::Space]) {
+                break enumeration;
+            } else if !matches!(
+                parser.iter.peek_kind(),
+                Some(TokenKind::Plain | TokenKind::OpenParenthesis)
+            ) {
+                return None;
+            }
+        };
+
+        // Enumerators of parent entries are only valid in sub entries, and must match the parent entry.
+        // Otherwise, text like `e.g. this` at the start of a line would be parsed as list.
+        let parent_enumerators = &enumerators[..enumerators.len() - 1];
+        if !parent_enumerators.is_empty() && parent_enumerators != parser.context.parent_enumerators
+        {
+            return None;
+        }
+
+        Some(NumberedListEntryKeyword {
+            kind,
+            delimiter: delimiter?,
+            number,
+            enumerators,
+        })
+    }
+}
+
+impl TryFrom<&str> for NumberedListEntryKeyword {
+    type Error = ConversionError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (delimiter, enumerators): (_, Vec<&str>) = if let Some(inner) = value
+            .strip_prefix(SymbolKind::OpenParenthesis.as_str())
+            .and_then(|inner| inner.strip_suffix(SymbolKind::CloseParenthesis.as_str()))
+        {
+            (
+                EnumerationDelimiter::EnclosingParentheses,
+                inner.split(")(").collect(),
+            )
+        } else if let Some(inner) = value.strip_suffix(SymbolKind::Dot.as_str()) {
+            (
+                EnumerationDelimiter::Dot,
+                inner.split(SymbolKind::Dot.as_str()).collect(),
+            )
+        } else if let Some(inner) = value.strip_suffix(SymbolKind::CloseParenthesis.as_str()) {
+            (
+                EnumerationDelimiter::Parenthesis,
+                inner.split(SymbolKind::CloseParenthesis.as_str()).collect(),
+            )
+        } else {
+            return Err(ConversionError::CannotConvertToken);
+        };
+
+        let mut enumeration = None;
+        for enumerator in &enumerators {
+            enumeration = Some(
+                EnumerationKind::from_enumerator(enumerator)
+                    .ok_or(ConversionError::CannotConvertToken)?,
+            );
+        }
+        let (kind, number) = enumeration.ok_or(ConversionError::CannotConvertToken)?;
+
+        Ok(NumberedListEntryKeyword {
+            kind,
+            delimiter,
+            number,
+            enumerators: enumerators.into_iter().map(String::from).collect(),
+        })
+    }
+}
+
+/// Converts lowercase latin letters to their number (`a` = 1, `z` = 26, `aa` = 27, ...).
+///
+/// Returns `None` if the number does not fit into `usize`.
+fn latin_to_number(letters: &str) -> Option<usize> {
+    letters.bytes().try_fold(0usize, |number, b| {
+        number
+            .checked_mul(26)?
+            .checked_add(usize::from(b - b'a' + 1))
+    })
+}
+
+fn roman_to_number(numerals: &str) -> Option<usize> {
+    let values: Vec<usize> = numerals
+        .chars()
+        .map(|c| match c {
+            'I' => 1,
+            'V' => 5,
+            'X' => 10,
+            'L' => 50,
+            'C' => 100,
+            'D' => 500,
+            'M' => 1000,
+            _ => 0,
+        })
+        .collect();
+
+    let number = values
+        .iter()
+        .enumerate()
+        .fold(0isize, |number, (i, value)| {
+            let value = *value as isize;
+            match values.get(i + 1) {
+                Some(next) if *next as isize > value => number - value,
+                _ => number + value,
+            }
+        });
+
+    usize::try_from(number).ok().filter(|number| *number > 0)
+}
+
+const ROMAN_NUMERALS: &str = "IVXLCDM";
+
+/// Maximum number of letters of latin enumerators (`zz` = 702).
+const MAX_LATIN_LETTERS: usize = 2;
+
+// Consts below help with matching to prevent dynamic allocations.
+// Entry starts only match the first enumerator. The following `Plain` or `OpenParenthesis`
+// allows to detect keywords that contain enumerators of parent entries, e.g. `1.1.`.
+
+const ENTRY_STARTS: &[&[TokenKind]] = &[
+    &[
+        TokenKind::Newline,
+        TokenKind::Plain,
+        TokenKind::Dot(1),
+        TokenKind::Space,
+    ],
+    &[
+        TokenKind::Newline,
+        TokenKind::Plain,
+        TokenKind::Dot(1),
+        TokenKind::Plain,
+    ],
+    &[
+        TokenKind::Newline,
+        TokenKind::Plain,
+        TokenKind::CloseParenthesis,
+        TokenKind::Space,
+    ],
+    &[
+        TokenKind::Newline,
+        TokenKind::Plain,
+        TokenKind::CloseParenthesis,
+        TokenKind::Plain,
+    ],
+    &[
+        TokenKind::Newline,
+        TokenKind::OpenParenthesis,
+        TokenKind::Plain,
+        TokenKind::CloseParenthesis,
+        TokenKind::Space,
+    ],
+    &[
+        TokenKind::Newline,
+        TokenKind::OpenParenthesis,
+        TokenKind::Plain,
+        TokenKind::CloseParenthesis,
+        TokenKind::OpenParenthesis,
+    ],
+];
+
+const SUB_ENTRY_STARTS: &[&[TokenKind]] = &[
+    &[
+        TokenKind::Newline,
+        TokenKind::Space,
+        TokenKind::Space,
+        TokenKind::Plain,
+        TokenKind::Dot(1),
+        TokenKind::Space,
+    ],
+    &[
+        TokenKind::Newline,
+        TokenKind::Space,
+        TokenKind::Space,
+        TokenKind::Plain,
+        TokenKind::Dot(1),
+        TokenKind::Plain,
+    ],
+    &[
+        TokenKind::Newline,
+        TokenKind::Space,
+        TokenKind::Space,
+        TokenKind::Plain,
+        TokenKind::CloseParenthesis,
+        TokenKind::Space,
+    ],
+    &[
+        TokenKind::Newline,
+        TokenKind::Space,
+        TokenKind::Space,
+        TokenKind::Plain,
+        TokenKind::CloseParenthesis,
+        TokenKind::Plain,
+    ],
+    &[
+        TokenKind::Newline,
+        TokenKind::Space,
+        TokenKind::Space,
+        TokenKind::OpenParenthesis,
+        TokenKind::Plain,
+        TokenKind::CloseParenthesis,
+        TokenKind::Space,
+    ],
+    &[
+        TokenKind::Newline,
+        TokenKind::Space,
+        TokenKind::Space,
+        TokenKind::OpenParenthesis,
+        TokenKind::Plain,
+        TokenKind::CloseParenthesis,
+        TokenKind::OpenParenthesis,
+    ],
+];
+
+/// Returns the entry starts of entries using the given [`EnumerationDelimiter`].
+fn entry_starts(delimiter: EnumerationDelimiter) -> &'static [&'static [TokenKind]] {
+    match delimiter {
+        EnumerationDelimiter::Dot => &ENTRY_STARTS[..2],
+        EnumerationDelimiter::Parenthesis => &ENTRY_STARTS[2..4],
+        EnumerationDelimiter::EnclosingParentheses => &ENTRY_STARTS[4..],
+    }
+}
+
+/// Returns `true` if the upcoming tokens may start a nested numbered list entry.
+pub(super) fn matches_sub_entry_start(matcher: &mut dyn EndMatcher) -> bool {
+    SUB_ENTRY_STARTS.iter().any(|seq| matcher.matches(seq))
+}
+
+impl NumberedListEntry {
+    /// Tries to create a numbered list entry from the current position of the given [`BlockParser`].
+    ///
+    /// Returns the block parser, and the optional numbered list entry.
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<NumberedListEntry>) {
+        let Some(start_token) = parser.iter.peek() else {
+            return (parser, None);
+        };
+
+        let Some(entry_keyword) = NumberedListEntryKeyword::parse(&mut parser) else {
+            return (parser, None);
+        };
+
+        let indent_sequence = &[TokenKind::Space, TokenKind::Space];
+        let mut entry_heading_parser = parser.nest(
+            Some(Rc::new(|matcher: &mut dyn PrefixMatcher| {
+                matcher.consumed_prefix(indent_sequence)
+            })),
+            Some(Rc::new(|matcher: &mut dyn EndMatcher| {
+                matcher.consumed_is_blank_line()
+                    || matcher.matches(INDENTED_LINE_BREAK)
+                    || matcher.outer_end()
+                    || ENTRY_STARTS.iter().any(|seq| matcher.matches(seq))
+                    || matches_sub_entry_start(matcher)
+                    || matcher.matches(STAR_SUB_ENTRY_START)
+                    || matcher.matches(MINUS_SUB_ENTRY_START)
+                    || matcher.matches(PLUS_SUB_ENTRY_START)
+                    || matcher.matches(TASK_SUB_ENTRY_START)
+            })),
+        );
+
+        let (iter, inline_context, parsed_inlines) = parser::parse_inlines(
+            entry_heading_parser.iter,
+            (&entry_heading_parser.context).into(),
+            None,
+            None,
+        );
+        entry_heading_parser.iter = iter;
+        entry_heading_parser.context.update_from(inline_context);
+        let entry_heading = parsed_inlines.to_inlines();
+
+        parser = entry_heading_parser.into_inner();
+
+        // List entries without content are invalid
+        if entry_heading.is_empty() {
+            return (parser, None);
+        }
+
+        let delimiter = entry_keyword.delimiter;
+        let mut body = Vec::new();
+
+        // A directly following line with an enumerator of another delimiter does not start a new list,
+        // but is a paragraph of this entry.
+        if parser.iter.prev_kind() != Some(TokenKind::Blankline)
+            && ENTRY_STARTS.iter().any(|seq| parser.iter.matches(seq))
+            && !entry_starts(delimiter)
+                .iter()
+                .any(|seq| parser.iter.matches(seq))
+        {
+            parser.iter.next(); // Consume newline before the paragraph
+
+            let paragraph_parser = parser.nest(
+                None,
+                Some(Rc::new(move |matcher: &mut dyn EndMatcher| {
+                    entry_starts(delimiter)
+                        .iter()
+                        .any(|seq| matcher.matches(seq))
+                })),
+            );
+            let (updated_parser, paragraph) = Paragraph::parse(paragraph_parser);
+            parser = updated_parser.into_inner();
+            body.push(paragraph);
+        }
+
+        while parser.iter.consumed_is_blank_line() {
+            // skip empty lines
+            //TODO: add blanklines in case newlines should be kept
+        }
+
+        // Attributes of the list may directly follow the last entry heading
+        let attributes_follow = parser.iter.matches(&[TokenKind::OpenBrace]);
+
+        if !attributes_follow
+            && !parser.iter.end_reached()
+            && !ENTRY_STARTS.iter().any(|seq| parser.iter.matches(seq))
+        {
+            let outer_enumerators = std::mem::replace(
+                &mut parser.context.parent_enumerators,
+                entry_keyword.enumerators.clone(),
+            );
+            let entry_body_parser = parser.nest(
+                Some(Rc::new(|matcher: &mut dyn PrefixMatcher| {
+                    matcher.consumed_prefix(indent_sequence) || matcher.only_spaces_until_newline()
+                })),
+                None,
+            );
+            let (updated_parser, blocks) = BlockParser::parse(entry_body_parser);
+            parser = updated_parser.into_inner();
+            parser.context.parent_enumerators = outer_enumerators;
+            body.extend(blocks);
+        } else if !attributes_follow {
+            parser.iter.next(); // Consume "Newline" token of next list entry
+        };
+
+        let end = match body.last() {
+            Some(block) => block.end(),
+            None => entry_heading
+                .last()
+                .expect("Ensured above that entry heading has elements.")
+                .end(),
+        };
+
+        (
+            parser,
+            Some(NumberedListEntry {
+                keyword: entry_keyword,
+                heading: entry_heading,
+                body,
+                attributes: None,
+                start: start_token.start,
+                end,
+            }),
+        )
+    }
+}
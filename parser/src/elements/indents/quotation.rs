@@ -0,0 +1,161 @@
+//! Contains the structs and parsers to parse quotation blocks.
+
+use std::rc::Rc;
+
+use unimarkup_commons::attributes::Attributes;
+use unimarkup_commons::lexer::{
+    position::Position,
+    symbol::SymbolKind,
+    token::{
+        iterator::{EndMatcher, PrefixMatcher},
+        TokenKind,
+    },
+};
+use unimarkup_inline::{
+    element::{Inline, InlineElement},
+    parser,
+};
+
+use crate::{
+    elements::{blocks::Block, BlockElement, Blocks},
+    BlockParser,
+};
+
+/// Structure of a Unimarkup quotation block.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Quotation {
+    /// The blocks inside this quotation.
+    pub content: Blocks,
+    /// Optional attribution set with `>-- Author` after the quoted content.
+    pub author: Option<Vec<Inline>>,
+    /// Optional attributes of this quotation.
+    pub attributes: Option<Attributes>,
+    /// The start of this quotation in the original content.
+    pub start: Position,
+    /// The end of this quotation in the original content.
+    pub end: Position,
+}
+
+impl BlockElement for Quotation {
+    fn as_unimarkup(&self) -> String {
+        let mut s = self
+            .content
+            .as_unimarkup()
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    SymbolKind::GreaterThan.as_str().to_string()
+                } else {
+                    format!("{} {line}", SymbolKind::GreaterThan.as_str())
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(SymbolKind::Newline.as_str());
+
+        if let Some(author) = &self.author {
+            s.push_str(SymbolKind::Newline.as_str());
+            s.push_str(&format!(
+                "{}{} {}",
+                SymbolKind::GreaterThan.as_str(),
+                SymbolKind::Minus.as_str().repeat(2),
+                author.as_unimarkup()
+            ));
+        }
+
+        s
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+const QUOTATION_START: &[TokenKind] = &[TokenKind::GreaterThan(1), TokenKind::Space];
+const AUTHOR_START: &[TokenKind] = &[
+    TokenKind::Newline,
+    TokenKind::GreaterThan(1),
+    TokenKind::Minus(2),
+    TokenKind::Space,
+];
+
+impl Quotation {
+    /// Tries to create a quotation from the current position of the given [`BlockParser`].
+    ///
+    /// Returns the block parser, and the optional quotation.
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<Block>) {
+        if !parser.iter.matches(QUOTATION_START) {
+            return (parser, None);
+        }
+
+        let start_token = parser.iter.next().expect("Quotation start matched above.");
+        parser.iter.next(); // Consume space after keyword
+
+        let content_parser = parser.nest(
+            Some(Rc::new(|matcher: &mut dyn PrefixMatcher| {
+                // `>` without space is allowed for empty lines inside the quotation
+                matcher.consumed_prefix(QUOTATION_START)
+                    || matcher.consumed_prefix(&[TokenKind::GreaterThan(1)])
+            })),
+            Some(Rc::new(|matcher: &mut dyn EndMatcher| {
+                matcher.matches(AUTHOR_START)
+            })),
+        );
+        let (updated_parser, content) = BlockParser::parse(content_parser);
+        parser = updated_parser.into_inner();
+
+        if content.is_empty() {
+            return (parser, None);
+        }
+
+        let mut end = content
+            .last()
+            .expect("Ensured above that content exists.")
+            .end();
+        let mut author = None;
+
+        if parser.iter.consumed_matches(AUTHOR_START) {
+            let mut author_parser = parser.nest(
+                None,
+                Some(Rc::new(|matcher: &mut dyn EndMatcher| {
+                    matcher.matches(&[TokenKind::Newline])
+                        || matcher.is_blank_line()
+                        || matcher.outer_end()
+                })),
+            );
+
+            let (iter, inline_context, parsed_inlines) = parser::parse_inlines(
+                author_parser.iter,
+                (&author_parser.context).into(),
+                None,
+                None,
+            );
+            author_parser.iter = iter;
+            author_parser.context.update_from(inline_context);
+            parser = author_parser.into_inner();
+
+            let author_inlines = parsed_inlines.to_inlines();
+
+            if let Some(last) = author_inlines.last() {
+                end = last.end();
+                author = Some(author_inlines);
+            }
+        }
+
+        (
+            parser,
+            Some(Block::Quotation(Quotation {
+                content,
+                author,
+                attributes: None,
+                start: start_token.start,
+                end,
+            })),
+        )
+    }
+}
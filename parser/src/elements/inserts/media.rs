@@ -0,0 +1,231 @@
+//! Contains the structs and parsers to parse media inserts.
+
+use std::path::{Path, PathBuf};
+
+use unimarkup_commons::attributes::Attributes;
+use unimarkup_commons::lexer::{
+    position::Position,
+    token::{Token, TokenKind},
+};
+
+use crate::{
+    elements::{blocks::Block, decorations::Decorations, BlockElement},
+    BlockParser,
+};
+
+/// Structure of a Unimarkup media insert.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MediaInsert {
+    /// Alternative text that describes the media.
+    pub alt: String,
+    /// Source of the media as set in the Unimarkup content.
+    pub source: String,
+    /// Path of the media resolved relative to the input file.
+    /// Only set for local media, because remote media is referenced by its URL.
+    pub resolved_path: Option<PathBuf>,
+    /// The kind of media, derived from the file extension of the source.
+    pub kind: MediaKind,
+    /// Optional title and caption of this media insert.
+    pub decorations: Decorations,
+    /// Optional attributes of this media insert.
+    pub attributes: Option<Attributes>,
+    /// The start of this media insert in the original content.
+    pub start: Position,
+    /// The end of this media insert in the original content.
+    pub end: Position,
+}
+
+/// Possible kinds of media that may be inserted.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MediaKind {
+    /// Image that is shown inline, e.g. png or svg.
+    Image,
+    /// Video that is shown with playback controls.
+    Video,
+    /// Audio that is shown with playback controls.
+    Audio,
+}
+
+impl MediaKind {
+    /// Derives the media kind from the file extension of the given source.
+    /// Sources without a known video or audio extension are treated as images.
+    pub fn from_source(source: &str) -> Self {
+        let extension = Path::new(source)
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        match extension.as_str() {
+            "mp4" | "webm" | "ogv" | "mov" | "mkv" => MediaKind::Video,
+            "mp3" | "wav" | "ogg" | "oga" | "flac" | "m4a" | "aac" => MediaKind::Audio,
+            _ => MediaKind::Image,
+        }
+    }
+
+    /// Returns the kind of media as string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MediaKind::Image => "image",
+            MediaKind::Video => "video",
+            MediaKind::Audio => "audio",
+        }
+    }
+}
+
+impl MediaInsert {
+    /// Returns the resolved path for local media, or the source for remote media.
+    pub fn resource(&self) -> String {
+        match &self.resolved_path {
+            Some(path) => path.to_string_lossy().to_string(),
+            None => self.source.clone(),
+        }
+    }
+}
+
+impl BlockElement for MediaInsert {
+    fn as_unimarkup(&self) -> String {
+        let source = if self.source.contains(char::is_whitespace) {
+            format!("<{}>", self.source)
+        } else {
+            self.source.clone()
+        };
+
+        self.decorations
+            .wrap_unimarkup(format!("{MEDIA_INSERT_KEYWORD}[{}]({})", self.alt, source))
+    }
+
+    fn start(&self) -> Position {
+        self.start
+    }
+
+    fn end(&self) -> Position {
+        self.end
+    }
+}
+
+/// Keyword starting a media insert.
+/// Defined as string, because `!` is lexed as terminal punctuation.
+const MEDIA_INSERT_KEYWORD: &str = "!!!";
+
+impl MediaInsert {
+    /// Tries to create a media insert from the current position of the given [`BlockParser`].
+    ///
+    /// Returns the block parser, and the optional media insert.
+    pub(crate) fn parse<'s, 'i>(
+        mut parser: BlockParser<'s, 'i>,
+    ) -> (BlockParser<'s, 'i>, Option<Block>) {
+        let Some(start_token) = parser.iter.peek() else {
+            return (parser, None);
+        };
+        let start = start_token.start;
+
+        for keyword_char in MEDIA_INSERT_KEYWORD.chars() {
+            match parser.iter.next() {
+                Some(token)
+                    if token.kind == TokenKind::TerminalPunctuation
+                        && String::from(token) == keyword_char.to_string() => {}
+                _ => return (parser, None),
+            }
+        }
+
+        if parser.iter.next().map(|t| t.kind) != Some(TokenKind::OpenBracket) {
+            return (parser, None);
+        }
+        let Some(alt_tokens) = take_until(&mut parser, TokenKind::CloseBracket) else {
+            return (parser, None);
+        };
+
+        if parser.iter.next().map(|t| t.kind) != Some(TokenKind::OpenParenthesis) {
+            return (parser, None);
+        }
+        let Some(source_tokens) = take_until(&mut parser, TokenKind::CloseParenthesis) else {
+            return (parser, None);
+        };
+        let end = parser
+            .iter
+            .prev()
+            .expect("Close parenthesis was consumed above.")
+            .end;
+
+        let alt = Token::flatten(&alt_tokens).unwrap_or_default().to_string();
+        let source = Token::flatten(&source_tokens).unwrap_or_default();
+        // `<...>` allows whitespace in sources
+        let source = source
+            .strip_prefix('<')
+            .and_then(|s| s.strip_suffix('>'))
+            .unwrap_or(source)
+            .trim()
+            .to_string();
+
+        if source.is_empty() {
+            return (parser, None);
+        }
+
+        // Only spaces are allowed until the end of the line
+        while parser.iter.peek_kind() == Some(TokenKind::Whitespace) {
+            parser.iter.next();
+        }
+        if !matches!(
+            parser.iter.peek_kind(),
+            None | Some(TokenKind::Newline | TokenKind::Blankline | TokenKind::Eoi)
+        ) {
+            return (parser, None);
+        }
+
+        let resolved_path = resolve_path(&source, parser.context.input_dir.as_deref());
+        let media = MediaInsert {
+            alt,
+            kind: MediaKind::from_source(&source),
+            source,
+            resolved_path,
+            decorations: Decorations::default(),
+            attributes: None,
+            start,
+            end,
+        };
+
+        let resource = media.resource();
+        if !parser.context.resources.contains(&resource) {
+            parser.context.resources.push(resource);
+        }
+
+        (parser, Some(Block::MediaInsert(media)))
+    }
+}
+
+/// Consumes tokens until the given closing token kind, which is consumed, but not returned.
+/// Returns `None` if the line ends before the closing token.
+fn take_until<'s, 'i>(
+    parser: &mut BlockParser<'s, 'i>,
+    close: TokenKind,
+) -> Option<Vec<Token<'i>>> {
+    let mut tokens = Vec::new();
+
+    loop {
+        match parser.iter.next() {
+            Some(token) if token.kind == close => return Some(tokens),
+            Some(token)
+                if !matches!(
+                    token.kind,
+                    TokenKind::Newline | TokenKind::Blankline | TokenKind::Eoi
+                ) =>
+            {
+                tokens.push(*token)
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// Resolves the given source relative to the given input directory.
+/// Returns `None` for remote sources.
+fn resolve_path(source: &str, input_dir: Option<&Path>) -> Option<PathBuf> {
+    if source.contains("://") || source.starts_with("data:") {
+        return None;
+    }
+
+    match input_dir {
+        Some(dir) => Some(dir.join(source)),
+        None => Some(PathBuf::from(source)),
+    }
+}
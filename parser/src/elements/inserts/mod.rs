@@ -0,0 +1,5 @@
+//! Contains insert elements
+
+mod media;
+
+pub use media::*;
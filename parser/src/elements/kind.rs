@@ -26,6 +26,8 @@ pub enum PossibleBlockStart {
     QuotationBlock,
     /// Denotes that the following tokens may resolve to a line block.
     LineBlock,
+    /// Denotes that the following tokens may resolve to a definition list.
+    DefinitionList,
     /// Denotes that the following tokens may resolve to a media insert.
     MediaInsert,
     /// Denotes that the following tokens may resolve to a render insert.
@@ -36,8 +38,14 @@ pub enum PossibleBlockStart {
     HorizontalLine,
     /// Denotes that the following tokens may resolve to a line break.
     LineBreak,
+    /// Denotes that the following tokens may resolve to a page break.
+    PageBreak,
+    /// Denotes that the following tokens may resolve to a comment that is the only element in its line.
+    Comment,
     /// Denotes that the following tokens may resolve to a block decoration.
     Decoration,
+    /// Denotes that the following tokens may resolve to a footnote or abbreviation definition.
+    Definition,
     /// Every token that may not lead to another block element can only lead to a paragraph.
     #[default]
     Paragraph,
@@ -80,22 +88,72 @@ impl From<TokenKind> for PossibleBlockStart {
                     return PossibleBlockStart::VerbatimBlock;
                 }
             }
-            TokenKind::Underline(_)
-            | TokenKind::Caret(_)
+            TokenKind::Dollar(len) => {
+                if len >= 3 {
+                    return PossibleBlockStart::MathBlock;
+                }
+            }
+            TokenKind::Equals(len) => {
+                if len >= 3 {
+                    return PossibleBlockStart::Decoration;
+                }
+            }
+            TokenKind::SingleQuote(len) => {
+                if len >= 3 {
+                    return PossibleBlockStart::RenderBlock;
+                }
+            }
+            TokenKind::TerminalPunctuation => {
+                return PossibleBlockStart::MediaInsert;
+            }
+            TokenKind::Plain | TokenKind::OpenParenthesis => {
+                return PossibleBlockStart::Digit;
+            }
+            TokenKind::Pipe(len) => {
+                if len == 1 {
+                    return PossibleBlockStart::LineBlock;
+                } else if len >= 3 {
+                    return PossibleBlockStart::ColumnBlock;
+                }
+            }
+            TokenKind::GreaterThan(len) => {
+                if len == 1 {
+                    return PossibleBlockStart::QuotationBlock;
+                }
+            }
+            TokenKind::Colon(len) => {
+                if len == 1 {
+                    return PossibleBlockStart::DefinitionList;
+                } else if len == 3 {
+                    return PossibleBlockStart::PageBreak;
+                }
+            }
+            TokenKind::Underline(len) => {
+                if len == 1 {
+                    return PossibleBlockStart::Definition;
+                }
+            }
+            TokenKind::OpenBrace => {
+                return PossibleBlockStart::OpenBrace;
+            }
+            TokenKind::OpenBracket => {
+                return PossibleBlockStart::OpenBracket;
+            }
+            TokenKind::EscapedNewline | TokenKind::EscapedBlankline => {
+                return PossibleBlockStart::LineBreak;
+            }
+            TokenKind::Comment { .. } => {
+                return PossibleBlockStart::Comment;
+            }
+            TokenKind::Caret(_)
             | TokenKind::Overline(_)
-            | TokenKind::Pipe(_)
             | TokenKind::Tilde(_)
             | TokenKind::Quote(_)
-            | TokenKind::Dollar(_)
-            | TokenKind::Colon(_)
             | TokenKind::Dot(_)
             | TokenKind::Ampersand(_)
             | TokenKind::Comma(_)
-            | TokenKind::OpenParenthesis
             | TokenKind::CloseParenthesis
-            | TokenKind::OpenBracket
             | TokenKind::CloseBracket
-            | TokenKind::OpenBrace
             | TokenKind::CloseBrace
             | TokenKind::Whitespace
             | TokenKind::Newline
@@ -103,10 +161,6 @@ impl From<TokenKind> for PossibleBlockStart {
             | TokenKind::Eoi
             | TokenKind::EscapedPlain
             | TokenKind::EscapedWhitespace
-            | TokenKind::EscapedNewline
-            | TokenKind::Plain
-            | TokenKind::TerminalPunctuation
-            | TokenKind::Comment { .. }
             | TokenKind::ImplicitSubstitution(_)
             | TokenKind::DirectUri
             | TokenKind::Any
@@ -1,11 +1,16 @@
 //! Available elements for a Unimarkup document.
 
 pub mod atomic;
+pub(crate) mod attributes;
 pub mod blocks;
+pub mod decorations;
+pub mod definitions;
 pub mod enclosed;
 pub mod indents;
+pub mod inserts;
 pub mod kind;
 pub mod preamble;
+pub mod references;
 
 use unimarkup_commons::{
     lexer::{position::Position, span::Span},
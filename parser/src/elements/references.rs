@@ -0,0 +1,155 @@
+//! Contains the registry of elements that may be referenced using cross-references (e.g. `[##heading-id]_`).
+
+use std::collections::HashMap;
+
+use logid::log;
+use unimarkup_commons::lexer::span::Span;
+use unimarkup_inline::element::Inline;
+
+use crate::log_id::ParserWarning;
+
+use super::{blocks::Block, BlockElement};
+
+/// An element of a Unimarkup document that may be the target of a cross-reference.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ReferenceTarget {
+    /// The ID of the element.
+    pub id: String,
+    /// The variant of the referenced block (e.g. "Heading" or "Table").
+    pub kind: &'static str,
+    /// Optional title of the element, used as link text for references.
+    /// Only set for headings.
+    pub title: Option<Vec<Inline>>,
+    /// The number of the element, counted per block kind in document order starting at `1`.
+    /// Elements that may be decorated use the number of their title or caption,
+    /// and have no number if they are not decorated.
+    /// All other elements are counted, not only the ones with an ID.
+    pub number: Option<usize>,
+    /// `true` if the ID was generated from the title of a heading, and not set explicitly.
+    pub generated_id: bool,
+    /// The span of the element in the original content.
+    pub span: Span,
+}
+
+/// Collects all elements with an ID in the given blocks, including nested blocks.
+///
+/// Headings are registered with the ID set in their attributes, or their generated ID otherwise.
+/// Math blocks may also be referenced by their equation ID.
+/// If an ID is used multiple times, the first element is used and a warning is logged.
+pub fn reference_targets(blocks: &[Block]) -> Vec<ReferenceTarget> {
+    let mut targets = Vec::new();
+    collect_targets(blocks, &mut targets, &mut HashMap::new());
+    targets
+}
+
+fn collect_targets(
+    blocks: &[Block],
+    targets: &mut Vec<ReferenceTarget>,
+    numbers: &mut HashMap<&'static str, usize>,
+) {
+    for block in blocks {
+        let kind = block.variant_str();
+        let number = match block.decorations() {
+            Some(decorations) => decorations.number,
+            None => {
+                let counted = numbers.entry(kind).or_default();
+                *counted += 1;
+                Some(*counted)
+            }
+        };
+
+        let attribute_id = block
+            .attributes()
+            .and_then(|attributes| attributes.id())
+            .map(str::to_string);
+
+        let generated_id = attribute_id.is_none() && matches!(block, Block::Heading(_));
+        let (id, title) = match block {
+            Block::Heading(heading) => (
+                Some(attribute_id.unwrap_or_else(|| heading.id.clone())),
+                Some(heading.content.clone()),
+            ),
+            Block::MathBlock(math) => (math.id().map(str::to_string), None),
+            _ => (attribute_id, None),
+        };
+
+        if let Some(id) = id {
+            let span = block.span();
+
+            if let Some(first) = targets.iter().find(|target| target.id == id) {
+                log!(
+                    ParserWarning::DuplicateId,
+                    format!(
+                        "ID '{}' at {}:{}-{}:{} is already used at {}:{}-{}:{}. Only the first element may be referenced.",
+                        id,
+                        span.start.line,
+                        span.start.col_utf8,
+                        span.end.line,
+                        span.end.col_utf8,
+                        first.span.start.line,
+                        first.span.start.col_utf8,
+                        first.span.end.line,
+                        first.span.end.col_utf8,
+                    )
+                );
+            } else {
+                targets.push(ReferenceTarget {
+                    id,
+                    kind,
+                    title,
+                    number,
+                    generated_id,
+                    span,
+                });
+            }
+        }
+
+        match block {
+            Block::BulletList(list) => {
+                for entry in &list.entries {
+                    collect_targets(&entry.body, targets, numbers);
+                }
+            }
+            Block::BulletListEntry(entry) => collect_targets(&entry.body, targets, numbers),
+            Block::NumberedList(list) => {
+                for entry in &list.entries {
+                    collect_targets(&entry.body, targets, numbers);
+                }
+            }
+            Block::NumberedListEntry(entry) => collect_targets(&entry.body, targets, numbers),
+            Block::DefinitionList(list) => {
+                for entry in &list.entries {
+                    collect_targets(&entry.description, targets, numbers);
+                }
+            }
+            Block::Table(table) => {
+                for cell in table.rows.iter().flat_map(|row| &row.cells) {
+                    collect_targets(&cell.content, targets, numbers);
+                }
+            }
+            Block::Quotation(quotation) => collect_targets(&quotation.content, targets, numbers),
+            Block::LineBlock(line_block) => collect_targets(&line_block.content, targets, numbers),
+            Block::Columns(columns) => {
+                for column in &columns.columns {
+                    collect_targets(column, targets, numbers);
+                }
+            }
+            Block::FootnoteDefinition(footnote) => {
+                collect_targets(&footnote.content, targets, numbers)
+            }
+            Block::TextBlock(text_block) => collect_targets(&text_block.content, targets, numbers),
+            Block::Blankline(_)
+            | Block::Heading(_)
+            | Block::Paragraph(_)
+            | Block::VerbatimBlock(_)
+            | Block::MathBlock(_)
+            | Block::MediaInsert(_)
+            | Block::RenderBlock(_)
+            | Block::AbbreviationDefinition(_)
+            | Block::HorizontalLine(_)
+            | Block::LineBreak(_)
+            | Block::PageBreak(_)
+            | Block::Comment(_) => {}
+        }
+    }
+}
@@ -14,4 +14,10 @@ pub enum ParserError {
 pub enum ParserWarning {
     /// Log-id denoting an unsupported Unimarkup block
     UnsupportedBlock,
+
+    /// Log-id denoting a footnote that is defined multiple times
+    DuplicateFootnoteDefinition,
+
+    /// Log-id denoting an ID that is set for multiple elements
+    DuplicateId,
 }
@@ -1,5 +1,10 @@
 //! Module for parsing of Unimarkup elements.
 
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
 use unimarkup_commons::{
     config::ConfigFns,
     lexer::{
@@ -15,13 +20,17 @@ use unimarkup_inline::parser::{InlineContext, InlineContextFlags};
 use crate::{
     document::Document,
     elements::{
-        atomic::{Heading, Paragraph},
+        atomic::{Comment, Heading, HorizontalLine, LineBreak, PageBreak, Paragraph},
+        attributes,
         blocks::Block,
-        enclosed::VerbatimBlock,
-        indents::BulletList,
+        decorations::{self, Decoration},
+        definitions::{self, AbbreviationDefinition, FootnoteDefinition},
+        enclosed::{Columns, MathBlock, RenderBlock, Table, TextBlock, VerbatimBlock},
+        indents::{BulletList, DefinitionList, LineBlock, NumberedList, Quotation},
+        inserts::MediaInsert,
         kind::PossibleBlockStart,
         preamble::parse_preamble,
-        Blocks,
+        references, BlockElement, Blocks,
     },
     metadata::{Metadata, MetadataKind},
     security,
@@ -32,7 +41,13 @@ use unimarkup_commons::config::Config;
 pub fn parse_unimarkup(um_content: &str, mut config: Config) -> Document {
     let tokens = unimarkup_commons::lexer::token::lex_str(um_content);
 
-    let mut parser = BlockParser::new(TokenIterator::from(&*tokens), BlockContext::default());
+    let mut parser = BlockParser::new(
+        TokenIterator::from(&*tokens),
+        BlockContext {
+            input_dir: config.input.parent().map(Path::to_path_buf),
+            ..Default::default()
+        },
+    );
 
     let checkpoint = parser.iter.checkpoint();
     let (updated_parser, preamble) = parse_preamble(parser);
@@ -45,13 +60,23 @@ pub fn parse_unimarkup(um_content: &str, mut config: Config) -> Document {
         }
     }
 
+    parser.context.flags.no_implicits = config.preamble.render.disable_implicit_substitutions;
+
     let (parser, blocks) = BlockParser::parse(parser);
 
     let input = config.input.clone();
+    let references = references::reference_targets(&blocks);
     Document {
         config,
         blocks,
         citations: parser.context.citations,
+        footnotes: definitions::referenced_footnotes(
+            parser.context.footnote_definitions,
+            &parser.context.footnote_references,
+        ),
+        abbreviations: definitions::abbreviation_table(parser.context.abbreviation_definitions),
+        references,
+        resources: parser.context.resources,
         metadata: vec![Metadata {
             file: input,
             contenthash: security::get_contenthash(um_content),
@@ -113,6 +138,47 @@ impl<'slice, 'input> BlockParser<'slice, 'input> {
             } else {
                 let block_start = PossibleBlockStart::from(kind);
 
+                // Attributes in the line directly after a block belong to this block
+                if block_start == PossibleBlockStart::OpenBrace
+                    && !parser.context.flags.logic_only
+                    && parser.iter.prev_kind() == Some(TokenKind::Newline)
+                {
+                    if let Some(block) = blocks.last_mut() {
+                        let checkpoint = parser.iter.checkpoint();
+
+                        if let Some(attributes) = attributes::parse_attributes(&mut parser) {
+                            if block.set_attributes(attributes) {
+                                continue 'outer;
+                            }
+
+                            parser.iter.rollback(checkpoint);
+                        }
+                    }
+                }
+
+                // Decorations in the line directly after a block belong to this block
+                if block_start == PossibleBlockStart::Decoration
+                    && !parser.context.flags.logic_only
+                    && parser.iter.prev_kind() == Some(TokenKind::Newline)
+                {
+                    let decoration_line = parser.iter.peek().map(|t| t.start.line);
+
+                    if let Some(block) = blocks
+                        .last_mut()
+                        .filter(|block| decoration_line == Some(block.end().line + 1))
+                    {
+                        let checkpoint = parser.iter.checkpoint();
+
+                        if let Some(decoration) = Decoration::parse(&mut parser) {
+                            if decoration.attach_to(block, &mut parser.context) {
+                                continue 'outer;
+                            }
+                        }
+
+                        parser.iter.rollback(checkpoint);
+                    }
+                }
+
                 if block_start != PossibleBlockStart::Paragraph {
                     // Token might be start of a block element
                     for parser_fn in get_parser_fn(block_start, &parser.context) {
@@ -204,7 +270,10 @@ impl<'slice, 'input> BlockParser<'slice, 'input> {
 }
 
 /// Gets possible matching parser functions depending on the peeked token.
-fn get_parser_fn(start: PossibleBlockStart, context: &BlockContext) -> &'static [BlockParserFn] {
+pub(crate) fn get_parser_fn(
+    start: PossibleBlockStart,
+    context: &BlockContext,
+) -> &'static [BlockParserFn] {
     if context.flags.logic_only {
         // if start == PossibleBlockStart::OpenBrace {
         //     // TODO: return macro parser
@@ -216,23 +285,32 @@ fn get_parser_fn(start: PossibleBlockStart, context: &BlockContext) -> &'static
     } else {
         match start {
             PossibleBlockStart::Heading(_) => &[Heading::parse],
-            PossibleBlockStart::ColumnBlock => &[], //&[implicit_column_parser, explicit_column_parser],
-            PossibleBlockStart::MathBlock => &[],
-            PossibleBlockStart::RenderBlock => &[],
+            PossibleBlockStart::ColumnBlock => &[Columns::parse],
+            PossibleBlockStart::MathBlock => &[MathBlock::parse],
+            PossibleBlockStart::RenderBlock => &[RenderBlock::parse],
             PossibleBlockStart::VerbatimBlock => &[VerbatimBlock::parse],
-            PossibleBlockStart::Table => &[],
-            PossibleBlockStart::BulletList => &[BulletList::parse],
-            PossibleBlockStart::Digit => &[],
-            PossibleBlockStart::QuotationBlock => &[],
-            PossibleBlockStart::LineBlock => &[],
-            PossibleBlockStart::MediaInsert => &[],
+            PossibleBlockStart::Table => &[Table::parse],
+            PossibleBlockStart::BulletList => {
+                &[DefinitionList::parse, BulletList::parse, Table::parse]
+            }
+            PossibleBlockStart::Digit => &[NumberedList::parse],
+            PossibleBlockStart::QuotationBlock => &[Quotation::parse],
+            PossibleBlockStart::DefinitionList => &[DefinitionList::parse],
+            PossibleBlockStart::LineBlock => &[LineBlock::parse],
+            PossibleBlockStart::MediaInsert => &[MediaInsert::parse],
             PossibleBlockStart::RenderInsert => &[],
             PossibleBlockStart::VerbatimInsert => &[],
-            PossibleBlockStart::HorizontalLine => &[],
-            PossibleBlockStart::LineBreak => &[],
-            PossibleBlockStart::Decoration | PossibleBlockStart::Paragraph => &[],
-            PossibleBlockStart::OpenBracket => &[],
-            PossibleBlockStart::OpenBrace => &[], //&[attribute_block_parser, block_macro_parser],
+            PossibleBlockStart::HorizontalLine => &[HorizontalLine::parse],
+            PossibleBlockStart::LineBreak => &[LineBreak::parse],
+            PossibleBlockStart::PageBreak => &[PageBreak::parse],
+            PossibleBlockStart::Comment => &[Comment::parse],
+            PossibleBlockStart::Definition => {
+                &[FootnoteDefinition::parse, AbbreviationDefinition::parse]
+            }
+            PossibleBlockStart::Decoration => &[decorations::parse_decorated],
+            PossibleBlockStart::Paragraph => &[],
+            PossibleBlockStart::OpenBracket => &[TextBlock::parse],
+            PossibleBlockStart::OpenBrace => &[], // Attributes are set in `BlockParser::parse()`. TODO: &[block_macro_parser]
         }
     }
 }
@@ -246,6 +324,21 @@ pub struct BlockContext {
     /// The citations are added in document flow.
     /// Every citation may contain one or more citation entry IDs.
     pub citations: Vec<Vec<String>>,
+    /// IDs of referenced footnotes in document flow.
+    pub footnote_references: Vec<String>,
+    /// Footnote definitions found in the Unimarkup content.
+    pub footnote_definitions: Vec<FootnoteDefinition>,
+    /// Abbreviation definitions found in the Unimarkup content.
+    pub abbreviation_definitions: Vec<AbbreviationDefinition>,
+    /// Directory of the input file, used to resolve relative paths of referenced resources.
+    pub input_dir: Option<PathBuf>,
+    /// External resources referenced in the Unimarkup content.
+    pub resources: Vec<String>,
+    /// Number of decorated elements per block kind, used to number titles and captions.
+    pub decoration_numbers: HashMap<&'static str, usize>,
+    /// Enumerators of the numbered list entry whose body is parsed.
+    /// Sub entries may only repeat these enumerators in their keyword (e.g. `1.1.`).
+    pub parent_enumerators: Vec<String>,
 }
 
 /// Block context flags used to define parser behavior of block element parsing.
@@ -257,6 +350,8 @@ pub struct BlockContextFlags {
     pub keep_whitespaces: bool,
     /// Flag to indicate that a newline must be explicitly kept, and not converted to one space.
     pub keep_newline: bool,
+    /// Flag to indicate that implicit substitutions are disabled for the document.
+    pub no_implicits: bool,
 }
 
 impl From<&BlockContext> for InlineContext {
@@ -266,9 +361,10 @@ impl From<&BlockContext> for InlineContext {
                 logic_only: value.flags.logic_only,
                 keep_whitespaces: value.flags.keep_whitespaces,
                 keep_newline: value.flags.keep_newline,
-                allow_implicits: value.flags.logic_only,
+                allow_implicits: !value.flags.logic_only && !value.flags.no_implicits,
             },
             citations: Vec::new(),
+            footnote_references: Vec::new(),
         }
     }
 }
@@ -279,14 +375,17 @@ impl BlockContext {
         // Flags are not updated, because they only "propagate" block->inline, but not the other way.
 
         self.citations.append(&mut inline_context.citations);
+        self.footnote_references
+            .append(&mut inline_context.footnote_references);
     }
 }
 
 #[cfg(test)]
 mod test {
     use unimarkup_commons::lexer::token::iterator::TokenIterator;
+    use unimarkup_inline::element::InlineElement;
 
-    use crate::{parse_unimarkup, BlockContext, BlockParser};
+    use crate::{elements::BlockElement, parse_unimarkup, BlockContext, BlockParser};
 
     #[test]
     fn debugging_dummy() {
@@ -322,4 +421,64 @@ Funktioniert preamble parsing?
 
         assert!(!doc.metadata.is_empty());
     }
+
+    #[test]
+    fn media_inserts_listed_as_resources() {
+        let content = "!!![image](image.png)
+
+!!![same image](image.png)
+
+!!![logo](https://example.com/logo.svg)";
+        let config = unimarkup_commons::config::Config {
+            input: "docs/input.um".into(),
+            ..Default::default()
+        };
+        let doc = parse_unimarkup(content, config);
+
+        assert_eq!(
+            doc.resources,
+            vec![
+                std::path::Path::new("docs")
+                    .join("image.png")
+                    .to_string_lossy()
+                    .to_string(),
+                "https://example.com/logo.svg".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn first_footnote_definition_is_used() {
+        let content = "Text[^^a]_ with repeated[^^a]_ footnote.
+
+_[^^a] x
+_[^^a] y";
+        let doc = parse_unimarkup(content, unimarkup_commons::config::Config::default());
+
+        assert_eq!(doc.footnotes.len(), 1);
+        assert_eq!(doc.footnotes[0].id, "a");
+        assert_eq!(doc.footnotes[0].content.as_unimarkup(), "x");
+    }
+
+    #[test]
+    fn first_abbreviation_definition_is_used() {
+        let content = "Text using [::xml]_.
+
+_[::xml] Extensible Markup Language
+_[::html] Hypertext Markup Language
+_[::xml] Duplicate definition";
+        let doc = parse_unimarkup(content, unimarkup_commons::config::Config::default());
+
+        let abbreviations: Vec<_> = doc
+            .abbreviations
+            .iter()
+            .map(|abbr| abbr.abbreviation.as_str())
+            .collect();
+        assert_eq!(abbreviations, vec!["xml", "html"]);
+
+        let xml = doc
+            .abbreviation("xml")
+            .expect("Abbreviation 'xml' is defined.");
+        assert_eq!(xml.expansion.as_unimarkup(), "Extensible Markup Language");
+    }
 }
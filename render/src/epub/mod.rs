@@ -0,0 +1,60 @@
+//! Packages Unimarkup rendered to HTML as EPUB 3 publication.
+//!
+//! The rendered HTML body is split into chapters at level-1 headings.
+//! Every chapter is stored as XHTML content document, and the navigation document is generated from all headings.
+
+mod package;
+pub mod render;
+
+/// A chapter of the publication.
+/// Every chapter except a possible first one starts with a level-1 heading.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct EpubChapter {
+    /// File name of the chapter relative to the package document.
+    pub file: String,
+    /// Title of the chapter without markup, escaped for XML.
+    pub title: String,
+    /// Rendered HTML of the chapter.
+    pub content: String,
+}
+
+/// A heading that is listed in the navigation document.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct NavHeading {
+    pub level: u8,
+    /// Title of the heading without markup, escaped for XML.
+    pub title: String,
+    /// Link to the heading relative to the package document.
+    pub href: String,
+}
+
+/// A file embedded in the publication that is no content document (e.g. fonts or stylesheets).
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct EpubResource {
+    /// Path of the resource relative to the package document.
+    pub path: String,
+    pub media_type: &'static str,
+    pub content: Vec<u8>,
+}
+
+/// Metadata that is set in the package document.
+/// All values are escaped for XML.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct EpubMetadata {
+    pub identifier: String,
+    pub title: String,
+    pub authors: Vec<String>,
+    pub lang: String,
+    /// Timestamp of the last modification in the form `CCYY-MM-DDThh:mm:ssZ`.
+    pub modified: String,
+}
+
+/// Escapes characters in the given content that have a special meaning in XML.
+pub(crate) fn escape(content: &str) -> String {
+    content
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
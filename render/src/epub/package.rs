@@ -0,0 +1,195 @@
+//! Contains the documents that make up the structure of an EPUB 3 publication.
+
+use super::{escape, EpubChapter, EpubMetadata, EpubResource, NavHeading};
+
+/// Content of the `mimetype` file that must be the first entry of the container.
+pub(crate) const MIMETYPE: &str = "application/epub+zip";
+
+/// Path of the package document inside the container.
+pub(crate) const PACKAGE_PATH: &str = "OEBPS/content.opf";
+
+/// File name of the navigation document relative to the package document.
+pub(crate) const NAV_FILE: &str = "nav.xhtml";
+
+/// Returns the content of `META-INF/container.xml` that points to the package document.
+pub(crate) fn container() -> String {
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+        <container version=\"1.0\" xmlns=\"urn:oasis:names:tc:opendocument:xmlns:container\">\n\
+        <rootfiles>\n\
+        <rootfile full-path=\"{PACKAGE_PATH}\" media-type=\"application/oebps-package+xml\"/>\n\
+        </rootfiles>\n\
+        </container>\n"
+    )
+}
+
+/// Returns the package document listing metadata, all files of the publication, and the reading order.
+pub(crate) fn package_document(
+    metadata: &EpubMetadata,
+    chapters: &[EpubChapter],
+    resources: &[EpubResource],
+) -> String {
+    let mut package = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+        <package xmlns=\"http://www.idpf.org/2007/opf\" version=\"3.0\" unique-identifier=\"uid\" xml:lang=\"{}\">\n\
+        <metadata xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\n\
+        <dc:identifier id=\"uid\">{}</dc:identifier>\n\
+        <dc:title>{}</dc:title>\n",
+        metadata.lang, metadata.identifier, metadata.title
+    );
+
+    for author in &metadata.authors {
+        package.push_str(&format!("<dc:creator>{author}</dc:creator>\n"));
+    }
+
+    package.push_str(&format!(
+        "<dc:language>{}</dc:language>\n\
+        <meta property=\"dcterms:modified\">{}</meta>\n\
+        </metadata>\n\
+        <manifest>\n\
+        <item id=\"nav\" href=\"{NAV_FILE}\" media-type=\"application/xhtml+xml\" properties=\"nav\"/>\n",
+        metadata.lang, metadata.modified
+    ));
+
+    for chapter in chapters {
+        let mut properties = Vec::new();
+        if chapter.content.contains("<math") {
+            properties.push("mathml");
+        }
+        if chapter.content.contains("<script") {
+            properties.push("scripted");
+        }
+
+        let properties = if properties.is_empty() {
+            String::new()
+        } else {
+            format!(" properties=\"{}\"", properties.join(" "))
+        };
+
+        package.push_str(&format!(
+            "<item id=\"{}\" href=\"{}\" media-type=\"application/xhtml+xml\"{properties}/>\n",
+            item_id(chapter),
+            chapter.file
+        ));
+    }
+
+    for (i, resource) in resources.iter().enumerate() {
+        package.push_str(&format!(
+            "<item id=\"resource-{}\" href=\"{}\" media-type=\"{}\"/>\n",
+            i + 1,
+            escape(&resource.path),
+            resource.media_type
+        ));
+    }
+
+    package.push_str("</manifest>\n<spine>\n");
+
+    for chapter in chapters {
+        package.push_str(&format!("<itemref idref=\"{}\"/>\n", item_id(chapter)));
+    }
+
+    package.push_str("</spine>\n</package>\n");
+    package
+}
+
+/// Returns the navigation document with the table of contents built from the given headings.
+/// If no headings are given, the table of contents lists all chapters instead.
+pub(crate) fn navigation_document(
+    metadata: &EpubMetadata,
+    headings: &[NavHeading],
+    chapters: &[EpubChapter],
+    stylesheet: Option<&str>,
+) -> String {
+    let toc = if headings.is_empty() {
+        let chapter_headings: Vec<_> = chapters
+            .iter()
+            .map(|chapter| NavHeading {
+                level: 1,
+                title: chapter.title.clone(),
+                href: chapter.file.clone(),
+            })
+            .collect();
+
+        nav_list(&chapter_headings)
+    } else {
+        nav_list(headings)
+    };
+
+    content_document(
+        &metadata.lang,
+        &metadata.title,
+        &format!(
+            "<nav epub:type=\"toc\" id=\"toc\"><h1>{}</h1>{toc}</nav>",
+            metadata.title
+        ),
+        stylesheet,
+    )
+}
+
+/// Wraps the given body in an XHTML content document.
+pub(crate) fn content_document(
+    lang: &str,
+    title: &str,
+    body: &str,
+    stylesheet: Option<&str>,
+) -> String {
+    let stylesheet = stylesheet
+        .map(|href| {
+            format!(
+                "<link rel=\"stylesheet\" type=\"text/css\" href=\"{}\"/>\n",
+                escape(href)
+            )
+        })
+        .unwrap_or_default();
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+        <!DOCTYPE html>\n\
+        <html xmlns=\"http://www.w3.org/1999/xhtml\" xmlns:epub=\"http://www.idpf.org/2007/ops\" lang=\"{lang}\" xml:lang=\"{lang}\">\n\
+        <head>\n\
+        <meta charset=\"UTF-8\"/>\n\
+        <title>{title}</title>\n\
+        {stylesheet}\
+        </head>\n\
+        <body>\n\
+        {body}\n\
+        </body>\n\
+        </html>\n"
+    )
+}
+
+/// Returns the ID of the manifest item for the given chapter.
+fn item_id(chapter: &EpubChapter) -> &str {
+    chapter.file.strip_suffix(".xhtml").unwrap_or(&chapter.file)
+}
+
+/// Creates a nested ordered list from the given headings.
+/// Headings are nested below the closest previous heading with a lower level.
+fn nav_list(headings: &[NavHeading]) -> String {
+    let mut list = String::from("<ol>");
+    let mut i = 0;
+
+    while i < headings.len() {
+        let heading = &headings[i];
+        let end = headings[i + 1..]
+            .iter()
+            .position(|next| next.level <= heading.level)
+            .map_or(headings.len(), |pos| i + 1 + pos);
+
+        list.push_str(&format!(
+            "<li><a href=\"{}\">{}</a>",
+            escape(&heading.href),
+            heading.title
+        ));
+
+        if end > i + 1 {
+            list.push_str(&nav_list(&headings[i + 1..end]));
+        }
+
+        list.push_str("</li>");
+        i = end;
+    }
+
+    list.push_str("</ol>");
+    list
+}
@@ -0,0 +1,548 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::io::{Cursor, Write};
+use std::path::Path;
+
+use logid::log;
+use time::macros::format_description;
+use time::OffsetDateTime;
+use unimarkup_commons::config::Config;
+use unimarkup_parser::document::Document;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::html::tag::HtmlTag;
+use crate::html::{Html, HtmlAttribute, HtmlElement};
+use crate::log_id::RenderError::UnexpectedEpubError;
+use crate::log_id::{GeneralWarning, RenderError};
+
+use super::package::{self, MIMETYPE, NAV_FILE, PACKAGE_PATH};
+use super::{escape, EpubChapter, EpubMetadata, EpubResource, NavHeading};
+
+/// Path of the stylesheet relative to the package document.
+const STYLESHEET_PATH: &str = "styles/style.css";
+
+/// Directory of embedded images relative to the package document.
+const IMAGES_DIR: &str = "images";
+
+/// Packages the given HTML as EPUB 3 publication represented as bytes.
+/// The HTML body is split into chapters at level-1 headings, and the navigation document lists all headings.
+/// Title, authors, and embedded fonts are taken from the metadata of the document configuration,
+/// and local images listed in the document resources are embedded in the publication.
+///
+/// # Arguments
+/// * `html` - The rendered HTML
+/// * `doc` - The document the HTML was rendered from
+///
+/// # Returns
+/// The EPUB container as bytes.
+///
+/// # Errors
+/// * `UnexpectedEpubError` - in case a font could not be read, or the container could not be written.
+pub fn render_epub(html: &Html, doc: &Document) -> Result<Vec<u8>, RenderError> {
+    let config = &doc.config;
+    let (mut chapters, headings) = split_chapters(&html.body.elements);
+    link_across_chapters(&mut chapters);
+
+    let images = images(doc);
+    link_images(&mut chapters, &images);
+
+    let metadata = epub_metadata(html, config, &chapters);
+    for chapter in chapters.iter_mut().filter(|c| c.title.is_empty()) {
+        chapter.title = metadata.title.clone();
+    }
+
+    let mut resources = resources(html, config)?;
+    resources.extend(images.into_iter().map(|(_, image)| image));
+    let stylesheet = resources
+        .iter()
+        .any(|r| r.path == STYLESHEET_PATH)
+        .then_some(STYLESHEET_PATH);
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+
+    // Reading systems detect EPUB containers by an uncompressed `mimetype` file at the start of the archive
+    write_entry(
+        &mut zip,
+        "mimetype",
+        MIMETYPE.as_bytes(),
+        FileOptions::default().compression_method(CompressionMethod::Stored),
+    )?;
+
+    let deflated = FileOptions::default();
+    write_entry(
+        &mut zip,
+        "META-INF/container.xml",
+        package::container().as_bytes(),
+        deflated,
+    )?;
+    write_entry(
+        &mut zip,
+        PACKAGE_PATH,
+        package::package_document(&metadata, &chapters, &resources).as_bytes(),
+        deflated,
+    )?;
+    write_entry(
+        &mut zip,
+        &format!("OEBPS/{NAV_FILE}"),
+        package::navigation_document(&metadata, &headings, &chapters, stylesheet).as_bytes(),
+        deflated,
+    )?;
+
+    for chapter in &chapters {
+        let document = package::content_document(
+            &metadata.lang,
+            &chapter.title,
+            &to_xhtml(&chapter.content),
+            stylesheet,
+        );
+        write_entry(
+            &mut zip,
+            &format!("OEBPS/{}", chapter.file),
+            document.as_bytes(),
+            deflated,
+        )?;
+    }
+
+    for resource in &resources {
+        write_entry(
+            &mut zip,
+            &format!("OEBPS/{}", resource.path),
+            &resource.content,
+            deflated,
+        )?;
+    }
+
+    zip.finish()
+        .map(Cursor::into_inner)
+        .map_err(|err| UnexpectedEpubError(err.to_string()))
+}
+
+fn write_entry(
+    zip: &mut ZipWriter<Cursor<Vec<u8>>>,
+    path: &str,
+    content: &[u8],
+    options: FileOptions,
+) -> Result<(), RenderError> {
+    zip.start_file(path, options)
+        .map_err(|err| UnexpectedEpubError(err.to_string()))?;
+    zip.write_all(content)
+        .map_err(|err| UnexpectedEpubError(err.to_string()))
+}
+
+/// Splits the given top-level elements into chapters that start at level-1 headings,
+/// and collects all headings for the navigation document.
+fn split_chapters(elements: &[HtmlElement]) -> (Vec<EpubChapter>, Vec<NavHeading>) {
+    let mut chapters = vec![EpubChapter {
+        file: chapter_file(1),
+        ..Default::default()
+    }];
+    let mut headings = Vec::new();
+
+    for element in elements {
+        let level = heading_level(element.tag);
+
+        if level == Some(1) && !chapters[chapters.len() - 1].content.trim().is_empty() {
+            chapters.push(EpubChapter {
+                file: chapter_file(chapters.len() + 1),
+                ..Default::default()
+            });
+        }
+
+        let chapter = chapters
+            .last_mut()
+            .expect("At least one chapter is always present.");
+
+        if let Some(level) = level {
+            let title = escape(&strip_tags(element.content.as_deref().unwrap_or_default()));
+            let id = element
+                .attributes
+                .iter()
+                .find(|attrb| attrb.name == "id")
+                .and_then(|attrb| attrb.value.as_deref());
+
+            if level == 1 && chapter.title.is_empty() {
+                chapter.title = title.clone();
+            }
+
+            let href = match id {
+                Some(id) => format!("{}#{id}", chapter.file),
+                None => chapter.file.clone(),
+            };
+
+            headings.push(NavHeading {
+                level,
+                // Links in the navigation document must not be empty
+                title: if title.is_empty() {
+                    escape(&href)
+                } else {
+                    title
+                },
+                href,
+            });
+        }
+
+        chapter.content.push_str(&element.to_string());
+    }
+
+    (chapters, headings)
+}
+
+fn chapter_file(number: usize) -> String {
+    format!("chapter-{number}.xhtml")
+}
+
+fn heading_level(tag: HtmlTag) -> Option<u8> {
+    match tag {
+        HtmlTag::H1 => Some(1),
+        HtmlTag::H2 => Some(2),
+        HtmlTag::H3 => Some(3),
+        HtmlTag::H4 => Some(4),
+        HtmlTag::H5 => Some(5),
+        HtmlTag::H6 => Some(6),
+        _ => None,
+    }
+}
+
+/// Removes all tags from the given HTML, keeping only the text content.
+/// Characters escaped by the HTML renderer are unescaped again.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.trim()
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Internal links only consist of a fragment (e.g. `#fn-1`),
+/// which does not resolve if the target is located in another chapter.
+/// Therefore, the chapter file is prepended to fragments pointing to other chapters.
+fn link_across_chapters(chapters: &mut [EpubChapter]) {
+    const ID_ATTRB: &str = " id='";
+    const FRAGMENT_HREF: &str = "href='#";
+
+    let mut targets = HashMap::new();
+    for chapter in chapters.iter() {
+        for (start, _) in chapter.content.match_indices(ID_ATTRB) {
+            let value = &chapter.content[start + ID_ATTRB.len()..];
+            let id = &value[..value.find('\'').unwrap_or(value.len())];
+            targets
+                .entry(id.to_string())
+                .or_insert_with(|| chapter.file.clone());
+        }
+    }
+
+    for chapter in chapters.iter_mut() {
+        let mut linked = String::with_capacity(chapter.content.len());
+        let mut rest = chapter.content.as_str();
+
+        while let Some(start) = rest.find(FRAGMENT_HREF) {
+            // Keep `href='`, and insert the file in front of `#`
+            let (before, fragment) = rest.split_at(start + FRAGMENT_HREF.len() - 1);
+            linked.push_str(before);
+
+            let end = fragment.find('\'').unwrap_or(fragment.len());
+            if let Some(file) = targets
+                .get(&fragment[1..end])
+                .filter(|file| **file != chapter.file)
+            {
+                linked.push_str(file);
+            }
+
+            linked.push_str(&fragment[..end]);
+            rest = &fragment[end..];
+        }
+
+        linked.push_str(rest);
+        chapter.content = linked;
+    }
+}
+
+/// Converts the given HTML to XHTML, because XHTML neither allows attributes without value (e.g. `checked`),
+/// nor void elements that are not closed (e.g. `<hr>`).
+/// Attributes without value get their name as value, void elements are closed,
+/// and MathML elements get their namespace, which is implied in HTML.
+fn to_xhtml(html: &str) -> String {
+    const VOID_ELEMENTS: [&str; 14] = [
+        "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param",
+        "source", "track", "wbr",
+    ];
+
+    let mut xhtml = String::with_capacity(html.len());
+    let mut chars = html.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        xhtml.push(c);
+
+        if c != '<' || !chars.peek().is_some_and(char::is_ascii_alphabetic) {
+            continue;
+        }
+
+        let mut tag = String::new();
+        while let Some(c) = chars.next_if(|c| !c.is_whitespace() && *c != '/' && *c != '>') {
+            tag.push(c);
+        }
+        xhtml.push_str(&tag);
+
+        // Attributes
+        let mut has_xmlns = false;
+        while let Some(&c) = chars.peek() {
+            if c == '>' {
+                if tag == "math" && !has_xmlns {
+                    let end = xhtml.len() - usize::from(xhtml.ends_with('/'));
+                    xhtml.insert_str(end, " xmlns='http://www.w3.org/1998/Math/MathML'");
+                }
+                if VOID_ELEMENTS.contains(&tag.to_lowercase().as_str()) && !xhtml.ends_with('/') {
+                    xhtml.push('/');
+                }
+                break;
+            } else if c.is_whitespace() || c == '/' {
+                xhtml.push(c);
+                chars.next();
+                continue;
+            }
+
+            let mut name = String::new();
+            while let Some(c) =
+                chars.next_if(|c| !c.is_whitespace() && !matches!(c, '/' | '>' | '='))
+            {
+                name.push(c);
+            }
+            xhtml.push_str(&name);
+            has_xmlns |= name == "xmlns";
+
+            match chars.next_if_eq(&'=') {
+                Some(eq) => {
+                    xhtml.push(eq);
+
+                    if let Some(quote) = chars.next_if(|c| *c == '\'' || *c == '"') {
+                        xhtml.push(quote);
+                        for c in chars.by_ref() {
+                            xhtml.push(c);
+                            if c == quote {
+                                break;
+                            }
+                        }
+                    }
+                }
+                None => xhtml.push_str(&format!("='{name}'")),
+            }
+        }
+    }
+
+    xhtml
+}
+
+fn epub_metadata(html: &Html, config: &Config, chapters: &[EpubChapter]) -> EpubMetadata {
+    let metadata = &config.preamble.metadata;
+
+    let title = metadata
+        .title
+        .clone()
+        .or_else(|| {
+            config
+                .input
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+        })
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| "Untitled".to_string());
+
+    let mut authors: Vec<_> = metadata.authors.iter().map(|a| escape(a)).collect();
+    authors.sort();
+
+    // The identifier must stay the same for the same publication, so it is derived from its content
+    let mut hasher = DefaultHasher::new();
+    title.hash(&mut hasher);
+    authors.hash(&mut hasher);
+    chapters.hash(&mut hasher);
+
+    let modified = OffsetDateTime::now_utc()
+        .format(format_description!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second]Z"
+        ))
+        .unwrap_or_default();
+
+    EpubMetadata {
+        identifier: format!("urn:unimarkup:{:016x}", hasher.finish()),
+        title: escape(&title),
+        authors,
+        lang: escape(&html.lang),
+        modified,
+    }
+}
+
+/// Collects the fonts set in the metadata of the given configuration,
+/// and creates a stylesheet declaring the fonts together with the syntax highlighting styles.
+fn resources(html: &Html, config: &Config) -> Result<Vec<EpubResource>, RenderError> {
+    let mut fonts: Vec<_> = config.preamble.metadata.fonts.iter().collect();
+    fonts.sort();
+
+    let mut resources = Vec::new();
+    let mut stylesheet = String::new();
+    let mut families = Vec::new();
+
+    for font in fonts {
+        let content = std::fs::read(font).map_err(|err| {
+            UnexpectedEpubError(format!("Reading font '{}' failed: {err}", font.display()))
+        })?;
+        let file_name = font
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let family = font
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let path = format!("fonts/{file_name}");
+
+        stylesheet.push_str(&format!(
+            "@font-face {{ font-family: '{family}'; src: url('../{path}'); }}\n"
+        ));
+        families.push(format!("'{family}'"));
+        resources.push(EpubResource {
+            path,
+            media_type: font_media_type(font),
+            content,
+        });
+    }
+
+    if !families.is_empty() {
+        stylesheet.push_str(&format!(
+            "body {{ font-family: {}, serif; }}\n",
+            families.join(", ")
+        ));
+    }
+
+    if html.head.syntax_highlighting_used {
+        stylesheet.push_str(include_str!("../../styles/syntax_highlighting.css"));
+    }
+
+    if !stylesheet.is_empty() {
+        resources.push(EpubResource {
+            path: STYLESHEET_PATH.to_string(),
+            media_type: "text/css",
+            content: stylesheet.into_bytes(),
+        });
+    }
+
+    Ok(resources)
+}
+
+/// Reads the local images listed in the resources of the given document.
+/// Every image is returned together with the source it is referenced with in the rendered HTML.
+///
+/// Images that cannot be read are skipped, and stay referenced by their source.
+fn images(doc: &Document) -> Vec<(String, EpubResource)> {
+    // Sources of media inserts are resolved relative to the directory of the input file
+    let input_dir = doc
+        .config
+        .input
+        .parent()
+        .map(|dir| dir.to_string_lossy().to_string())
+        .filter(|dir| !dir.is_empty())
+        .map(|dir| format!("{dir}{}", std::path::MAIN_SEPARATOR));
+
+    let mut images = Vec::new();
+
+    for resource in &doc.resources {
+        let path = Path::new(resource);
+        let Some(media_type) = image_media_type(path) else {
+            continue;
+        };
+
+        if resource.contains("://") || resource.starts_with("data:") {
+            continue;
+        }
+
+        let content = match std::fs::read(path) {
+            Ok(content) => content,
+            Err(err) => {
+                log!(
+                    GeneralWarning::FileRead,
+                    format!("Could not read image '{resource}' to add it to the EPUB: {err}"),
+                );
+                continue;
+            }
+        };
+
+        let source = input_dir
+            .as_deref()
+            .and_then(|dir| resource.strip_prefix(dir))
+            .unwrap_or(resource);
+        let extension = path
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        images.push((
+            source.to_string(),
+            EpubResource {
+                path: format!("{IMAGES_DIR}/image-{}.{extension}", images.len() + 1),
+                media_type,
+                content,
+            },
+        ));
+    }
+
+    images
+}
+
+/// Replaces the sources of the given images in all chapters with the path of the embedded image.
+fn link_images(chapters: &mut [EpubChapter], images: &[(String, EpubResource)]) {
+    let src = |value: &str| {
+        HtmlAttribute {
+            name: "src".to_string(),
+            value: Some(value.to_string()),
+        }
+        .to_string()
+    };
+
+    for chapter in chapters {
+        for (source, image) in images {
+            chapter.content = chapter.content.replace(&src(source), &src(&image.path));
+        }
+    }
+}
+
+/// Returns the media type of the given image, or `None` if it is no image supported by EPUB reading systems.
+fn image_media_type(image: &Path) -> Option<&'static str> {
+    match image
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .as_deref()
+    {
+        Some("png") => Some("image/png"),
+        Some("jpg" | "jpeg") => Some("image/jpeg"),
+        Some("gif") => Some("image/gif"),
+        Some("svg") => Some("image/svg+xml"),
+        Some("webp") => Some("image/webp"),
+        _ => None,
+    }
+}
+
+fn font_media_type(font: &Path) -> &'static str {
+    match font
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .as_deref()
+    {
+        Some("ttf") => "font/ttf",
+        Some("otf") => "font/otf",
+        Some("woff") => "font/woff",
+        Some("woff2") => "font/woff2",
+        _ => "application/octet-stream",
+    }
+}
@@ -180,6 +180,9 @@ mod tests {
             variables: vec![],
             metadata: vec![],
             resources: vec![],
+            footnotes: vec![],
+            abbreviations: vec![],
+            references: vec![],
         }
     }
 
@@ -7,6 +7,7 @@ use self::tag::HtmlTag;
 pub(crate) mod citeproc;
 pub mod highlight;
 pub mod render;
+pub mod render_block;
 pub mod tag;
 
 #[derive(Debug, Default)]
@@ -36,6 +37,7 @@ pub struct HtmlHead {
     pub elements: HtmlElements,
     pub syntax_highlighting_used: bool,
     pub paged_js_used: bool,
+    pub mermaid_used: bool,
     pub styles: HtmlAttributes, //TODO: replace with CSS struct
 }
 
@@ -45,6 +47,7 @@ impl HtmlHead {
         self.styles.append(&mut other.styles);
         self.syntax_highlighting_used |= other.syntax_highlighting_used;
         self.paged_js_used |= other.paged_js_used;
+        self.mermaid_used |= other.mermaid_used;
     }
 }
 
@@ -102,6 +105,7 @@ impl OutputFormat for Html {
                 elements: HtmlElements(Vec::new()),
                 syntax_highlighting_used: false,
                 paged_js_used: false,
+                mermaid_used: false,
                 styles: HtmlAttributes(Vec::new()),
             },
             body: HtmlBody {
@@ -192,6 +196,8 @@ impl std::fmt::Display for HtmlHead {
                 "<script>{}</script>",
                 include_str!("paged.polyfill.min.js")
             );
+            // Paged.js moves footnote content to the footnote area of the page
+            let _ = write!(f, "<style>.footnote {{ float: footnote; }}</style>");
             include_str!("../../styles/syntax_highlighting_paged_js.css")
         } else {
             include_str!("../../styles/syntax_highlighting.css")
@@ -199,6 +205,13 @@ impl std::fmt::Display for HtmlHead {
         if self.syntax_highlighting_used {
             write!(f, "<style>{}</style>", highlighting)?;
         }
+        if self.mermaid_used {
+            write!(
+                f,
+                "<script type=\"module\">{}</script>",
+                render_block::MERMAID_SCRIPT
+            )?;
+        }
 
         //TODO: write other head styles (try to use LightningCss optimizations)
 
@@ -233,7 +246,12 @@ impl From<HtmlElement> for HtmlBody {
 impl std::fmt::Display for HtmlAttribute {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self.value {
-            Some(value) => write!(f, "{}='{}'", self.name, value),
+            Some(value) => write!(
+                f,
+                "{}='{}'",
+                self.name,
+                render_block::escape_text(value).replace('\'', "&#39;")
+            ),
             None => write!(f, "{}", self.name),
         }
     }
@@ -245,6 +263,34 @@ impl From<Vec<HtmlAttribute>> for HtmlAttributes {
     }
 }
 
+impl HtmlAttributes {
+    /// Sets the value of the attribute with the given name, replacing a previously set value.
+    pub fn set(&mut self, name: &str, value: String) {
+        match self.0.iter_mut().find(|attrb| attrb.name == name) {
+            Some(attrb) => attrb.value = Some(value),
+            None => self.0.push(HtmlAttribute {
+                name: name.to_string(),
+                value: Some(value),
+            }),
+        }
+    }
+
+    /// Appends the value to the attribute with the given name, using the separator between the previous and new value.
+    /// The attribute is set if it was not set before.
+    pub fn extend(&mut self, name: &str, value: &str, separator: &str) {
+        match self.0.iter_mut().find(|attrb| attrb.name == name) {
+            Some(HtmlAttribute {
+                value: Some(prev_value),
+                ..
+            }) if !prev_value.is_empty() => {
+                prev_value.push_str(separator);
+                prev_value.push_str(value);
+            }
+            _ => self.set(name, value.to_string()),
+        }
+    }
+}
+
 impl std::fmt::Display for HtmlAttributes {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for attrb in &self.0 {
@@ -1,37 +1,261 @@
+use std::{collections::HashSet, path::Path};
+
 use crate::html::citeproc::CiteprocWrapper;
-use crate::log_id::RenderError;
-use serde_json::Value;
+use crate::log_id::{GeneralWarning, RenderError};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use logid::log;
 use unimarkup_commons::lexer::{span::Span, symbol::SymbolKind, token::TokenKind};
-use unimarkup_inline::element::substitution::DistinctReference;
+use unimarkup_inline::element::substitution::{
+    direct::DirectUnicode, named::NamedSubstitution, DistinctReference, ImplicitSubstitution,
+};
 use unimarkup_inline::element::{
-    base::{EscapedNewline, EscapedPlain, EscapedWhitespace, Newline, Plain},
+    base::{Comment, EscapedNewline, EscapedPlain, EscapedWhitespace, Newline, Plain},
     formatting::{
         Bold, Highlight, Italic, Math, Overline, Quote, Strikethrough, Subscript, Superscript,
         Underline, Verbatim,
     },
-    textbox::{citation::Citation, hyperlink::Hyperlink, TextBox},
+    textbox::{
+        abbreviation::{AbbreviationDisplay, AbbreviationReference},
+        citation::Citation,
+        footnote::FootnoteReference,
+        hyperlink::Hyperlink,
+        reference::Reference,
+        TextBox,
+    },
     Inline, InlineElement,
 };
-use unimarkup_parser::elements::indents::{BulletList, BulletListEntry};
+use unimarkup_parser::elements::{
+    atomic::{self, HorizontalLine, LineBreak, PageBreak},
+    blocks::Block,
+    decorations::Decorations,
+    definitions::{AbbreviationDefinition, FootnoteDefinition},
+    enclosed::{
+        Columns, MathBlock, RenderBlock, Table, TableCell, TableCellKind, TableRow, TextBlock,
+        VerbatimBlock,
+    },
+    indents::{
+        BulletList, BulletListEntry, DefinitionList, EnumerationKind, LineBlock, NumberedList,
+        NumberedListEntry, Quotation, TaskStatus,
+    },
+    inserts::{MediaInsert, MediaKind},
+};
 
-use crate::render::{Context, OutputFormat, Renderer};
+use crate::render::{
+    decoration_label, plain_text, Context, OutputFormat, Renderer, FIGURE_LABEL, LISTING_LABEL,
+    TABLE_LABEL,
+};
+use crate::render_block::{RenderBlockHandler, RenderBlockHandlers};
 
 use super::{
-    highlight, tag::HtmlTag, Html, HtmlAttribute, HtmlAttributes, HtmlBody, HtmlElement, HtmlHead,
+    highlight,
+    render_block::{DotHandler, MermaidHandler},
+    tag::HtmlTag,
+    Html, HtmlAttribute, HtmlAttributes, HtmlBody, HtmlElement, HtmlHead,
 };
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct HtmlRenderer {
     use_paged_js: bool,
     citation_index: usize,
+    /// Numbers of footnotes that were already referenced.
+    referenced_footnotes: HashSet<usize>,
+    render_block_handlers: RenderBlockHandlers<Html>,
+}
+
+impl Default for HtmlRenderer {
+    fn default() -> Self {
+        Self::new(false)
+    }
 }
 
 impl HtmlRenderer {
     pub fn new(use_paged_js: bool) -> Self {
+        let mut render_block_handlers = RenderBlockHandlers::new();
+        render_block_handlers.register(MermaidHandler);
+        render_block_handlers.register(DotHandler);
+
         HtmlRenderer {
             use_paged_js,
             citation_index: 0,
+            referenced_footnotes: HashSet::new(),
+            render_block_handlers,
+        }
+    }
+
+    /// Registers a handler to render render blocks of the languages it handles.
+    /// The handler takes precedence over previously registered handlers, including the default ones.
+    pub fn register_render_block_handler(
+        &mut self,
+        handler: impl RenderBlockHandler<Html> + 'static,
+    ) {
+        self.render_block_handlers.register(handler);
+    }
+
+    /// Renders the content of the given footnote, so it may be placed inside a paragraph.
+    /// Paragraphs are rendered without `<p>` tags, and are separated by line breaks.
+    fn render_footnote_content(
+        &mut self,
+        footnote: &FootnoteDefinition,
+        context: &Context,
+    ) -> Result<Html, RenderError> {
+        let mut content = Html::new(context);
+
+        for (index, block) in footnote.content.iter().enumerate() {
+            if index > 0 {
+                content.body.elements.push(HtmlElement {
+                    tag: HtmlTag::Br,
+                    attributes: HtmlAttributes::default(),
+                    content: None,
+                });
+            }
+
+            match block {
+                Block::Paragraph(paragraph) => {
+                    content.append(self.render_nested_inline(&paragraph.content, context)?)?
+                }
+                block => content.append(self.render_block(block, context)?)?,
+            }
+        }
+
+        Ok(content)
+    }
+
+    /// Renders the title and caption of a decorated element, prefixed with its label (e.g. "Table 3: ").
+    /// The caption is placed in a new line, if both title and caption are set.
+    ///
+    /// Returns `None` if the element is not decorated.
+    fn render_decorations(
+        &mut self,
+        label: &str,
+        decorations: &Decorations,
+        context: &Context,
+    ) -> Result<Option<Html>, RenderError> {
+        let mut html = match decoration_label(label, decorations) {
+            Some(label) => Html::with_body(HtmlBody::from(HtmlElement {
+                tag: HtmlTag::PlainContent,
+                attributes: HtmlAttributes::default(),
+                content: Some(format!("{label}: ")),
+            })),
+            None => Html::new(context),
+        };
+
+        match (&decorations.title, &decorations.caption) {
+            (Some(title), Some(caption)) => {
+                html.append(self.render_inlines(title, context)?)?;
+                html.body.elements.push(HtmlElement {
+                    tag: HtmlTag::Br,
+                    attributes: HtmlAttributes::default(),
+                    content: None,
+                });
+                html.append(self.render_inlines(caption, context)?)?;
+            }
+            (Some(content), None) | (None, Some(content)) => {
+                html.append(self.render_inlines(content, context)?)?
+            }
+            (None, None) => return Ok(None),
         }
+
+        Ok(Some(html))
+    }
+
+    /// Wraps the given content inside a figure, if the element is decorated.
+    /// The title is placed before the content, and the caption after the content.
+    fn render_figure(
+        &mut self,
+        content: Html,
+        label: &str,
+        decorations: &Decorations,
+        context: &Context,
+    ) -> Result<Html, RenderError> {
+        let Some(figcaption) = self.render_decorations(label, decorations, context)? else {
+            return Ok(content);
+        };
+        let figcaption = Html::nested(HtmlTag::Figcaption, HtmlAttributes::default(), figcaption);
+
+        let figure = if decorations.title.is_some() {
+            let mut figure = figcaption;
+            figure.append(content)?;
+            figure
+        } else {
+            let mut figure = content;
+            figure.append(figcaption)?;
+            figure
+        };
+
+        Ok(Html::nested(
+            HtmlTag::Figure,
+            HtmlAttributes::default(),
+            figure,
+        ))
+    }
+
+    /// Renders the given table rows wrapped inside the given table section tag.
+    /// Nothing is rendered if no rows are given.
+    fn render_table_section(
+        &mut self,
+        tag: HtmlTag,
+        rows: &[TableRow],
+        context: &Context,
+    ) -> Result<Html, RenderError> {
+        let mut section = Html::new(context);
+
+        if rows.is_empty() {
+            return Ok(section);
+        }
+
+        for row in rows {
+            let mut cells = Html::new(context);
+
+            for cell in &row.cells {
+                cells.append(self.render_table_cell(cell, context)?)?;
+            }
+
+            section.append(Html::nested(HtmlTag::Tr, HtmlAttributes::default(), cells))?;
+        }
+
+        Ok(Html::nested(tag, HtmlAttributes::default(), section))
+    }
+
+    fn render_table_cell(
+        &mut self,
+        cell: &TableCell,
+        context: &Context,
+    ) -> Result<Html, RenderError> {
+        // A single paragraph is rendered without `<p>`, same as list entry headings
+        let content = match cell.content.as_slice() {
+            [Block::Paragraph(paragraph)] => self.render_inlines(&paragraph.content, context)?,
+            blocks => self.render_blocks(blocks, context)?,
+        };
+
+        let mut attributes = HtmlAttributes::default();
+
+        if cell.column_span > 1 {
+            attributes.push(HtmlAttribute {
+                name: "colspan".to_string(),
+                value: Some(cell.column_span.to_string()),
+            });
+        }
+
+        if cell.row_span > 1 {
+            attributes.push(HtmlAttribute {
+                name: "rowspan".to_string(),
+                value: Some(cell.row_span.to_string()),
+            });
+        }
+
+        if let Some(alignment) = cell.alignment {
+            attributes.push(HtmlAttribute {
+                name: "style".to_string(),
+                value: Some(format!("text-align: {};", alignment.as_str())),
+            });
+        }
+
+        let tag = match cell.kind {
+            TableCellKind::Header => HtmlTag::Th,
+            TableCellKind::Data | TableCellKind::Footer => HtmlTag::Td,
+        };
+
+        Ok(Html::nested(tag, attributes, content))
     }
 }
 
@@ -44,6 +268,46 @@ impl Renderer<Html> for HtmlRenderer {
         Ok(html)
     }
 
+    fn render_attributes(
+        &mut self,
+        mut rendered: Html,
+        attributes: &unimarkup_commons::attributes::Attributes,
+        _context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        // Attributes belong to the outermost element of the rendered block or inline
+        if let Some(element) = rendered
+            .body
+            .elements
+            .iter_mut()
+            .find(|element| element.tag != HtmlTag::PlainContent)
+        {
+            if let Some(id) = attributes.id() {
+                element.attributes.set("id", id.to_string());
+            }
+
+            if !attributes.classes().is_empty() {
+                element
+                    .attributes
+                    .extend("class", &attributes.classes().join(" "), " ");
+            }
+
+            if let Some(css) = attributes.css() {
+                element.attributes.extend("style", &css, "; ");
+            }
+
+            // Other attributes may configure elements (e.g. `display` of abbreviations),
+            // so only attributes that are valid for all HTML elements are set
+            for (name, value) in attributes
+                .others()
+                .filter(|(name, _)| is_global_html_attribute(name))
+            {
+                element.attributes.set(name, value.to_string());
+            }
+        }
+
+        Ok(rendered)
+    }
+
     fn render_paragraph(
         &mut self,
         paragraph: &unimarkup_parser::elements::atomic::Paragraph,
@@ -73,7 +337,7 @@ impl Renderer<Html> for HtmlRenderer {
     fn render_verbatim_block(
         &mut self,
         verbatim: &unimarkup_parser::elements::enclosed::VerbatimBlock,
-        _context: &Context,
+        context: &Context,
     ) -> Result<Html, crate::log_id::RenderError> {
         let inner = Html::with(
             HtmlHead {
@@ -96,7 +360,66 @@ impl Renderer<Html> for HtmlRenderer {
             }),
         );
 
-        Ok(Html::nested(HtmlTag::Pre, HtmlAttributes::default(), inner))
+        self.render_figure(
+            Html::nested(HtmlTag::Pre, HtmlAttributes::default(), inner),
+            LISTING_LABEL,
+            &verbatim.decorations,
+            context,
+        )
+    }
+
+    fn render_render_block(
+        &mut self,
+        render_block: &RenderBlock,
+        context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        if let Some(handler) = self.render_block_handlers.get(&render_block.lang) {
+            match handler.render(render_block, context) {
+                Ok(html) => return Ok(html),
+                Err(err) => {
+                    log!(
+                        GeneralWarning::RenderBlockFallback,
+                        format!(
+                            "Render block with language '{}' is shown as verbatim block: {}",
+                            render_block.lang, err
+                        )
+                    );
+                }
+            }
+        }
+
+        // Content is shown as is, if it cannot be rendered
+        self.render_verbatim_block(
+            &VerbatimBlock {
+                content: render_block.content.clone(),
+                data_lang: Some(render_block.lang.clone()),
+                attributes: None,
+                decorations: Default::default(),
+                implicit_closed: render_block.implicit_closed,
+                tick_len: render_block.quote_len,
+                start: render_block.start,
+                end: render_block.end,
+            },
+            context,
+        )
+    }
+
+    fn render_footnote_definition(
+        &mut self,
+        _footnote_definition: &FootnoteDefinition,
+        context: &Context,
+    ) -> Result<Html, RenderError> {
+        // Content is rendered at the footnote reference, or with the other footnotes
+        Ok(Html::new(context))
+    }
+
+    fn render_abbreviation_definition(
+        &mut self,
+        _abbreviation_definition: &AbbreviationDefinition,
+        context: &Context,
+    ) -> Result<Html, RenderError> {
+        // Expansion is rendered at the abbreviation reference, or in the glossary
+        Ok(Html::new(context))
     }
 
     fn render_bullet_list(
@@ -123,12 +446,145 @@ impl Renderer<Html> for HtmlRenderer {
         context: &Context,
     ) -> Result<Html, crate::log_id::RenderError> {
         let mut entry_heading = self.render_inlines(&bullet_list_entry.heading, context)?;
+        let mut attributes = HtmlAttributes::default();
+
+        if let Some(status) = bullet_list_entry.task_status() {
+            let mut checkbox_attributes = vec![
+                HtmlAttribute {
+                    name: "type".to_string(),
+                    value: Some("checkbox".to_string()),
+                },
+                HtmlAttribute {
+                    name: "disabled".to_string(),
+                    value: None,
+                },
+            ];
+            if status == TaskStatus::Completed {
+                checkbox_attributes.push(HtmlAttribute {
+                    name: "checked".to_string(),
+                    value: None,
+                });
+            }
+
+            entry_heading.body.elements.insert(
+                0,
+                HtmlElement {
+                    tag: HtmlTag::Input,
+                    attributes: checkbox_attributes.into(),
+                    content: None,
+                },
+            );
+
+            attributes = HtmlAttributes::from(vec![
+                HtmlAttribute {
+                    name: "class".to_string(),
+                    value: Some(format!("task task-{}", status.as_str())),
+                },
+                HtmlAttribute {
+                    name: "style".to_string(),
+                    value: Some("list-style-type: none".to_string()),
+                },
+            ]);
+        }
 
         if !bullet_list_entry.body.is_empty() {
             entry_heading = Html::nested(HtmlTag::P, HtmlAttributes::default(), entry_heading);
             entry_heading.append(self.render_blocks(&bullet_list_entry.body, context)?)?;
         }
 
+        Ok(Html::nested(HtmlTag::Li, attributes, entry_heading))
+    }
+
+    fn render_definition_list(
+        &mut self,
+        definition_list: &DefinitionList,
+        context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        let mut entries = Html::new(context);
+
+        for entry in &definition_list.entries {
+            let mut term = self.render_inlines(&entry.term, context)?;
+
+            if let Some(classifier) = &entry.classifier {
+                term.body.elements.push(HtmlElement {
+                    tag: HtmlTag::PlainContent,
+                    attributes: HtmlAttributes::default(),
+                    content: Some(" ".to_string()),
+                });
+                term.append(Html::nested(
+                    HtmlTag::Span,
+                    HtmlAttributes::from(vec![HtmlAttribute {
+                        name: "class".to_string(),
+                        value: Some("classifier".to_string()),
+                    }]),
+                    self.render_inlines(classifier, context)?,
+                ))?;
+            }
+
+            entries.append(Html::nested(HtmlTag::Dt, HtmlAttributes::default(), term))?;
+
+            if !entry.description.is_empty() {
+                entries.append(Html::nested(
+                    HtmlTag::Dd,
+                    HtmlAttributes::default(),
+                    self.render_blocks(&entry.description, context)?,
+                ))?;
+            }
+        }
+
+        Ok(Html::nested(
+            HtmlTag::Dl,
+            HtmlAttributes::default(),
+            entries,
+        ))
+    }
+
+    fn render_numbered_list(
+        &mut self,
+        numbered_list: &NumberedList,
+        context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        let mut entries = Html::new(context);
+
+        for entry in &numbered_list.entries {
+            entries.append(self.render_numbered_list_entry(entry, context)?)?;
+        }
+
+        let list_type = match numbered_list.kind {
+            EnumerationKind::Arabic => "1",
+            EnumerationKind::LowerLatin => "a",
+            EnumerationKind::UpperLatin => "A",
+            EnumerationKind::LowerRoman => "i",
+            EnumerationKind::UpperRoman => "I",
+        };
+
+        let mut attributes = HtmlAttributes::from(vec![HtmlAttribute {
+            name: "type".to_string(),
+            value: Some(list_type.to_string()),
+        }]);
+
+        if numbered_list.start_number != 1 {
+            attributes.push(HtmlAttribute {
+                name: "start".to_string(),
+                value: Some(numbered_list.start_number.to_string()),
+            });
+        }
+
+        Ok(Html::nested(HtmlTag::Ol, attributes, entries))
+    }
+
+    fn render_numbered_list_entry(
+        &mut self,
+        numbered_list_entry: &NumberedListEntry,
+        context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        let mut entry_heading = self.render_inlines(&numbered_list_entry.heading, context)?;
+
+        if !numbered_list_entry.body.is_empty() {
+            entry_heading = Html::nested(HtmlTag::P, HtmlAttributes::default(), entry_heading);
+            entry_heading.append(self.render_blocks(&numbered_list_entry.body, context)?)?;
+        }
+
         Ok(Html::nested(
             HtmlTag::Li,
             HtmlAttributes::default(),
@@ -136,6 +592,252 @@ impl Renderer<Html> for HtmlRenderer {
         ))
     }
 
+    fn render_table(
+        &mut self,
+        table: &Table,
+        context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        let mut sections =
+            match self.render_decorations(TABLE_LABEL, &table.decorations, context)? {
+                Some(caption) => Html::nested(HtmlTag::Caption, HtmlAttributes::default(), caption),
+                None => Html::new(context),
+            };
+        sections.append(self.render_table_section(HtmlTag::Thead, table.head(), context)?)?;
+        sections.append(self.render_table_section(HtmlTag::Tbody, table.body(), context)?)?;
+        sections.append(self.render_table_section(HtmlTag::Tfoot, table.foot(), context)?)?;
+
+        Ok(Html::nested(
+            HtmlTag::Table,
+            HtmlAttributes::default(),
+            sections,
+        ))
+    }
+
+    fn render_text_block(
+        &mut self,
+        text_block: &TextBlock,
+        context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        let content = self.render_blocks(&text_block.content, context)?;
+
+        Ok(Html::nested(
+            HtmlTag::Div,
+            HtmlAttributes::default(),
+            content,
+        ))
+    }
+
+    fn render_quotation(
+        &mut self,
+        quotation: &Quotation,
+        context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        let content = self.render_blocks(&quotation.content, context)?;
+        let blockquote = Html::nested(HtmlTag::Blockquote, HtmlAttributes::default(), content);
+
+        match &quotation.author {
+            Some(author) => {
+                let author = Html::nested(
+                    HtmlTag::Cite,
+                    HtmlAttributes::default(),
+                    self.render_inlines(author, context)?,
+                );
+                let mut figure = blockquote;
+                figure.append(Html::nested(
+                    HtmlTag::Figcaption,
+                    HtmlAttributes::default(),
+                    author,
+                ))?;
+
+                Ok(Html::nested(
+                    HtmlTag::Figure,
+                    HtmlAttributes::default(),
+                    figure,
+                ))
+            }
+            None => Ok(blockquote),
+        }
+    }
+
+    fn render_line_block(
+        &mut self,
+        line_block: &LineBlock,
+        context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        let content = self.render_blocks(&line_block.content, context)?;
+
+        Ok(Html::nested(
+            HtmlTag::Div,
+            HtmlAttributes(vec![HtmlAttribute {
+                name: "style".to_string(),
+                value: Some("white-space: pre-wrap;".to_string()),
+            }]),
+            content,
+        ))
+    }
+
+    fn render_math_block(
+        &mut self,
+        math_block: &MathBlock,
+        _context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        let math = mathemascii::render_mathml(mathemascii::parse(&math_block.content));
+
+        // mathemascii only renders inline math, so display mode is set on the root element
+        let mut attributes = String::from(" display=\"block\"");
+        if let Some(id) = math_block.id() {
+            let id = super::render_block::escape_content(id).replace('"', "&quot;");
+            attributes.push_str(&format!(" id=\"{id}\""));
+        }
+        let math = math.replacen("<math", &format!("<math{attributes}"), 1);
+
+        Ok(Html::with_body(HtmlBody {
+            elements: vec![HtmlElement {
+                tag: HtmlTag::PlainContent,
+                attributes: HtmlAttributes::default(),
+                content: Some(math),
+            }]
+            .into(),
+        }))
+    }
+
+    fn render_columns(
+        &mut self,
+        columns: &Columns,
+        context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        let mut html = Html::default();
+
+        for column in &columns.columns {
+            html.append(Html::nested(
+                HtmlTag::Div,
+                HtmlAttributes::default(),
+                self.render_blocks(column, context)?,
+            ))?;
+        }
+
+        // Grid instead of CSS multi-column, because content is already split into columns,
+        // and multi-column layouts are rebalanced by Paged.js when paginating.
+        Ok(Html::nested(
+            HtmlTag::Div,
+            HtmlAttributes(vec![HtmlAttribute {
+                name: "style".to_string(),
+                value: Some(format!(
+                    "display: grid; grid-template-columns: repeat({}, 1fr); column-gap: 1em;",
+                    columns.column_count()
+                )),
+            }]),
+            html,
+        ))
+    }
+
+    fn render_media_insert(
+        &mut self,
+        media_insert: &MediaInsert,
+        context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        let src = match (&media_insert.resolved_path, media_insert.kind) {
+            (Some(path), MediaKind::Image) if context.get_config().preamble.render.embed_media => {
+                embed_as_data_uri(path).unwrap_or_else(|| media_insert.source.clone())
+            }
+            _ => media_insert.source.clone(),
+        };
+        let src_attribute = HtmlAttribute {
+            name: "src".to_string(),
+            value: Some(src),
+        };
+
+        let media = match media_insert.kind {
+            MediaKind::Image => HtmlElement {
+                tag: HtmlTag::Img,
+                attributes: HtmlAttributes(vec![
+                    src_attribute,
+                    HtmlAttribute {
+                        name: "alt".to_string(),
+                        value: Some(media_insert.alt.clone()),
+                    },
+                ]),
+                content: None,
+            },
+            MediaKind::Video | MediaKind::Audio => HtmlElement {
+                tag: if media_insert.kind == MediaKind::Video {
+                    HtmlTag::Video
+                } else {
+                    HtmlTag::Audio
+                },
+                attributes: HtmlAttributes(vec![
+                    src_attribute,
+                    HtmlAttribute {
+                        name: "controls".to_string(),
+                        value: None,
+                    },
+                ]),
+                // Alternative text is shown if the browser does not support the media
+                content: Some(media_insert.alt.clone()),
+            },
+        };
+
+        let media = Html::with_body(HtmlBody::from(media));
+
+        if media_insert.decorations.is_empty() {
+            return Ok(Html::nested(
+                HtmlTag::Figure,
+                HtmlAttributes::default(),
+                media,
+            ));
+        }
+
+        self.render_figure(media, FIGURE_LABEL, &media_insert.decorations, context)
+    }
+
+    fn render_horizontal_line(
+        &mut self,
+        _horizontal_line: &HorizontalLine,
+        _context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        Ok(Html::with_body(HtmlBody::from(HtmlElement {
+            tag: HtmlTag::Hr,
+            attributes: HtmlAttributes::default(),
+            content: None,
+        })))
+    }
+
+    fn render_line_break(
+        &mut self,
+        _line_break: &LineBreak,
+        _context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        Ok(Html::with_body(HtmlBody::from(HtmlElement {
+            tag: HtmlTag::Br,
+            attributes: HtmlAttributes::default(),
+            content: None,
+        })))
+    }
+
+    fn render_page_break(
+        &mut self,
+        _page_break: &PageBreak,
+        _context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        // Paged.js and browser print dialogs both respect `break-after`
+        Ok(Html::with_body(HtmlBody::from(HtmlElement {
+            tag: HtmlTag::Div,
+            attributes: HtmlAttributes(vec![HtmlAttribute {
+                name: "style".to_string(),
+                value: Some("break-after: page;".to_string()),
+            }]),
+            content: Some(String::new()),
+        })))
+    }
+
+    fn render_comment_block(
+        &mut self,
+        comment: &atomic::Comment,
+        _context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        Ok(html_comment(&comment.content))
+    }
+
     fn render_blankline(
         &mut self,
         _blankline: &Span,
@@ -207,60 +909,223 @@ impl Renderer<Html> for HtmlRenderer {
         Ok(html)
     }
 
-    fn render_distinct_reference(
+    fn render_footnote_reference(
         &mut self,
-        distinct_reference: &DistinctReference,
+        footnote_reference: &FootnoteReference,
         context: &Context,
     ) -> Result<Html, RenderError> {
-        let mut selected_item: Option<Value> = None;
-        for csl_item in context.csl_data.clone().items {
-            if csl_item.id.to_string() == distinct_reference.id() {
-                selected_item = Some(serde_json::to_value(csl_item).unwrap());
-                break;
-            }
+        let Some((number, footnote)) = context.doc.footnote(footnote_reference.id()) else {
+            log!(
+                GeneralWarning::UnresolvedFootnote,
+                format!(
+                    "Footnote '{}' is referenced, but not defined.",
+                    footnote_reference.id()
+                )
+            );
+
+            return Ok(Html::with_body(HtmlBody::from(HtmlElement {
+                tag: HtmlTag::PlainContent,
+                attributes: HtmlAttributes::default(),
+                content: Some(super::render_block::escape_text(
+                    &footnote_reference.as_unimarkup(),
+                )),
+            })));
+        };
+        let first_reference = self.referenced_footnotes.insert(number);
+
+        if self.use_paged_js && first_reference {
+            // Paged.js places the content in the footnote area, and creates the footnote marker
+            let content = self.render_footnote_content(footnote, context)?;
+            return Ok(Html::nested(
+                HtmlTag::Span,
+                HtmlAttributes::from(vec![HtmlAttribute {
+                    name: "class".to_string(),
+                    value: Some("footnote".to_string()),
+                }]),
+                content,
+            ));
         }
-        let content;
-        if let Some(item_value) = selected_item {
-            let mut result_value = item_value.clone();
-            if distinct_reference.fields().len() == 1 && distinct_reference.fields()[0] == "authors"
-            {
-                let citeproc_wrapper = if cfg!(test) {
-                    CiteprocWrapper::new_with_path("./src/html/citeproc/js/citeproc_adapter.js")
-                } else {
-                    CiteprocWrapper::new()
-                };
-                content = match citeproc_wrapper {
-                    Ok(mut citeproc) => citeproc
-                        .get_author_only(context.doc, distinct_reference.id().to_string())
-                        .unwrap_or("########### CITATION ERROR ###########".to_string()),
-                    Err(_) => "########### CITATION ERROR ###########".to_string(),
+
+        let mut link_attributes = vec![HtmlAttribute {
+            name: "href".to_string(),
+            value: Some(format!("#fn-{number}")),
+        }];
+        if first_reference {
+            link_attributes.push(HtmlAttribute {
+                name: "id".to_string(),
+                value: Some(format!("fnref-{number}")),
+            });
+        }
+
+        Ok(Html::with_body(HtmlBody::from(HtmlElement {
+            tag: HtmlTag::Sup,
+            attributes: HtmlAttributes::default(),
+            content: Some(
+                HtmlElement {
+                    tag: HtmlTag::A,
+                    attributes: link_attributes.into(),
+                    content: Some(number.to_string()),
                 }
+                .to_string(),
+            ),
+        })))
+    }
+
+    fn render_abbreviation_reference(
+        &mut self,
+        abbreviation_reference: &AbbreviationReference,
+        context: &Context,
+    ) -> Result<Html, RenderError> {
+        let Some(definition) = context
+            .doc
+            .abbreviation(abbreviation_reference.abbreviation())
+        else {
+            log!(
+                GeneralWarning::UnresolvedAbbreviation,
+                format!(
+                    "Abbreviation '{}' is referenced, but not defined.",
+                    abbreviation_reference.abbreviation()
+                )
+            );
+
+            return Ok(Html::with_body(HtmlBody::from(HtmlElement {
+                tag: HtmlTag::PlainContent,
+                attributes: HtmlAttributes::default(),
+                content: Some(super::render_block::escape_text(
+                    abbreviation_reference.abbreviation(),
+                )),
+            })));
+        };
+
+        if abbreviation_reference.display() == AbbreviationDisplay::Replace {
+            return self.render_nested_inline(&definition.expansion, context);
+        }
+
+        Ok(Html::with_body(HtmlBody::from(HtmlElement {
+            tag: HtmlTag::Abbr,
+            attributes: HtmlAttributes::from(vec![HtmlAttribute {
+                name: "title".to_string(),
+                value: Some(plain_text(&definition.expansion)),
+            }]),
+            content: Some(super::render_block::escape_text(
+                abbreviation_reference.abbreviation(),
+            )),
+        })))
+    }
+
+    fn render_reference(
+        &mut self,
+        reference: &Reference,
+        context: &Context,
+    ) -> Result<Html, RenderError> {
+        let Some((target, text)) = context.resolve_reference(reference) else {
+            return Ok(Html::with_body(HtmlBody::from(HtmlElement {
+                tag: HtmlTag::PlainContent,
+                attributes: HtmlAttributes::default(),
+                content: Some(super::render_block::escape_text(&reference.as_unimarkup())),
+            })));
+        };
+
+        Ok(Html::with_body(HtmlBody::from(HtmlElement {
+            tag: HtmlTag::A,
+            attributes: HtmlAttributes::from(vec![HtmlAttribute {
+                name: "href".to_string(),
+                value: Some(format!("#{}", target.id)),
+            }]),
+            content: Some(text),
+        })))
+    }
+
+    fn render_named_substitution(
+        &mut self,
+        named_substitution: &NamedSubstitution,
+        _context: &Context,
+    ) -> Result<Html, RenderError> {
+        let content = match named_substitution.subst() {
+            Some(subst) => subst.to_string(),
+            None => {
+                let span = named_substitution.span();
+                log!(
+                    GeneralWarning::UnknownNamedSubstitution,
+                    format!(
+                        "Named substitution '{}' at {}:{}-{}:{} has an unknown alias.",
+                        named_substitution.alias(),
+                        span.start.line,
+                        span.start.col_utf8,
+                        span.end.line,
+                        span.end.col_utf8,
+                    )
+                );
+
+                named_substitution.as_unimarkup()
+            }
+        };
+
+        Ok(Html::with_body(HtmlBody::from(HtmlElement {
+            tag: HtmlTag::PlainContent,
+            attributes: HtmlAttributes::default(),
+            content: Some(content),
+        })))
+    }
+
+    fn render_direct_unicode(
+        &mut self,
+        direct_unicode: &DirectUnicode,
+        _context: &Context,
+    ) -> Result<Html, RenderError> {
+        Ok(Html::with_body(HtmlBody::from(HtmlElement {
+            tag: HtmlTag::PlainContent,
+            attributes: HtmlAttributes::default(),
+            // Characters like `&lt;` must stay escaped to not be interpreted as markup
+            content: Some(super::render_block::escape_content(
+                &direct_unicode.character().to_string(),
+            )),
+        })))
+    }
+
+    fn render_implicit_substitution(
+        &mut self,
+        implicit_substitution: &ImplicitSubstitution,
+        _context: &Context,
+    ) -> Result<Html, RenderError> {
+        Ok(Html::with_body(HtmlBody::from(HtmlElement {
+            tag: HtmlTag::PlainContent,
+            attributes: HtmlAttributes::default(),
+            content: Some(implicit_substitution.subst().to_string()),
+        })))
+    }
+
+    fn render_distinct_reference(
+        &mut self,
+        distinct_reference: &DistinctReference,
+        context: &Context,
+    ) -> Result<Html, RenderError> {
+        let is_authors =
+            distinct_reference.fields().len() == 1 && distinct_reference.fields()[0] == "authors";
+        let content = if is_authors
+            && context
+                .csl_data
+                .items
+                .iter()
+                .any(|item| item.id.to_string() == distinct_reference.id())
+        {
+            let citeproc_wrapper = if cfg!(test) {
+                CiteprocWrapper::new_with_path("./src/html/citeproc/js/citeproc_adapter.js")
             } else {
-                for field in distinct_reference.fields().clone() {
-                    result_value = match field.parse::<usize>() {
-                        Ok(n) => result_value[n].clone(),
-                        Err(_) => result_value[field].clone(),
-                    };
-                }
-                content = if let Some(s) = result_value.as_str() {
-                    s.to_string()
-                } else {
-                    let content_as_string = result_value.to_string();
-                    if content_as_string.ends_with(".0") {
-                        match content_as_string[..content_as_string.len() - 2].parse::<usize>() {
-                            Ok(n) => n.to_string(),
-                            Err(_) => content_as_string,
-                        }
-                    } else if content_as_string == "null" {
-                        "########### CITATION ERROR ###########".to_string()
-                    } else {
-                        content_as_string
-                    }
-                }
+                CiteprocWrapper::new()
+            };
+            match citeproc_wrapper {
+                Ok(mut citeproc) => citeproc
+                    .get_author_only(context.doc, distinct_reference.id().to_string())
+                    .unwrap_or("########### CITATION ERROR ###########".to_string()),
+                Err(_) => "########### CITATION ERROR ###########".to_string(),
             }
         } else {
-            content = "########### CITATION ERROR ###########".to_string()
-        }
+            context
+                .distinct_reference_value(distinct_reference)
+                .map(|value| super::render_block::escape_text(&value))
+                .unwrap_or("########### CITATION ERROR ###########".to_string())
+        };
         let html = Html::with_body(HtmlBody::from(HtmlElement {
             tag: HtmlTag::PlainContent,
             attributes: HtmlAttributes::default(),
@@ -302,26 +1167,100 @@ impl Renderer<Html> for HtmlRenderer {
     }
 
     fn render_footnotes(&mut self, context: &Context) -> Result<Html, RenderError> {
-        match &context.footnotes {
-            Some(footnotes) => {
-                let elements: Vec<HtmlElement> = vec![
-                    HtmlElement {
-                        tag: HtmlTag::PlainContent,
-                        attributes: HtmlAttributes::default(),
-                        content: Some("<hr style=\"width: 25%; margin-left: 0\">".to_string()),
-                    },
-                    HtmlElement {
-                        tag: HtmlTag::PlainContent,
-                        attributes: HtmlAttributes::default(),
-                        content: Some(footnotes.clone()),
-                    },
-                ];
-                let body = HtmlBody::from(elements);
-                let html = Html::with_body(body);
-                Ok(html)
+        // Footnotes are placed on their pages by Paged.js
+        let unimarkup_footnotes = if self.use_paged_js {
+            &[]
+        } else {
+            context.doc.footnotes.as_slice()
+        };
+
+        if context.footnotes.is_none() && unimarkup_footnotes.is_empty() {
+            return Ok(Html::default());
+        }
+
+        let mut html = Html::with_body(HtmlBody::from(HtmlElement {
+            tag: HtmlTag::PlainContent,
+            attributes: HtmlAttributes::default(),
+            content: Some("<hr style=\"width: 25%; margin-left: 0\">".to_string()),
+        }));
+
+        if let Some(footnotes) = &context.footnotes {
+            html.body.elements.push(HtmlElement {
+                tag: HtmlTag::PlainContent,
+                attributes: HtmlAttributes::default(),
+                content: Some(footnotes.clone()),
+            });
+        }
+
+        if !unimarkup_footnotes.is_empty() {
+            let mut entries = Html::new(context);
+
+            for (index, footnote) in unimarkup_footnotes.iter().enumerate() {
+                let number = index + 1;
+                let mut content = self.render_footnote_content(footnote, context)?;
+                content.body.elements.push(HtmlElement {
+                    tag: HtmlTag::A,
+                    attributes: HtmlAttributes::from(vec![HtmlAttribute {
+                        name: "href".to_string(),
+                        value: Some(format!("#fnref-{number}")),
+                    }]),
+                    content: Some("↩".to_string()),
+                });
+
+                entries.append(Html::nested(
+                    HtmlTag::Li,
+                    HtmlAttributes::from(vec![HtmlAttribute {
+                        name: "id".to_string(),
+                        value: Some(format!("fn-{number}")),
+                    }]),
+                    content,
+                ))?;
             }
-            None => Ok(Html::default()),
+
+            html.append(Html::nested(
+                HtmlTag::Ol,
+                HtmlAttributes::from(vec![HtmlAttribute {
+                    name: "class".to_string(),
+                    value: Some("footnotes".to_string()),
+                }]),
+                entries,
+            ))?;
+        }
+
+        Ok(html)
+    }
+
+    fn render_glossary(&mut self, context: &Context) -> Result<Html, RenderError> {
+        if !context.get_config().preamble.render.glossary || context.doc.abbreviations.is_empty() {
+            return Ok(Html::default());
+        }
+
+        let mut abbreviations: Vec<_> = context.doc.abbreviations.iter().collect();
+        abbreviations.sort_by_key(|abbr| abbr.abbreviation.to_lowercase());
+
+        let mut entries = Html::new(context);
+
+        for abbreviation in abbreviations {
+            entries.body.elements.push(HtmlElement {
+                tag: HtmlTag::Dt,
+                attributes: HtmlAttributes::default(),
+                content: Some(abbreviation.abbreviation.clone()),
+            });
+            entries.append(Html::nested(
+                HtmlTag::Dd,
+                HtmlAttributes::default(),
+                self.render_nested_inline(&abbreviation.expansion, context)?,
+            ))?;
         }
+
+        Ok(Html::nested(
+            HtmlTag::Dl,
+            HtmlAttributes::from(vec![HtmlAttribute {
+                name: "class".to_string(),
+                value: Some("glossary".to_string()),
+            }]),
+            entries,
+        ))
     }
 
     fn render_bold(
@@ -445,7 +1384,9 @@ impl Renderer<Html> for HtmlRenderer {
         let html = Html::with_body(HtmlBody::from(HtmlElement {
             tag: HtmlTag::Code,
             attributes: HtmlAttributes::default(),
-            content: Some(verbatim.inner().as_unimarkup()),
+            content: Some(super::render_block::escape_text(
+                &verbatim.inner().as_unimarkup(),
+            )),
         }));
 
         Ok(html)
@@ -478,6 +1419,14 @@ impl Renderer<Html> for HtmlRenderer {
         }))
     }
 
+    fn render_inline_comment(
+        &mut self,
+        comment: &Comment,
+        _context: &Context,
+    ) -> Result<Html, crate::log_id::RenderError> {
+        Ok(html_comment(comment.content()))
+    }
+
     fn render_plain(
         &mut self,
         plain: &Plain,
@@ -486,7 +1435,7 @@ impl Renderer<Html> for HtmlRenderer {
         let html = Html::with_body(HtmlBody::from(HtmlElement {
             tag: HtmlTag::PlainContent,
             attributes: HtmlAttributes::default(),
-            content: Some(plain.content().clone()),
+            content: Some(super::render_block::escape_text(plain.content())),
         }));
 
         Ok(html)
@@ -559,19 +1508,84 @@ impl Renderer<Html> for HtmlRenderer {
         let html = Html::with_body(HtmlBody::from(HtmlElement {
             tag: HtmlTag::PlainContent,
             attributes: HtmlAttributes::default(),
-            content: Some(escaped_plain.content().clone()),
+            content: Some(super::render_block::escape_text(escaped_plain.content())),
         }));
 
         Ok(html)
     }
 }
 
+/// Creates an HTML comment with the given content.
+///
+/// Double hyphens are split, because they are not allowed inside HTML comments.
+fn html_comment(content: &str) -> Html {
+    Html::with_body(HtmlBody::from(HtmlElement {
+        tag: HtmlTag::PlainContent,
+        attributes: HtmlAttributes::default(),
+        content: Some(format!("<!-- {} -->", content.trim().replace("--", "- -"))),
+    }))
+}
+
+/// Global HTML attributes that may be set for every element using Unimarkup attributes.
+/// Custom data attributes (e.g. `data-id`) and ARIA attributes are allowed as well.
+const GLOBAL_HTML_ATTRIBUTES: &[&str] = &["dir", "lang", "role", "tabindex", "title"];
+
+/// Returns `true` if the given attribute name is a global HTML attribute.
+fn is_global_html_attribute(name: &str) -> bool {
+    let custom = ["data-", "aria-"].iter().any(|prefix| {
+        name.strip_prefix(prefix).is_some_and(|suffix| {
+            !suffix.is_empty()
+                && suffix
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        })
+    });
+
+    custom || GLOBAL_HTML_ATTRIBUTES.contains(&name)
+}
+
+/// Reads the image at the given path, and returns it as base64 encoded data URI.
+///
+/// Returns `None` if the file could not be read, or the image format is not supported.
+fn embed_as_data_uri(path: &Path) -> Option<String> {
+    let extension = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let mime = match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "bmp" => "image/bmp",
+        "ico" => "image/x-icon",
+        _ => return None,
+    };
+
+    match std::fs::read(path) {
+        Ok(data) => Some(format!("data:{mime};base64,{}", STANDARD.encode(data))),
+        Err(_) => {
+            log!(
+                GeneralWarning::FileRead,
+                format!("Could not read image to embed it: '{:?}'", path),
+            );
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::html::citeproc::get_csl_data;
-    use crate::html::render::HtmlRenderer;
-    use crate::render::{Context, Renderer};
+    use crate::html::render::{embed_as_data_uri, HtmlRenderer};
+    use crate::html::{tag::HtmlTag, Html, HtmlElement};
+    use crate::log_id::RenderError;
+    use crate::render::{Context, OutputFormat, Renderer};
+    use crate::render_block::RenderBlockHandler;
     use std::collections::{HashMap, HashSet};
+    use std::io::Write;
     use std::path::PathBuf;
     use std::str::FromStr;
     use unimarkup_commons::config::icu_locid::locale;
@@ -579,6 +1593,7 @@ mod tests {
     use unimarkup_commons::config::Config;
     use unimarkup_inline::element::substitution::DistinctReference;
     use unimarkup_parser::document::Document;
+    use unimarkup_parser::elements::enclosed::RenderBlock;
 
     fn get_test_context(doc: &Document) -> Context {
         let mut citation_paths: HashSet<PathBuf> = HashSet::new();
@@ -634,6 +1649,9 @@ mod tests {
             variables: vec![],
             metadata: vec![],
             resources: vec![],
+            footnotes: vec![],
+            abbreviations: vec![],
+            references: vec![],
         };
         let context = get_test_context(&doc);
         let distinct_reference = DistinctReference::new(
@@ -642,10 +1660,7 @@ mod tests {
             Default::default(),
             Default::default(),
         );
-        let mut under_test = HtmlRenderer {
-            use_paged_js: false,
-            citation_index: 0,
-        };
+        let mut under_test = HtmlRenderer::new(false);
         let actual = under_test.render_distinct_reference(&distinct_reference, &context);
 
         assert!(actual.is_ok());
@@ -666,6 +1681,9 @@ mod tests {
             variables: vec![],
             metadata: vec![],
             resources: vec![],
+            footnotes: vec![],
+            abbreviations: vec![],
+            references: vec![],
         };
         let context = get_test_context(&doc);
         let distinct_reference = DistinctReference::new(
@@ -674,10 +1692,7 @@ mod tests {
             Default::default(),
             Default::default(),
         );
-        let mut under_test = HtmlRenderer {
-            use_paged_js: false,
-            citation_index: 0,
-        };
+        let mut under_test = HtmlRenderer::new(false);
         let actual = under_test.render_distinct_reference(&distinct_reference, &context);
 
         assert!(actual.is_ok());
@@ -698,6 +1713,9 @@ mod tests {
             variables: vec![],
             metadata: vec![],
             resources: vec![],
+            footnotes: vec![],
+            abbreviations: vec![],
+            references: vec![],
         };
         let context = get_test_context(&doc);
         let distinct_reference = DistinctReference::new(
@@ -706,10 +1724,7 @@ mod tests {
             Default::default(),
             Default::default(),
         );
-        let mut under_test = HtmlRenderer {
-            use_paged_js: false,
-            citation_index: 0,
-        };
+        let mut under_test = HtmlRenderer::new(false);
         let actual = under_test.render_distinct_reference(&distinct_reference, &context);
 
         assert!(actual.is_ok());
@@ -730,6 +1745,9 @@ mod tests {
             variables: vec![],
             metadata: vec![],
             resources: vec![],
+            footnotes: vec![],
+            abbreviations: vec![],
+            references: vec![],
         };
         let context = get_test_context(&doc);
         let distinct_reference = DistinctReference::new(
@@ -738,10 +1756,7 @@ mod tests {
             Default::default(),
             Default::default(),
         );
-        let mut under_test = HtmlRenderer {
-            use_paged_js: false,
-            citation_index: 0,
-        };
+        let mut under_test = HtmlRenderer::new(false);
         let actual = under_test.render_distinct_reference(&distinct_reference, &context);
 
         assert!(actual.is_ok());
@@ -762,6 +1777,9 @@ mod tests {
             variables: vec![],
             metadata: vec![],
             resources: vec![],
+            footnotes: vec![],
+            abbreviations: vec![],
+            references: vec![],
         };
         let context = get_test_context(&doc);
         let distinct_reference = DistinctReference::new(
@@ -774,10 +1792,7 @@ mod tests {
             Default::default(),
             Default::default(),
         );
-        let mut under_test = HtmlRenderer {
-            use_paged_js: false,
-            citation_index: 0,
-        };
+        let mut under_test = HtmlRenderer::new(false);
         let actual = under_test.render_distinct_reference(&distinct_reference, &context);
 
         assert!(actual.is_ok());
@@ -798,6 +1813,9 @@ mod tests {
             variables: vec![],
             metadata: vec![],
             resources: vec![],
+            footnotes: vec![],
+            abbreviations: vec![],
+            references: vec![],
         };
         let context = get_test_context(&doc);
         let distinct_reference = DistinctReference::new(
@@ -811,10 +1829,7 @@ mod tests {
             Default::default(),
             Default::default(),
         );
-        let mut under_test = HtmlRenderer {
-            use_paged_js: false,
-            citation_index: 0,
-        };
+        let mut under_test = HtmlRenderer::new(false);
         let actual = under_test.render_distinct_reference(&distinct_reference, &context);
 
         assert!(actual.is_ok());
@@ -824,4 +1839,68 @@ mod tests {
         let content_unwrapped = content_option.unwrap();
         assert_eq!(content_unwrapped, "1997");
     }
+
+    #[test]
+    fn test_embed_image_as_data_uri() {
+        let mut image = tempfile::Builder::new().suffix(".png").tempfile().unwrap();
+        image.write_all(b"png").unwrap();
+
+        let actual = embed_as_data_uri(image.path());
+
+        assert_eq!(actual, Some("data:image/png;base64,cG5n".to_string()));
+    }
+
+    #[test]
+    fn test_embed_missing_image_falls_back() {
+        let actual = embed_as_data_uri(&PathBuf::from("./does/not/exist.png"));
+
+        assert!(actual.is_none());
+    }
+
+    #[derive(Debug)]
+    struct UppercaseHandler;
+
+    impl RenderBlockHandler<Html> for UppercaseHandler {
+        fn handles(&self, lang: &str) -> bool {
+            lang == "mermaid"
+        }
+
+        fn render(
+            &self,
+            render_block: &RenderBlock,
+            context: &Context,
+        ) -> Result<Html, RenderError> {
+            let mut html = Html::new(context);
+            html.body.elements.push(HtmlElement {
+                tag: HtmlTag::PlainContent,
+                attributes: Default::default(),
+                content: Some(render_block.content.to_uppercase()),
+            });
+            Ok(html)
+        }
+    }
+
+    #[test]
+    fn test_registered_render_block_handler_takes_precedence() {
+        let doc = Document::default();
+        let context = get_test_context(&doc);
+        let render_block = RenderBlock {
+            content: "graph TD".to_string(),
+            lang: "mermaid".to_string(),
+            implicit_closed: false,
+            attributes: None,
+            quote_len: 3,
+            start: Default::default(),
+            end: Default::default(),
+        };
+        let mut under_test = HtmlRenderer::new(false);
+        under_test.register_render_block_handler(UppercaseHandler);
+
+        let actual = under_test
+            .render_render_block(&render_block, &context)
+            .unwrap();
+
+        assert_eq!(actual.body.elements.to_string(), "GRAPH TD");
+        assert!(!actual.head.mermaid_used);
+    }
 }
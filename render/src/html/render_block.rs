@@ -0,0 +1,108 @@
+//! Contains the [`RenderBlockHandler`]s that are registered per default for the [`HtmlRenderer`](super::render::HtmlRenderer).
+
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use unimarkup_parser::elements::enclosed::RenderBlock;
+
+use crate::{log_id::RenderError, render::Context, render_block::RenderBlockHandler};
+
+use super::{tag::HtmlTag, Html, HtmlAttribute, HtmlBody, HtmlElement, HtmlHead};
+
+/// Script that renders all `<pre class="mermaid">` elements once the page is loaded.
+pub(crate) const MERMAID_SCRIPT: &str = "import mermaid from 'https://cdn.jsdelivr.net/npm/mermaid@10/dist/mermaid.esm.min.mjs'; mermaid.initialize({ startOnLoad: true });";
+
+/// Renders `mermaid` diagrams in the browser using the mermaid script.
+#[derive(Debug, Default)]
+pub struct MermaidHandler;
+
+impl RenderBlockHandler<Html> for MermaidHandler {
+    fn handles(&self, lang: &str) -> bool {
+        lang == "mermaid"
+    }
+
+    fn render(&self, render_block: &RenderBlock, _context: &Context) -> Result<Html, RenderError> {
+        Ok(Html::with(
+            HtmlHead {
+                mermaid_used: true,
+                ..Default::default()
+            },
+            HtmlBody::from(HtmlElement {
+                tag: HtmlTag::Pre,
+                attributes: vec![HtmlAttribute {
+                    name: "class".to_string(),
+                    value: Some("mermaid".to_string()),
+                }]
+                .into(),
+                content: Some(escape_content(&render_block.content)),
+            }),
+        ))
+    }
+}
+
+/// Renders graphviz graphs to SVG using the `dot` binary that must be available in `PATH`.
+#[derive(Debug, Default)]
+pub struct DotHandler;
+
+impl RenderBlockHandler<Html> for DotHandler {
+    fn handles(&self, lang: &str) -> bool {
+        matches!(lang, "dot" | "graphviz")
+    }
+
+    fn render(&self, render_block: &RenderBlock, _context: &Context) -> Result<Html, RenderError> {
+        let mut dot = Command::new("dot")
+            .arg("-Tsvg")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| RenderError::RenderBlockFailed(format!("Could not run `dot`: {err}")))?;
+
+        if let Some(mut stdin) = dot.stdin.take() {
+            stdin
+                .write_all(render_block.content.as_bytes())
+                .map_err(|err| RenderError::RenderBlockFailed(err.to_string()))?;
+        }
+
+        let output = dot
+            .wait_with_output()
+            .map_err(|err| RenderError::RenderBlockFailed(err.to_string()))?;
+
+        if !output.status.success() {
+            return Err(RenderError::RenderBlockFailed(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+
+        let svg = String::from_utf8_lossy(&output.stdout);
+        // Skip XML declaration and doctype, because the SVG is embedded in HTML
+        let svg = svg.find("<svg").map_or(svg.as_ref(), |start| &svg[start..]);
+
+        Ok(Html::with_body(HtmlBody::from(HtmlElement {
+            tag: HtmlTag::Div,
+            attributes: vec![HtmlAttribute {
+                name: "class".to_string(),
+                value: Some("graphviz".to_string()),
+            }]
+            .into(),
+            content: Some(svg.trim_end().to_string()),
+        })))
+    }
+}
+
+/// Escapes characters that would otherwise be interpreted as HTML markup.
+pub(crate) fn escape_content(content: &str) -> String {
+    content
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Escapes `&` and `<` in inline text, so the text can neither start an entity nor an HTML element.
+///
+/// **Note:** `>` is kept, because it cannot start markup on its own.
+pub(crate) fn escape_text(content: &str) -> String {
+    content.replace('&', "&amp;").replace('<', "&lt;")
+}
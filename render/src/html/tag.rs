@@ -22,15 +22,38 @@ pub enum HtmlTag {
     Strong,
     Em,
     Span,
+    Div,
     Sub,
     Sup,
     Mark,
     Q,
     Br,
+    Hr,
     Ul,
+    Ol,
     Li,
+    Table,
+    Thead,
+    Tbody,
+    Tfoot,
+    Caption,
+    Tr,
+    Th,
+    Td,
+    Blockquote,
+    Figure,
+    Figcaption,
+    Cite,
     A,
     Script,
+    Img,
+    Video,
+    Audio,
+    Abbr,
+    Dl,
+    Dt,
+    Dd,
+    Input,
 }
 
 impl HtmlTag {
@@ -52,15 +75,38 @@ impl HtmlTag {
             HtmlTag::Strong => "strong",
             HtmlTag::Em => "em",
             HtmlTag::Span => "span",
+            HtmlTag::Div => "div",
             HtmlTag::Sub => "sub",
             HtmlTag::Sup => "sup",
             HtmlTag::Mark => "mark",
             HtmlTag::Q => "q",
             HtmlTag::Br => "br",
+            HtmlTag::Hr => "hr",
             HtmlTag::Ul => "ul",
+            HtmlTag::Ol => "ol",
             HtmlTag::Li => "li",
+            HtmlTag::Table => "table",
+            HtmlTag::Thead => "thead",
+            HtmlTag::Tbody => "tbody",
+            HtmlTag::Tfoot => "tfoot",
+            HtmlTag::Caption => "caption",
+            HtmlTag::Tr => "tr",
+            HtmlTag::Th => "th",
+            HtmlTag::Td => "td",
+            HtmlTag::Blockquote => "blockquote",
+            HtmlTag::Figure => "figure",
+            HtmlTag::Figcaption => "figcaption",
+            HtmlTag::Cite => "cite",
             HtmlTag::A => "a",
             HtmlTag::Script => "script",
+            HtmlTag::Img => "img",
+            HtmlTag::Video => "video",
+            HtmlTag::Audio => "audio",
+            HtmlTag::Abbr => "abbr",
+            HtmlTag::Dl => "dl",
+            HtmlTag::Dt => "dt",
+            HtmlTag::Dd => "dd",
+            HtmlTag::Input => "input",
         }
     }
 }
@@ -0,0 +1,249 @@
+//! Defines the [`Latex`] struct that is returned when rendering Unimarkup to LaTeX.
+//!
+//! The rendered LaTeX is a complete `article` document.
+//! Packages are only loaded if the rendered content needs them.
+
+use std::collections::BTreeSet;
+
+use crate::render::{Context, OutputFormat};
+
+pub mod render;
+
+/// A LaTeX package that must be loaded in the preamble of the document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LatexPackage {
+    pub name: &'static str,
+    pub options: Option<&'static str>,
+}
+
+impl LatexPackage {
+    /// Creates a package that is loaded without options.
+    pub const fn new(name: &'static str) -> Self {
+        LatexPackage {
+            name,
+            options: None,
+        }
+    }
+
+    /// Creates a package that is loaded with the given options.
+    pub const fn with_options(name: &'static str, options: &'static str) -> Self {
+        LatexPackage {
+            name,
+            options: Some(options),
+        }
+    }
+}
+
+impl std::fmt::Display for LatexPackage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.options {
+            Some(options) => write!(f, "\\usepackage[{options}]{{{}}}", self.name),
+            None => write!(f, "\\usepackage{{{}}}", self.name),
+        }
+    }
+}
+
+/// Contains everything that is set in the preamble of a LaTeX document.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LatexHead {
+    /// The escaped title of the document.
+    pub title: Option<String>,
+    /// The escaped authors of the document.
+    pub authors: Vec<String>,
+    /// The language option for `babel`.
+    pub babel_lang: Option<&'static str>,
+    /// Packages needed by the rendered content.
+    pub packages: BTreeSet<LatexPackage>,
+    /// Bibliography files that are added as resources for `biblatex`.
+    pub bib_resources: Vec<String>,
+}
+
+impl LatexHead {
+    fn merge(&mut self, other: Self) {
+        self.title = self.title.take().or(other.title);
+        self.authors.extend(other.authors);
+        self.babel_lang = self.babel_lang.or(other.babel_lang);
+        self.packages.extend(other.packages);
+        self.bib_resources.extend(other.bib_resources);
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Latex {
+    pub head: LatexHead,
+    pub body: String,
+}
+
+impl Latex {
+    /// Creates LaTeX with the given body.
+    pub fn with_body(body: impl Into<String>) -> Self {
+        Latex {
+            body: body.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates LaTeX with the given body that needs the given packages.
+    pub fn with_packages(body: impl Into<String>, packages: &[LatexPackage]) -> Self {
+        let mut latex = Latex::with_body(body);
+        latex.head.packages.extend(packages);
+        latex
+    }
+
+    /// Creates a LaTeX block with the given body.
+    /// The body is terminated with a blank line to separate it from following blocks.
+    pub fn block(body: impl AsRef<str>) -> Self {
+        let body = body.as_ref().trim_end_matches('\n');
+
+        if body.is_empty() {
+            return Latex::default();
+        }
+
+        Latex::with_body(format!("{body}\n\n"))
+    }
+
+    /// Wraps the body of the given LaTeX in the given command (e.g. `\textbf{...}`).
+    pub fn command(command: &str, mut inner: Self) -> Self {
+        inner.body = format!("\\{command}{{{}}}", inner.body);
+        inner
+    }
+
+    /// Wraps the body of the given LaTeX in the given environment.
+    /// `args` are set directly after `\begin{<environment>}` (e.g. `{2}` or `[start=3]`).
+    pub fn environment(environment: &str, args: &str, mut inner: Self) -> Self {
+        inner.body = format!(
+            "\\begin{{{environment}}}{args}\n{}\n\\end{{{environment}}}\n\n",
+            inner.body.trim_end()
+        );
+        inner
+    }
+}
+
+impl OutputFormat for Latex {
+    fn new(context: &Context) -> Self {
+        let metadata = &context.get_config().preamble.metadata;
+
+        let mut authors: Vec<_> = metadata.authors.iter().map(|a| escape(a)).collect();
+        authors.sort();
+
+        let mut bib_resources: Vec<_> = context
+            .get_config()
+            .preamble
+            .cite
+            .references
+            .iter()
+            .map(|reference| {
+                // biblatex cannot read CSL-JSON, so a BibTeX file with the same name is expected
+                reference
+                    .with_extension("bib")
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+        bib_resources.sort();
+
+        Latex {
+            head: LatexHead {
+                title: metadata.title.as_deref().map(escape),
+                authors,
+                babel_lang: babel_lang(context.get_lang().id.language.as_str()),
+                packages: BTreeSet::new(),
+                bib_resources,
+            },
+            body: String::new(),
+        }
+    }
+
+    fn append(&mut self, other: Self) -> Result<(), crate::log_id::RenderError> {
+        self.head.merge(other.head);
+        self.body.push_str(&other.body);
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Latex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const HYPERREF: LatexPackage = LatexPackage::new("hyperref");
+        const BIBLATEX: &str = "biblatex";
+
+        writeln!(f, "\\documentclass{{article}}")?;
+        writeln!(f, "{}", LatexPackage::with_options("fontenc", "T1"))?;
+
+        if let Some(lang) = self.head.babel_lang {
+            writeln!(f, "{}", LatexPackage::with_options("babel", lang))?;
+        }
+
+        for package in self.head.packages.iter().filter(|p| **p != HYPERREF) {
+            writeln!(f, "{package}")?;
+        }
+
+        // hyperref must be loaded after other packages
+        writeln!(f, "{HYPERREF}")?;
+
+        if self.head.packages.iter().any(|p| p.name == BIBLATEX) {
+            for resource in &self.head.bib_resources {
+                writeln!(f, "\\addbibresource{{{resource}}}")?;
+            }
+        }
+
+        if let Some(title) = &self.head.title {
+            writeln!(f)?;
+            writeln!(f, "\\title{{{title}}}")?;
+            writeln!(f, "\\author{{{}}}", self.head.authors.join(" \\and "))?;
+            writeln!(f, "\\date{{}}")?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "\\begin{{document}}")?;
+        writeln!(f)?;
+
+        if self.head.title.is_some() {
+            writeln!(f, "\\maketitle")?;
+            writeln!(f)?;
+        }
+
+        let body = self.body.trim_end_matches('\n');
+        if !body.is_empty() {
+            writeln!(f, "{body}")?;
+            writeln!(f)?;
+        }
+
+        writeln!(f, "\\end{{document}}")
+    }
+}
+
+/// Escapes characters in the given content that have a special meaning in LaTeX.
+pub fn escape(content: &str) -> String {
+    let mut escaped = String::with_capacity(content.len());
+
+    for c in content.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Returns the `babel` option for the given language subtag,
+/// or `None` if the language is not known.
+fn babel_lang(language: &str) -> Option<&'static str> {
+    match language {
+        "en" => Some("english"),
+        "de" => Some("ngerman"),
+        "fr" => Some("french"),
+        "es" => Some("spanish"),
+        "it" => Some("italian"),
+        "pt" => Some("portuguese"),
+        "nl" => Some("dutch"),
+        _ => None,
+    }
+}
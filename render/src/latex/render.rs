@@ -0,0 +1,1210 @@
+use std::collections::HashSet;
+
+use logid::log;
+use unimarkup_commons::attributes::Attributes;
+use unimarkup_commons::lexer::span::Span;
+use unimarkup_inline::element::substitution::{
+    direct::DirectUnicode, named::NamedSubstitution, DistinctReference, ImplicitSubstitution,
+};
+use unimarkup_inline::element::{
+    base::{Comment, EscapedNewline, EscapedPlain, EscapedWhitespace, Newline, Plain},
+    formatting::{
+        Bold, Highlight, Italic, Math, Overline, Quote, Strikethrough, Subscript, Superscript,
+        Underline, Verbatim,
+    },
+    textbox::{
+        abbreviation::{AbbreviationDisplay, AbbreviationReference},
+        citation::Citation,
+        footnote::FootnoteReference,
+        hyperlink::Hyperlink,
+        reference::Reference,
+        TextBox,
+    },
+    InlineElement,
+};
+use unimarkup_parser::elements::{
+    atomic::{self, Heading, HeadingLevel, HorizontalLine, LineBreak, PageBreak, Paragraph},
+    blocks::Block,
+    decorations::Decorations,
+    definitions::{AbbreviationDefinition, FootnoteDefinition},
+    enclosed::{
+        ColumnAlignment, Columns, MathBlock, RenderBlock, Table, TableCellKind, TableRow,
+        TextBlock, VerbatimBlock,
+    },
+    indents::{
+        BulletList, BulletListEntry, DefinitionList, EnumerationKind, LineBlock, NumberedList,
+        NumberedListEntry, Quotation, TaskStatus,
+    },
+    inserts::{MediaInsert, MediaKind},
+};
+
+use crate::log_id::{GeneralWarning, RenderError};
+use crate::render::{Context, OutputFormat, Renderer};
+
+use super::{escape, Latex, LatexPackage};
+
+const AMSSYMB: LatexPackage = LatexPackage::new("amssymb");
+const BIBLATEX: LatexPackage = LatexPackage::new("biblatex");
+const BOOKTABS: LatexPackage = LatexPackage::new("booktabs");
+const CSQUOTES: LatexPackage = LatexPackage::new("csquotes");
+const ENUMITEM: LatexPackage = LatexPackage::new("enumitem");
+const GRAPHICX: LatexPackage = LatexPackage::new("graphicx");
+const HYPERREF: LatexPackage = LatexPackage::new("hyperref");
+const LISTINGS: LatexPackage = LatexPackage::new("listings");
+const MINTED: LatexPackage = LatexPackage::new("minted");
+const MULTICOL: LatexPackage = LatexPackage::new("multicol");
+const MULTIROW: LatexPackage = LatexPackage::new("multirow");
+const ULEM: LatexPackage = LatexPackage::with_options("ulem", "normalem");
+const XCOLOR: LatexPackage = LatexPackage::new("xcolor");
+
+#[derive(Debug, Default)]
+pub struct LatexRenderer {
+    /// Numbers of footnotes whose content was already rendered.
+    rendered_footnotes: HashSet<usize>,
+}
+
+impl LatexRenderer {
+    /// Renders the given blocks, and removes trailing blank lines.
+    fn render_trimmed(
+        &mut self,
+        blocks: &[Block],
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let mut latex = self.render_blocks(blocks, context)?;
+        latex.body.truncate(latex.body.trim_end().len());
+        Ok(latex)
+    }
+
+    /// Renders title and caption of the given decorations as one caption text.
+    ///
+    /// Returns `None` if the element is not decorated.
+    fn render_caption(
+        &mut self,
+        decorations: &Decorations,
+        context: &Context,
+    ) -> Result<Option<Latex>, RenderError> {
+        let caption = match (&decorations.title, &decorations.caption) {
+            (Some(title), Some(caption)) => {
+                let mut latex = self.render_inlines(title, context)?;
+                latex.body.push_str(" --- ");
+                latex.append(self.render_inlines(caption, context)?)?;
+                latex
+            }
+            (Some(content), None) | (None, Some(content)) => {
+                self.render_inlines(content, context)?
+            }
+            (None, None) => return Ok(None),
+        };
+
+        Ok(Some(caption))
+    }
+
+    /// Wraps decorated content inside a float environment (e.g. `table`) with a caption.
+    /// The caption is placed before the content if a title is set, and after the content otherwise.
+    ///
+    /// Undecorated content is returned unchanged, because only decorated elements are numbered.
+    fn render_float(
+        &mut self,
+        environment: &str,
+        content: Latex,
+        decorations: &Decorations,
+        attributes: Option<&Attributes>,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let Some(mut caption) = self.render_caption(decorations, context)? else {
+            return Ok(content);
+        };
+
+        caption.body = format!("\\caption{{{}}}", caption.body);
+        if let Some(id) = attributes.and_then(|attributes| attributes.id()) {
+            caption
+                .body
+                .push_str(&format!("\\label{{{}}}", label_key(id)));
+        }
+
+        let mut latex = Latex::with_body("\\centering\n");
+        if decorations.title.is_some() {
+            caption.body.push('\n');
+            latex.append(caption)?;
+            latex.append(content)?;
+        } else {
+            latex.append(content)?;
+            latex.body = format!("{}\n", latex.body.trim_end());
+            latex.append(caption)?;
+        }
+
+        Ok(Latex::environment(environment, "[htbp]", latex))
+    }
+
+    /// Renders the content of the given footnote.
+    fn render_footnote_content(
+        &mut self,
+        footnote: &FootnoteDefinition,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        self.render_trimmed(&footnote.content, context)
+    }
+
+    /// Renders all cells of the given row separated by `&`.
+    ///
+    /// `pending_rows` contains the number of rows that are still covered by cells of previous rows per column.
+    /// Empty cells are added for covered columns.
+    fn render_table_row(
+        &mut self,
+        row: &TableRow,
+        alignments: &[char],
+        pending_rows: &mut [usize],
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let mut latex = Latex::default();
+        let mut cells = row.cells.iter();
+        let mut column = 0;
+        let mut rendered_cells = Vec::new();
+
+        while column < pending_rows.len() {
+            if pending_rows[column] > 0 {
+                pending_rows[column] -= 1;
+                rendered_cells.push(String::new());
+                column += 1;
+                continue;
+            }
+
+            let Some(cell) = cells.next() else {
+                break;
+            };
+
+            let mut content = Latex::default();
+            for block in &cell.content {
+                let rendered = match block {
+                    Block::Paragraph(paragraph) => {
+                        self.render_inlines(&paragraph.content, context)?
+                    }
+                    block => self.render_block(block, context)?,
+                };
+
+                if !content.body.is_empty() {
+                    content.body.push(' ');
+                }
+                content.body.push_str(rendered.body.trim());
+                content.head.merge(rendered.head);
+            }
+
+            if cell.kind == TableCellKind::Header {
+                content = Latex::command("textbf", content);
+            }
+
+            if cell.row_span > 1 {
+                content.body = format!("\\multirow{{{}}}{{*}}{{{}}}", cell.row_span, content.body);
+                content.head.packages.insert(MULTIROW);
+
+                for pending in pending_rows.iter_mut().skip(column).take(cell.column_span) {
+                    *pending = cell.row_span - 1;
+                }
+            }
+
+            // Cells that span multiple columns or differ from the column alignment need their own alignment
+            let column_alignment = alignments.get(column).copied().unwrap_or('l');
+            let alignment = cell
+                .alignment
+                .map(alignment_char)
+                .unwrap_or(column_alignment);
+
+            if cell.column_span > 1 || alignment != column_alignment {
+                content.body = format!(
+                    "\\multicolumn{{{}}}{{{alignment}}}{{{}}}",
+                    cell.column_span, content.body
+                );
+            }
+
+            rendered_cells.push(content.body);
+            latex.head.merge(content.head);
+            column += cell.column_span.max(1);
+        }
+
+        latex.body = format!("{} \\\\\n", rendered_cells.join(" & "));
+        Ok(latex)
+    }
+}
+
+impl Renderer<Latex> for LatexRenderer {
+    fn get_target(&mut self) -> Result<Latex, RenderError> {
+        Ok(Latex::default())
+    }
+
+    fn render_paragraph(
+        &mut self,
+        paragraph: &Paragraph,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let mut latex = self.render_inlines(&paragraph.content, context)?;
+        latex.body = format!("{}\n\n", latex.body.trim_end());
+        Ok(latex)
+    }
+
+    fn render_heading(
+        &mut self,
+        heading: &Heading,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let command = match heading.level {
+            HeadingLevel::Level1 => "section",
+            HeadingLevel::Level2 => "subsection",
+            HeadingLevel::Level3 => "subsubsection",
+            HeadingLevel::Level4 => "paragraph",
+            HeadingLevel::Level5 | HeadingLevel::Level6 => "subparagraph",
+        };
+
+        // IDs set in attributes take precedence for cross-references
+        let id = heading
+            .attributes
+            .as_ref()
+            .and_then(|attributes| attributes.id())
+            .unwrap_or(&heading.id);
+
+        let mut latex = Latex::command(command, self.render_inlines(&heading.content, context)?);
+        latex.body = format!(
+            "{}\\label{{{}}}\n\n",
+            latex.body.replace("\\\\\n", " "),
+            label_key(id)
+        );
+        Ok(latex)
+    }
+
+    fn render_verbatim_block(
+        &mut self,
+        verbatim: &VerbatimBlock,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        match &verbatim.data_lang {
+            Some(lang) => {
+                let content = Latex::with_packages(
+                    format!(
+                        "\\begin{{minted}}{{{}}}\n{}\n\\end{{minted}}\n\n",
+                        lang.to_lowercase(),
+                        verbatim.content
+                    ),
+                    &[MINTED],
+                );
+
+                // minted provides the `listing` float for captions
+                self.render_float(
+                    "listing",
+                    content,
+                    &verbatim.decorations,
+                    verbatim.attributes.as_ref(),
+                    context,
+                )
+            }
+            None => {
+                // listings sets captions as option, because it has no float environment
+                let options = match self.render_caption(&verbatim.decorations, context)? {
+                    Some(caption) => format!("[caption={{{}}}]", caption.body),
+                    None => String::new(),
+                };
+
+                Ok(Latex::with_packages(
+                    format!(
+                        "\\begin{{lstlisting}}{options}\n{}\n\\end{{lstlisting}}\n\n",
+                        verbatim.content
+                    ),
+                    &[LISTINGS],
+                ))
+            }
+        }
+    }
+
+    fn render_render_block(
+        &mut self,
+        render_block: &RenderBlock,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        // Render blocks (e.g. mermaid diagrams) cannot be rendered by LaTeX, so the source is shown
+        Ok(Latex::block(format!(
+            "\\begin{{verbatim}}\n{}\n\\end{{verbatim}}",
+            render_block.content
+        )))
+    }
+
+    fn render_bullet_list(
+        &mut self,
+        bullet_list: &BulletList,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let mut entries = Latex::default();
+
+        for entry in &bullet_list.entries {
+            entries.append(self.render_bullet_list_entry(entry, context)?)?;
+        }
+
+        Ok(Latex::environment("itemize", "", entries))
+    }
+
+    fn render_bullet_list_entry(
+        &mut self,
+        bullet_list_entry: &BulletListEntry,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let mut latex = match bullet_list_entry.task_status() {
+            Some(TaskStatus::Completed) => {
+                Latex::with_packages("\\item[$\\boxtimes$] ", &[AMSSYMB])
+            }
+            Some(_) => Latex::with_packages("\\item[$\\square$] ", &[AMSSYMB]),
+            None => Latex::with_body("\\item "),
+        };
+
+        latex.append(self.render_inlines(&bullet_list_entry.heading, context)?)?;
+
+        if !bullet_list_entry.body.is_empty() {
+            latex.body.push_str("\n\n");
+            latex.append(self.render_trimmed(&bullet_list_entry.body, context)?)?;
+        }
+
+        latex.body.push('\n');
+        Ok(latex)
+    }
+
+    fn render_numbered_list(
+        &mut self,
+        numbered_list: &NumberedList,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let mut entries = Latex::default();
+
+        for entry in &numbered_list.entries {
+            entries.append(self.render_numbered_list_entry(entry, context)?)?;
+        }
+
+        // enumitem is only needed for other labels or start numbers
+        let mut options = Vec::new();
+        match numbered_list.kind {
+            EnumerationKind::Arabic => {}
+            EnumerationKind::LowerLatin => options.push("label=\\alph*.".to_string()),
+            EnumerationKind::UpperLatin => options.push("label=\\Alph*.".to_string()),
+            EnumerationKind::LowerRoman => options.push("label=\\roman*.".to_string()),
+            EnumerationKind::UpperRoman => options.push("label=\\Roman*.".to_string()),
+        }
+        if numbered_list.start_number != 1 {
+            options.push(format!("start={}", numbered_list.start_number));
+        }
+
+        let args = if options.is_empty() {
+            String::new()
+        } else {
+            entries.head.packages.insert(ENUMITEM);
+            format!("[{}]", options.join(","))
+        };
+
+        Ok(Latex::environment("enumerate", &args, entries))
+    }
+
+    fn render_numbered_list_entry(
+        &mut self,
+        numbered_list_entry: &NumberedListEntry,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let mut latex = Latex::with_body("\\item ");
+        latex.append(self.render_inlines(&numbered_list_entry.heading, context)?)?;
+
+        if !numbered_list_entry.body.is_empty() {
+            latex.body.push_str("\n\n");
+            latex.append(self.render_trimmed(&numbered_list_entry.body, context)?)?;
+        }
+
+        latex.body.push('\n');
+        Ok(latex)
+    }
+
+    fn render_definition_list(
+        &mut self,
+        definition_list: &DefinitionList,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let mut entries = Latex::default();
+
+        for entry in &definition_list.entries {
+            // Braces allow brackets inside the term
+            let mut term = self.render_inlines(&entry.term, context)?;
+            if let Some(classifier) = &entry.classifier {
+                term.body.push_str(" (");
+                term.append(Latex::command(
+                    "textit",
+                    self.render_inlines(classifier, context)?,
+                ))?;
+                term.body.push(')');
+            }
+            term.body = format!("\\item[{{{}}}] ", term.body);
+            entries.append(term)?;
+
+            entries.append(self.render_trimmed(&entry.description, context)?)?;
+            entries.body.push('\n');
+        }
+
+        Ok(Latex::environment("description", "", entries))
+    }
+
+    fn render_table(&mut self, table: &Table, context: &Context) -> Result<Latex, RenderError> {
+        // Columns are aligned like the first cell that only spans this column
+        let alignments: Vec<char> = (0..table.column_count)
+            .map(|column| {
+                table
+                    .rows
+                    .iter()
+                    .filter_map(|row| {
+                        let mut start = 0;
+                        row.cells.iter().find(|cell| {
+                            let found = start == column && cell.column_span == 1;
+                            start += cell.column_span;
+                            found
+                        })
+                    })
+                    .find_map(|cell| cell.alignment)
+                    .map(alignment_char)
+                    .unwrap_or('l')
+            })
+            .collect();
+
+        let mut pending_rows = vec![0; table.column_count];
+        let mut tabular = Latex::with_packages("\\toprule\n", &[BOOKTABS]);
+
+        for row in table.head() {
+            tabular.append(self.render_table_row(
+                row,
+                &alignments,
+                &mut pending_rows,
+                context,
+            )?)?;
+        }
+        if !table.head().is_empty() {
+            tabular.body.push_str("\\midrule\n");
+        }
+
+        for row in table.body() {
+            tabular.append(self.render_table_row(
+                row,
+                &alignments,
+                &mut pending_rows,
+                context,
+            )?)?;
+        }
+
+        if !table.foot().is_empty() {
+            tabular.body.push_str("\\midrule\n");
+            for row in table.foot() {
+                tabular.append(self.render_table_row(
+                    row,
+                    &alignments,
+                    &mut pending_rows,
+                    context,
+                )?)?;
+            }
+        }
+        tabular.body.push_str("\\bottomrule");
+
+        let alignments: String = alignments.into_iter().collect();
+        let tabular = Latex::environment("tabular", &format!("{{{alignments}}}"), tabular);
+
+        self.render_float(
+            "table",
+            tabular,
+            &table.decorations,
+            table.attributes.as_ref(),
+            context,
+        )
+    }
+
+    fn render_quotation(
+        &mut self,
+        quotation: &Quotation,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let mut latex = self.render_trimmed(&quotation.content, context)?;
+
+        if let Some(author) = &quotation.author {
+            latex.body.push_str("\n\\par\\hfill--- ");
+            latex.append(self.render_inlines(author, context)?)?;
+        }
+
+        Ok(Latex::environment("quote", "", latex))
+    }
+
+    fn render_line_block(
+        &mut self,
+        line_block: &LineBlock,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        // Newlines inside line blocks are already rendered as line breaks
+        self.render_blocks(&line_block.content, context)
+    }
+
+    fn render_math_block(
+        &mut self,
+        math_block: &MathBlock,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let content = math_block.content.trim();
+
+        Ok(match math_block.id() {
+            Some(id) => Latex::block(format!(
+                "\\begin{{equation}}\\label{{{}}}\n{content}\n\\end{{equation}}",
+                label_key(id)
+            )),
+            None => Latex::block(format!("\\[\n{content}\n\\]")),
+        })
+    }
+
+    fn render_columns(
+        &mut self,
+        columns: &Columns,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let mut latex = Latex::default();
+
+        for (index, column) in columns.columns.iter().enumerate() {
+            if index > 0 {
+                latex.body.push_str("\\columnbreak\n\n");
+            }
+            latex.append(self.render_blocks(column, context)?)?;
+        }
+
+        // multicols needs at least two columns
+        if columns.columns.len() < 2 {
+            return Ok(latex);
+        }
+
+        latex.head.packages.insert(MULTICOL);
+        Ok(Latex::environment(
+            "multicols",
+            &format!("{{{}}}", columns.columns.len()),
+            latex,
+        ))
+    }
+
+    fn render_text_block(
+        &mut self,
+        text_block: &TextBlock,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        self.render_blocks(&text_block.content, context)
+    }
+
+    fn render_media_insert(
+        &mut self,
+        media_insert: &MediaInsert,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let media = match media_insert.kind {
+            MediaKind::Image => Latex::with_packages(
+                format!(
+                    "\\includegraphics[width=\\linewidth,keepaspectratio]{{{}}}\n\n",
+                    media_insert.source
+                ),
+                &[GRAPHICX],
+            ),
+            // Video and audio cannot be embedded, so a link to the source is shown
+            MediaKind::Video | MediaKind::Audio => Latex::with_packages(
+                format!(
+                    "\\href{{{}}}{{{}}}\n\n",
+                    escape_url(&media_insert.source),
+                    escape(&media_insert.alt)
+                ),
+                &[HYPERREF],
+            ),
+        };
+
+        self.render_float(
+            "figure",
+            media,
+            &media_insert.decorations,
+            media_insert.attributes.as_ref(),
+            context,
+        )
+    }
+
+    fn render_footnote_definition(
+        &mut self,
+        _footnote_definition: &FootnoteDefinition,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        // Content is rendered at the first footnote reference
+        Ok(Latex::default())
+    }
+
+    fn render_abbreviation_definition(
+        &mut self,
+        _abbreviation_definition: &AbbreviationDefinition,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        // Expansion is rendered at the abbreviation reference, or in the glossary
+        Ok(Latex::default())
+    }
+
+    fn render_horizontal_line(
+        &mut self,
+        _horizontal_line: &HorizontalLine,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        Ok(Latex::block("\\noindent\\rule{\\linewidth}{0.4pt}"))
+    }
+
+    fn render_line_break(
+        &mut self,
+        _line_break: &LineBreak,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        Ok(Latex::block("\\vspace{\\baselineskip}"))
+    }
+
+    fn render_page_break(
+        &mut self,
+        _page_break: &PageBreak,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        Ok(Latex::block("\\newpage"))
+    }
+
+    fn render_comment_block(
+        &mut self,
+        comment: &atomic::Comment,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        Ok(Latex::block(latex_comment(&comment.content)))
+    }
+
+    fn render_blankline(
+        &mut self,
+        _blankline: &Span,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        // Blocks are already separated by blank lines
+        Ok(Latex::default())
+    }
+
+    fn render_textbox(
+        &mut self,
+        textbox: &TextBox,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        self.render_nested_inline(textbox.inner(), context)
+    }
+
+    fn render_hyperlink(
+        &mut self,
+        hyperlink: &Hyperlink,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let mut latex = self.render_nested_inline(hyperlink.inner(), context)?;
+        latex.body = format!(
+            "\\href{{{}}}{{{}}}",
+            escape_url(hyperlink.link()),
+            latex.body
+        );
+        latex.head.packages.insert(HYPERREF);
+
+        Ok(latex)
+    }
+
+    fn render_citation(
+        &mut self,
+        citation: &Citation,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let keys = citation
+            .entries()
+            .iter()
+            .map(|entry| entry.id())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        Ok(Latex::with_packages(
+            format!("\\cite{{{keys}}}"),
+            &[BIBLATEX],
+        ))
+    }
+
+    fn render_distinct_reference(
+        &mut self,
+        distinct_reference: &DistinctReference,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let fields = distinct_reference.fields();
+
+        // biblatex formats the authors like in citations
+        if fields.len() == 1 && fields[0] == "authors" {
+            return Ok(Latex::with_packages(
+                format!("\\citeauthor{{{}}}", distinct_reference.id()),
+                &[BIBLATEX],
+            ));
+        }
+
+        Ok(Latex::with_body(escape(
+            &context.distinct_reference_text(distinct_reference),
+        )))
+    }
+
+    fn render_footnote_reference(
+        &mut self,
+        footnote_reference: &FootnoteReference,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let Some((number, footnote)) = context.doc.footnote(footnote_reference.id()) else {
+            log!(
+                GeneralWarning::UnresolvedFootnote,
+                format!(
+                    "Footnote '{}' is referenced, but not defined.",
+                    footnote_reference.id()
+                )
+            );
+
+            return Ok(Latex::with_body(escape(&footnote_reference.as_unimarkup())));
+        };
+
+        // Footnote content is only shown once, so later references only show the footnote mark
+        if !self.rendered_footnotes.insert(number) {
+            return Ok(Latex::with_body(format!("\\footnotemark[{number}]")));
+        }
+
+        let mut latex = self.render_footnote_content(footnote, context)?;
+        latex.body = format!("\\footnote[{number}]{{{}}}", latex.body);
+
+        Ok(latex)
+    }
+
+    fn render_abbreviation_reference(
+        &mut self,
+        abbreviation_reference: &AbbreviationReference,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let Some(definition) = context
+            .doc
+            .abbreviation(abbreviation_reference.abbreviation())
+        else {
+            log!(
+                GeneralWarning::UnresolvedAbbreviation,
+                format!(
+                    "Abbreviation '{}' is referenced, but not defined.",
+                    abbreviation_reference.abbreviation()
+                )
+            );
+
+            return Ok(Latex::with_body(escape(
+                abbreviation_reference.abbreviation(),
+            )));
+        };
+
+        if abbreviation_reference.display() == AbbreviationDisplay::Replace {
+            return self.render_nested_inline(&definition.expansion, context);
+        }
+
+        Ok(Latex::with_body(escape(
+            abbreviation_reference.abbreviation(),
+        )))
+    }
+
+    fn render_reference(
+        &mut self,
+        reference: &Reference,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let Some((target, text)) = context.resolve_reference(reference) else {
+            return Ok(Latex::with_body(escape(&reference.as_unimarkup())));
+        };
+
+        Ok(Latex::with_packages(
+            format!("\\hyperref[{}]{{{}}}", label_key(&target.id), escape(&text)),
+            &[HYPERREF],
+        ))
+    }
+
+    fn render_named_substitution(
+        &mut self,
+        named_substitution: &NamedSubstitution,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let content = match named_substitution.subst() {
+            Some(subst) => subst.to_string(),
+            None => {
+                let span = named_substitution.span();
+                log!(
+                    GeneralWarning::UnknownNamedSubstitution,
+                    format!(
+                        "Named substitution '{}' at {}:{}-{}:{} has an unknown alias.",
+                        named_substitution.alias(),
+                        span.start.line,
+                        span.start.col_utf8,
+                        span.end.line,
+                        span.end.col_utf8,
+                    )
+                );
+
+                named_substitution.as_unimarkup()
+            }
+        };
+
+        Ok(Latex::with_body(escape(&content)))
+    }
+
+    fn render_direct_unicode(
+        &mut self,
+        direct_unicode: &DirectUnicode,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        Ok(Latex::with_body(escape(
+            &direct_unicode.character().to_string(),
+        )))
+    }
+
+    fn render_implicit_substitution(
+        &mut self,
+        implicit_substitution: &ImplicitSubstitution,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        Ok(Latex::with_body(escape(implicit_substitution.subst())))
+    }
+
+    fn render_footnotes(&mut self, _context: &Context) -> Result<Latex, RenderError> {
+        // Footnotes are placed by LaTeX at the bottom of the page of their reference
+        Ok(Latex::default())
+    }
+
+    fn render_bibliography(&mut self, context: &Context) -> Result<Latex, RenderError> {
+        if context.doc.citations.is_empty() {
+            return Ok(Latex::default());
+        }
+
+        // biblatex sets the heading in the language of the document
+        Ok(Latex::with_packages("\\printbibliography\n\n", &[BIBLATEX]))
+    }
+
+    fn render_glossary(&mut self, context: &Context) -> Result<Latex, RenderError> {
+        if !context.get_config().preamble.render.glossary || context.doc.abbreviations.is_empty() {
+            return Ok(Latex::default());
+        }
+
+        let mut abbreviations: Vec<_> = context.doc.abbreviations.iter().collect();
+        abbreviations.sort_by_key(|abbr| abbr.abbreviation.to_lowercase());
+
+        let mut entries = Latex::default();
+
+        for abbreviation in abbreviations {
+            entries.body.push_str(&format!(
+                "\\item[{{{}}}] ",
+                escape(&abbreviation.abbreviation)
+            ));
+            entries.append(self.render_nested_inline(&abbreviation.expansion, context)?)?;
+            entries.body.push('\n');
+        }
+
+        Ok(Latex::environment("description", "", entries))
+    }
+
+    fn render_bold(&mut self, bold: &Bold, context: &Context) -> Result<Latex, RenderError> {
+        let inner = self.render_nested_inline(bold.inner(), context)?;
+        Ok(Latex::command("textbf", inner))
+    }
+
+    fn render_italic(&mut self, italic: &Italic, context: &Context) -> Result<Latex, RenderError> {
+        let inner = self.render_nested_inline(italic.inner(), context)?;
+        Ok(Latex::command("emph", inner))
+    }
+
+    fn render_underline(
+        &mut self,
+        underline: &Underline,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let inner = self.render_nested_inline(underline.inner(), context)?;
+        Ok(Latex::command("underline", inner))
+    }
+
+    fn render_subscript(
+        &mut self,
+        subscript: &Subscript,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let inner = self.render_nested_inline(subscript.inner(), context)?;
+        Ok(Latex::command("textsubscript", inner))
+    }
+
+    fn render_superscript(
+        &mut self,
+        superscript: &Superscript,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let inner = self.render_nested_inline(superscript.inner(), context)?;
+        Ok(Latex::command("textsuperscript", inner))
+    }
+
+    fn render_overline(
+        &mut self,
+        overline: &Overline,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let mut inner = self.render_nested_inline(overline.inner(), context)?;
+        inner.body = format!("\\ensuremath{{\\overline{{\\mbox{{{}}}}}}}", inner.body);
+        Ok(inner)
+    }
+
+    fn render_strikethrough(
+        &mut self,
+        strikethrough: &Strikethrough,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let mut inner = self.render_nested_inline(strikethrough.inner(), context)?;
+        inner.head.packages.insert(ULEM);
+        Ok(Latex::command("sout", inner))
+    }
+
+    fn render_highlight(
+        &mut self,
+        highlight: &Highlight,
+        context: &Context,
+    ) -> Result<Latex, RenderError> {
+        let mut inner = self.render_nested_inline(highlight.inner(), context)?;
+        inner.body = format!("\\colorbox{{yellow}}{{{}}}", inner.body);
+        inner.head.packages.insert(XCOLOR);
+        Ok(inner)
+    }
+
+    fn render_quote(&mut self, quote: &Quote, context: &Context) -> Result<Latex, RenderError> {
+        let mut inner = self.render_nested_inline(quote.inner(), context)?;
+        inner.head.packages.insert(CSQUOTES);
+        Ok(Latex::command("enquote", inner))
+    }
+
+    fn render_inline_verbatim(
+        &mut self,
+        verbatim: &Verbatim,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        Ok(Latex::with_body(format!(
+            "\\texttt{{{}}}",
+            escape(&verbatim.inner().as_unimarkup())
+        )))
+    }
+
+    fn render_inline_math(
+        &mut self,
+        math: &Math,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        Ok(Latex::with_body(format!(
+            "${}$",
+            math.inner().as_unimarkup()
+        )))
+    }
+
+    fn render_inline_comment(
+        &mut self,
+        comment: &Comment,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        // LaTeX comments end at the end of the line, so a newline is required
+        Ok(Latex::with_body(format!(
+            "{}\n",
+            latex_comment(comment.content())
+        )))
+    }
+
+    fn render_plain(&mut self, plain: &Plain, _context: &Context) -> Result<Latex, RenderError> {
+        Ok(Latex::with_body(escape(plain.content())))
+    }
+
+    fn render_newline(
+        &mut self,
+        _newline: &Newline,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        Ok(Latex::with_body("\n"))
+    }
+
+    fn render_implicit_newline(
+        &mut self,
+        _implicit_newline: &Newline,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        Ok(Latex::with_body("\\\\\n"))
+    }
+
+    fn render_escaped_newline(
+        &mut self,
+        _escaped_newline: &EscapedNewline,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        Ok(Latex::with_body("\\\\\n"))
+    }
+
+    fn render_escaped_whitespace(
+        &mut self,
+        _escaped_whitespace: &EscapedWhitespace,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        Ok(Latex::with_body("~"))
+    }
+
+    fn render_escaped_plain(
+        &mut self,
+        escaped_plain: &EscapedPlain,
+        _context: &Context,
+    ) -> Result<Latex, RenderError> {
+        Ok(Latex::with_body(escape(escaped_plain.content())))
+    }
+}
+
+/// Returns the column specifier of `tabular` for the given alignment.
+fn alignment_char(alignment: ColumnAlignment) -> char {
+    match alignment {
+        ColumnAlignment::Left => 'l',
+        ColumnAlignment::Center => 'c',
+        ColumnAlignment::Right => 'r',
+    }
+}
+
+/// Escapes characters of the given URL that would break `\href`.
+fn escape_url(url: &str) -> String {
+    url.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('#', "\\#")
+}
+
+/// Returns the key used in `\label` and `\hyperref` for the given ID.
+///
+/// Characters that may break labels are replaced by their code point in hex enclosed in `+`
+/// (e.g. `100%` becomes `100+25+`), so different IDs always result in different keys.
+fn label_key(id: &str) -> String {
+    let mut key = String::with_capacity(id.len());
+
+    for c in id.chars() {
+        if c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | ':') {
+            key.push(c);
+        } else {
+            key.push_str(&format!("+{:X}+", c as u32));
+        }
+    }
+
+    key
+}
+
+/// Creates a LaTeX comment with the given content.
+/// Every line of the content is commented out.
+fn latex_comment(content: &str) -> String {
+    content
+        .trim()
+        .lines()
+        .map(|line| format!("% {}", line.trim()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use unimarkup_commons::config::{output::OutputFormatKind, Config};
+    use unimarkup_parser::parse_unimarkup;
+
+    use super::LatexRenderer;
+
+    fn render(input: &str) -> String {
+        let doc = parse_unimarkup(input, Config::default());
+
+        crate::render::render(&doc, OutputFormatKind::Latex, LatexRenderer::default())
+            .unwrap()
+            .to_string()
+    }
+
+    /// Asserts that the rendered document contains all expected snippets.
+    fn assert_contains(latex: &str, expected: &[&str]) {
+        for expected in expected {
+            assert!(
+                latex.contains(expected),
+                "Rendered LaTeX does not contain '{expected}':\n{latex}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_citations_and_distinct_references() {
+        let mut config = Config::default();
+        config
+            .preamble
+            .cite
+            .references
+            .insert("./src/html/citeproc/test_files/citation_items.csl".into());
+        let doc = parse_unimarkup(
+            "Cited [&&id-1&&id-2] by &&id-1&&: &&id-1.title&&, not &&unknown.title&&.\n",
+            config,
+        );
+        let latex = crate::render::render(&doc, OutputFormatKind::Latex, LatexRenderer::default())
+            .unwrap()
+            .to_string();
+
+        assert_contains(
+            &latex,
+            &[
+                "\\usepackage{biblatex}",
+                "Cited \\cite{id-1,id-2} by \\citeauthor{id-1}: Some Notes on Gertrude Stein and Deixis, not \\&\\&unknown.title\\&\\&.",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_escape_special_characters() {
+        let latex = render("Special & % $ # _ { } ~ ^ \\\\ chars.\n");
+
+        assert_contains(
+            &latex,
+            &["Special \\& \\% \\$ \\# \\_ \\{ \\} \\textasciitilde{} \\textasciicircum{} \\textbackslash{} chars."],
+        );
+    }
+
+    #[test]
+    fn test_heading_labels_with_special_characters() {
+        let latex = render("# 100% done\n\n# C# tips\n\nSee [##100%-done]_ and [##c#-tips]_.\n");
+
+        assert_contains(
+            &latex,
+            &[
+                "\\section{100\\% done}\\label{100+25+-done}",
+                "\\section{C\\# tips}\\label{c+23+-tips}",
+                "See \\hyperref[100+25+-done]{100\\% done} and \\hyperref[c+23+-tips]{C\\# tips}.",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_equation_and_caption_labels() {
+        let latex = render(
+            "$$${ \"id\": \"eq_1\" }\nx^2\n$$$\n\n===\nTitle\n===\n+---+---+\n| a | b |\n+---+---+\n{ \"id\": \"tab#1\" }\n\nSee [##eq_1]_ and [##tab#1]_.\n",
+        );
+
+        assert_contains(
+            &latex,
+            &[
+                "\\begin{equation}\\label{eq+5F+1}\nx^2\n\\end{equation}",
+                "\\caption{Title}\\label{tab+23+1}",
+                "See \\hyperref[eq+5F+1]{1} and \\hyperref[tab+23+1]{1}.",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_label_keys_are_distinct() {
+        assert_eq!(super::label_key("plain-id.1:2"), "plain-id.1:2");
+        assert_ne!(super::label_key("a%b"), super::label_key("a#b"));
+        assert_ne!(super::label_key("a+25+b"), super::label_key("a%b"));
+    }
+
+    #[test]
+    fn test_tables_with_spans() {
+        let latex = render(
+            "+---+---+---+\n| a | b | c |\n+-------+---+\n| spanned | d |\n+---+---+---+\n\n+------+---+\n| long | a |\n+      +---+\n| cell | b |\n+------+---+\n",
+        );
+
+        assert_contains(
+            &latex,
+            &[
+                "\\begin{tabular}{lll}\n\\toprule\na & b & c \\\\\n\\multicolumn{2}{l}{spanned} & d \\\\\n\\bottomrule\n\\end{tabular}",
+                "\\multirow{2}{*}{long\ncell} & a \\\\\n & b \\\\",
+                "\\usepackage{multirow}",
+            ],
+        );
+    }
+}
@@ -4,8 +4,13 @@
 //! It also provides syntax highlighting functionality.
 
 mod csl_json;
+pub mod epub;
 pub mod html;
+pub mod latex;
 pub mod log_id;
+pub mod markdown;
 pub mod pdf;
 pub mod render;
+pub mod render_block;
+pub mod txt;
 pub mod umi;
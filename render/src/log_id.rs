@@ -11,6 +11,12 @@ pub enum RenderError {
 
     #[error("Unexpected error during pdf render: {}", .0)]
     UnexpectedPdfError(String),
+
+    #[error("Unexpected error during epub render: {}", .0)]
+    UnexpectedEpubError(String),
+
+    #[error("Rendering the content of a render block failed: {}", .0)]
+    RenderBlockFailed(String),
 }
 
 #[derive(Debug, Clone, WarnLogId)]
@@ -26,6 +32,24 @@ pub enum GeneralWarning {
 
     /// Log-id denoting a failed json serialization
     JSONSerialization,
+
+    /// Log-id denoting that a render block could not be rendered, and its content is shown as is
+    RenderBlockFallback,
+
+    /// Log-id denoting a reference to a footnote that is not defined
+    UnresolvedFootnote,
+
+    /// Log-id denoting a reference to an abbreviation that is not defined
+    UnresolvedAbbreviation,
+
+    /// Log-id denoting a cross-reference to an ID that no element has
+    UnresolvedReference,
+
+    /// Log-id denoting a named substitution with an unknown alias
+    UnknownNamedSubstitution,
+
+    /// Log-id denoting a distinct reference to a bibliography entry or field that does not exist
+    UnresolvedDistinctReference,
 }
 
 #[derive(Debug, Clone, ErrLogId, Error)]
@@ -0,0 +1,61 @@
+//! Defines the [`Markdown`] struct that is returned when rendering Unimarkup to Markdown.
+//!
+//! The rendered Markdown follows [CommonMark](https://commonmark.org/) with the
+//! [GitHub Flavored Markdown](https://github.github.com/gfm/) extensions for tables, task lists, strikethrough, and footnotes.
+
+use crate::render::{Context, OutputFormat};
+
+pub mod render;
+
+/// Markdown content rendered from Unimarkup.
+///
+/// Rendered blocks end with a blank line, so blocks may be appended without additional separation.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Markdown {
+    pub content: String,
+}
+
+impl Markdown {
+    /// Creates Markdown with the given content.
+    pub fn with_content(content: impl Into<String>) -> Self {
+        Markdown {
+            content: content.into(),
+        }
+    }
+
+    /// Creates a Markdown block with the given content.
+    /// The content is terminated with a blank line to separate it from following blocks.
+    pub fn block(content: impl AsRef<str>) -> Self {
+        let content = content.as_ref().trim_end_matches('\n');
+
+        if content.is_empty() {
+            return Markdown::default();
+        }
+
+        Markdown::with_content(format!("{content}\n\n"))
+    }
+}
+
+impl OutputFormat for Markdown {
+    fn new(_context: &Context) -> Self {
+        Markdown::default()
+    }
+
+    fn append(&mut self, other: Self) -> Result<(), crate::log_id::RenderError> {
+        self.content.push_str(&other.content);
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Markdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let content = self.content.trim_end_matches('\n');
+
+        if content.is_empty() {
+            Ok(())
+        } else {
+            writeln!(f, "{content}")
+        }
+    }
+}
@@ -0,0 +1,1331 @@
+use std::collections::HashSet;
+
+use logid::log;
+use unimarkup_commons::lexer::span::Span;
+use unimarkup_inline::element::substitution::{
+    direct::DirectUnicode, named::NamedSubstitution, DistinctReference, ImplicitSubstitution,
+};
+use unimarkup_inline::element::{
+    base::{Comment, EscapedNewline, EscapedPlain, EscapedWhitespace, Newline, Plain},
+    formatting::{
+        Bold, Highlight, Italic, Math, Overline, Quote, Strikethrough, Subscript, Superscript,
+        Underline, Verbatim,
+    },
+    textbox::{
+        abbreviation::{AbbreviationDisplay, AbbreviationReference},
+        citation::Citation,
+        footnote::FootnoteReference,
+        hyperlink::Hyperlink,
+        reference::Reference,
+        TextBox,
+    },
+    Inline, InlineElement,
+};
+use unimarkup_parser::elements::{
+    atomic::{self, Heading, HorizontalLine, LineBreak, PageBreak, Paragraph},
+    blocks::Block,
+    decorations::Decorations,
+    definitions::{AbbreviationDefinition, FootnoteDefinition},
+    enclosed::{
+        ColumnAlignment, Columns, MathBlock, RenderBlock, Table, TableRow, TextBlock, VerbatimBlock,
+    },
+    indents::{
+        BulletList, BulletListEntry, DefinitionList, LineBlock, NumberedList, NumberedListEntry,
+        Quotation, TaskStatus,
+    },
+    inserts::{MediaInsert, MediaKind},
+};
+
+use crate::html::render::HtmlRenderer;
+use crate::log_id::{GeneralWarning, RenderError};
+use crate::render::{
+    decoration_label, plain_text, Context, OutputFormat, Renderer, FIGURE_LABEL, LISTING_LABEL,
+    TABLE_LABEL,
+};
+
+use super::Markdown;
+
+/// Characters that are escaped in plain content, because they may be interpreted as Markdown syntax.
+const ESCAPED_CHARS: &[char] = &['\\', '`', '*', '_', '[', ']', '<', '>', '|', '~', '$'];
+
+#[derive(Debug, Default)]
+pub struct MarkdownRenderer {
+    citation_index: usize,
+    /// Rendered citations that are added as footnotes at the end of the document.
+    citations: Vec<String>,
+    /// Numbers of footnotes that were already referenced.
+    referenced_footnotes: HashSet<usize>,
+}
+
+impl MarkdownRenderer {
+    /// Renders the given blocks, and prefixes every line with the given prefix.
+    /// The first line is prefixed with `first_prefix` instead.
+    fn render_prefixed(
+        &mut self,
+        blocks: &[Block],
+        first_prefix: &str,
+        prefix: &str,
+        context: &Context,
+    ) -> Result<String, RenderError> {
+        let content = self.render_blocks(blocks, context)?;
+
+        Ok(prefix_lines(
+            content.content.trim_end(),
+            first_prefix,
+            prefix,
+        ))
+    }
+
+    /// Renders the title and caption of a decorated element, prefixed with its label in bold (e.g. "**Table 3:** ").
+    ///
+    /// Returns `None` if the element is not decorated.
+    fn render_decorations(
+        &mut self,
+        label: &str,
+        decorations: &Decorations,
+        context: &Context,
+    ) -> Result<Option<Markdown>, RenderError> {
+        let mut md = match decoration_label(label, decorations) {
+            Some(label) => Markdown::with_content(format!("**{label}:** ")),
+            None => Markdown::default(),
+        };
+
+        match (&decorations.title, &decorations.caption) {
+            (Some(title), Some(caption)) => {
+                md.append(self.render_inlines(title, context)?)?;
+                md.content.push_str("\\\n");
+                md.append(self.render_inlines(caption, context)?)?;
+            }
+            (Some(content), None) | (None, Some(content)) => {
+                md.append(self.render_inlines(content, context)?)?
+            }
+            (None, None) => return Ok(None),
+        }
+
+        Ok(Some(Markdown::block(md.content)))
+    }
+
+    /// Places the decorations of an element as paragraph around the given content.
+    /// Decorations are placed before the content if a title is set, and after the content otherwise.
+    fn render_decorated(
+        &mut self,
+        content: Markdown,
+        label: &str,
+        decorations: &Decorations,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let Some(decorations_md) = self.render_decorations(label, decorations, context)? else {
+            return Ok(content);
+        };
+
+        if decorations.title.is_some() {
+            let mut md = decorations_md;
+            md.append(content)?;
+            Ok(md)
+        } else {
+            let mut md = content;
+            md.append(decorations_md)?;
+            Ok(md)
+        }
+    }
+
+    /// Renders the given formatting with the given Markdown delimiter around its content.
+    fn render_delimited(
+        &mut self,
+        delimiter: &str,
+        inner: &[Inline],
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let inner = self.render_nested_inline(inner, context)?;
+
+        Ok(Markdown::with_content(format!(
+            "{delimiter}{}{delimiter}",
+            inner.content
+        )))
+    }
+
+    /// Renders the given formatting as inline HTML element, because Markdown has no syntax for it.
+    fn render_html_inline(
+        &mut self,
+        tag: &str,
+        style: Option<&str>,
+        inner: &[Inline],
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let inner = self.render_nested_inline(inner, context)?;
+        let style = style
+            .map(|style| format!(" style=\"{style}\""))
+            .unwrap_or_default();
+
+        Ok(Markdown::with_content(format!(
+            "<{tag}{style}>{}</{tag}>",
+            inner.content
+        )))
+    }
+
+    /// Renders the content of the given footnote.
+    /// Following blocks are indented, so they belong to the footnote.
+    fn render_footnote_content(
+        &mut self,
+        footnote: &FootnoteDefinition,
+        context: &Context,
+    ) -> Result<String, RenderError> {
+        self.render_prefixed(&footnote.content, "", "    ", context)
+    }
+
+    /// Returns `true` if the given table may be rendered as GFM table.
+    /// GFM tables need exactly one header row, and do not support cells spanning multiple rows or columns.
+    fn is_gfm_table(table: &Table) -> bool {
+        table.head().len() <= 1
+            && table.foot().is_empty()
+            && table.rows.iter().all(|row| {
+                row.cells
+                    .iter()
+                    .all(|cell| cell.column_span == 1 && cell.row_span == 1)
+            })
+    }
+
+    /// Renders the content of all cells of the given row.
+    /// Cell content must be in one line, so blocks are separated by `<br>`.
+    fn render_table_cells(
+        &mut self,
+        row: &TableRow,
+        context: &Context,
+    ) -> Result<Vec<String>, RenderError> {
+        let mut cells = Vec::with_capacity(row.cells.len());
+
+        for cell in &row.cells {
+            let mut content = Vec::new();
+
+            for block in &cell.content {
+                let rendered = match block {
+                    Block::Paragraph(paragraph) => {
+                        self.render_inlines(&paragraph.content, context)?
+                    }
+                    block => self.render_block(block, context)?,
+                };
+                content.push(rendered.content.trim().replace('\n', " "));
+            }
+
+            cells.push(content.join("<br>"));
+        }
+
+        Ok(cells)
+    }
+}
+
+impl Renderer<Markdown> for MarkdownRenderer {
+    fn get_target(&mut self) -> Result<Markdown, RenderError> {
+        Ok(Markdown::default())
+    }
+
+    fn render_attributes(
+        &mut self,
+        rendered: Markdown,
+        attributes: &unimarkup_commons::attributes::Attributes,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        // Classes and styles are not supported in Markdown, but elements with an ID need an anchor to be referenced
+        match attributes.id() {
+            Some(id) => Ok(with_anchor(id, rendered)),
+            None => Ok(rendered),
+        }
+    }
+
+    fn render_paragraph(
+        &mut self,
+        paragraph: &Paragraph,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let inner = self.render_inlines(&paragraph.content, context)?;
+
+        Ok(Markdown::block(escape_line_starts(&inner.content)))
+    }
+
+    fn render_heading(
+        &mut self,
+        heading: &Heading,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let inner = self.render_inlines(&heading.content, context)?;
+        // Headings must be in one line
+        let content = inner.content.replace("\\\n", " ").replace('\n', " ");
+
+        Ok(Markdown::block(format!(
+            "{} {}",
+            heading.level.as_str(),
+            content.trim()
+        )))
+    }
+
+    fn render_verbatim_block(
+        &mut self,
+        verbatim: &VerbatimBlock,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let fence = code_fence(&verbatim.content);
+        let lang = verbatim.data_lang.as_deref().unwrap_or_default();
+
+        self.render_decorated(
+            Markdown::block(format!("{fence}{lang}\n{}\n{fence}", verbatim.content)),
+            LISTING_LABEL,
+            &verbatim.decorations,
+            context,
+        )
+    }
+
+    fn render_render_block(
+        &mut self,
+        render_block: &RenderBlock,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        // Markdown viewers like GitHub render supported languages (e.g. mermaid) from code blocks
+        let fence = code_fence(&render_block.content);
+
+        Ok(Markdown::block(format!(
+            "{fence}{}\n{}\n{fence}",
+            render_block.lang, render_block.content
+        )))
+    }
+
+    fn render_bullet_list(
+        &mut self,
+        bullet_list: &BulletList,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let mut entries = Vec::with_capacity(bullet_list.entries.len());
+        let mut loose = false;
+
+        for entry in &bullet_list.entries {
+            loose |= !entry.body.is_empty();
+            entries.push(self.render_bullet_list_entry(entry, context)?.content);
+        }
+
+        Ok(Markdown::block(join_entries(&entries, loose)))
+    }
+
+    fn render_bullet_list_entry(
+        &mut self,
+        bullet_list_entry: &BulletListEntry,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let mut heading = escape_line_starts(
+            &self
+                .render_inlines(&bullet_list_entry.heading, context)?
+                .content,
+        );
+
+        // GFM task lists only distinguish between open and completed tasks
+        match bullet_list_entry.task_status() {
+            Some(TaskStatus::Completed) => heading.insert_str(0, "[x] "),
+            Some(_) => heading.insert_str(0, "[ ] "),
+            None => {}
+        }
+
+        let mut entry = prefix_lines(&heading, "- ", "  ");
+
+        if !bullet_list_entry.body.is_empty() {
+            entry.push_str("\n\n");
+            entry.push_str(&self.render_prefixed(&bullet_list_entry.body, "  ", "  ", context)?);
+        }
+
+        Ok(Markdown::with_content(entry))
+    }
+
+    fn render_numbered_list(
+        &mut self,
+        numbered_list: &NumberedList,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let mut entries = Vec::with_capacity(numbered_list.entries.len());
+        let mut loose = false;
+
+        // Markdown only supports arabic numbers, so other enumeration kinds are rendered with arabic numbers
+        for (index, entry) in numbered_list.entries.iter().enumerate() {
+            let marker = format!("{}. ", numbered_list.start_number + index);
+            let indent = " ".repeat(marker.len());
+            let heading =
+                escape_line_starts(&self.render_inlines(&entry.heading, context)?.content);
+            let mut rendered = prefix_lines(&heading, &marker, &indent);
+
+            if !entry.body.is_empty() {
+                loose = true;
+                rendered.push_str("\n\n");
+                rendered.push_str(&self.render_prefixed(&entry.body, &indent, &indent, context)?);
+            }
+
+            entries.push(rendered);
+        }
+
+        Ok(Markdown::block(join_entries(&entries, loose)))
+    }
+
+    fn render_numbered_list_entry(
+        &mut self,
+        numbered_list_entry: &NumberedListEntry,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        // Entries are numbered by the list, so a single entry is rendered as first entry
+        let heading = escape_line_starts(
+            &self
+                .render_inlines(&numbered_list_entry.heading, context)?
+                .content,
+        );
+        let mut entry = prefix_lines(&heading, "1. ", "   ");
+
+        if !numbered_list_entry.body.is_empty() {
+            entry.push_str("\n\n");
+            entry.push_str(&self.render_prefixed(
+                &numbered_list_entry.body,
+                "   ",
+                "   ",
+                context,
+            )?);
+        }
+
+        Ok(Markdown::with_content(entry))
+    }
+
+    fn render_definition_list(
+        &mut self,
+        definition_list: &DefinitionList,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        // Markdown has no definition lists, so HTML is used.
+        // Markdown content inside HTML blocks must be surrounded by blank lines.
+        let mut md = String::from("<dl>\n");
+
+        for entry in &definition_list.entries {
+            let mut term = self.render_inlines(&entry.term, context)?.content;
+
+            if let Some(classifier) = &entry.classifier {
+                let classifier = self.render_inlines(classifier, context)?.content;
+                term.push_str(&format!(" <span class=\"classifier\">{classifier}</span>"));
+            }
+
+            md.push_str(&format!("<dt>\n\n{}\n\n</dt>\n", term.trim()));
+
+            if !entry.description.is_empty() {
+                let description = self.render_blocks(&entry.description, context)?;
+                md.push_str(&format!(
+                    "<dd>\n\n{}\n\n</dd>\n",
+                    description.content.trim_end()
+                ));
+            }
+        }
+
+        md.push_str("</dl>");
+
+        Ok(Markdown::block(md))
+    }
+
+    fn render_table(&mut self, table: &Table, context: &Context) -> Result<Markdown, RenderError> {
+        if !Self::is_gfm_table(table) {
+            // Tables that cannot be represented by GFM tables are rendered as HTML
+            let html = HtmlRenderer::default().render_table(table, context)?;
+            return Ok(Markdown::block(html.body.elements.to_string()));
+        }
+
+        let column_count = table
+            .rows
+            .iter()
+            .map(|row| row.cells.len())
+            .max()
+            .unwrap_or_default();
+
+        let head = match table.head().first() {
+            Some(row) => self.render_table_cells(row, context)?,
+            None => Vec::new(),
+        };
+
+        let alignments = (0..column_count)
+            .map(|index| {
+                let alignment = table
+                    .rows
+                    .first()
+                    .and_then(|row| row.cells.get(index))
+                    .and_then(|cell| cell.alignment);
+
+                match alignment {
+                    Some(ColumnAlignment::Left) => ":---",
+                    Some(ColumnAlignment::Center) => ":---:",
+                    Some(ColumnAlignment::Right) => "---:",
+                    None => "---",
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut rows = vec![
+            table_row(&head, column_count),
+            format!("| {} |", alignments.join(" | ")),
+        ];
+
+        for row in table.body() {
+            let cells = self.render_table_cells(row, context)?;
+            rows.push(table_row(&cells, column_count));
+        }
+
+        self.render_decorated(
+            Markdown::block(rows.join("\n")),
+            TABLE_LABEL,
+            &table.decorations,
+            context,
+        )
+    }
+
+    fn render_quotation(
+        &mut self,
+        quotation: &Quotation,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let mut content = self.render_blocks(&quotation.content, context)?.content;
+
+        if let Some(author) = &quotation.author {
+            let author = self.render_inlines(author, context)?;
+            content = format!("{}\n\n— {}", content.trim_end(), author.content);
+        }
+
+        Ok(Markdown::block(prefix_lines(
+            content.trim_end(),
+            "> ",
+            "> ",
+        )))
+    }
+
+    fn render_line_block(
+        &mut self,
+        line_block: &LineBlock,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        // Newlines inside line blocks are already rendered as hard line breaks
+        self.render_blocks(&line_block.content, context)
+    }
+
+    fn render_math_block(
+        &mut self,
+        math_block: &MathBlock,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let fence = code_fence(&math_block.content);
+        let math = Markdown::block(format!("{fence}math\n{}\n{fence}", math_block.content));
+
+        // IDs set with attributes get their anchor in `render_attributes()`
+        let has_attribute_id = math_block
+            .attributes
+            .as_ref()
+            .is_some_and(|attributes| attributes.id().is_some());
+
+        match &math_block.id {
+            Some(id) if !has_attribute_id => Ok(with_anchor(id, math)),
+            _ => Ok(math),
+        }
+    }
+
+    fn render_columns(
+        &mut self,
+        columns: &Columns,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        // Markdown has no layout, so columns are rendered one after another
+        let mut md = Markdown::default();
+
+        for column in &columns.columns {
+            md.append(self.render_blocks(column, context)?)?;
+        }
+
+        Ok(md)
+    }
+
+    fn render_text_block(
+        &mut self,
+        text_block: &TextBlock,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        self.render_blocks(&text_block.content, context)
+    }
+
+    fn render_media_insert(
+        &mut self,
+        media_insert: &MediaInsert,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let media = match media_insert.kind {
+            MediaKind::Image => {
+                format!("![{}]({})", escape(&media_insert.alt), media_insert.source)
+            }
+            MediaKind::Video => format!(
+                "<video src=\"{}\" controls>{}</video>",
+                media_insert.source, media_insert.alt
+            ),
+            MediaKind::Audio => format!(
+                "<audio src=\"{}\" controls>{}</audio>",
+                media_insert.source, media_insert.alt
+            ),
+        };
+
+        self.render_decorated(
+            Markdown::block(media),
+            FIGURE_LABEL,
+            &media_insert.decorations,
+            context,
+        )
+    }
+
+    fn render_footnote_definition(
+        &mut self,
+        _footnote_definition: &FootnoteDefinition,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        // Content is rendered with the other footnotes
+        Ok(Markdown::default())
+    }
+
+    fn render_abbreviation_definition(
+        &mut self,
+        _abbreviation_definition: &AbbreviationDefinition,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        // Expansion is rendered at the abbreviation reference, or in the glossary
+        Ok(Markdown::default())
+    }
+
+    fn render_horizontal_line(
+        &mut self,
+        _horizontal_line: &HorizontalLine,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        Ok(Markdown::block("---"))
+    }
+
+    fn render_line_break(
+        &mut self,
+        _line_break: &LineBreak,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        Ok(Markdown::block("<br>"))
+    }
+
+    fn render_page_break(
+        &mut self,
+        _page_break: &PageBreak,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        Ok(Markdown::block("<div style=\"break-after: page;\"></div>"))
+    }
+
+    fn render_comment_block(
+        &mut self,
+        comment: &atomic::Comment,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        Ok(Markdown::block(markdown_comment(&comment.content)))
+    }
+
+    fn render_blankline(
+        &mut self,
+        _blankline: &Span,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        // Blocks are already separated by blank lines
+        Ok(Markdown::default())
+    }
+
+    fn render_textbox(
+        &mut self,
+        textbox: &TextBox,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        self.render_nested_inline(textbox.inner(), context)
+    }
+
+    fn render_hyperlink(
+        &mut self,
+        hyperlink: &Hyperlink,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let inner = self.render_nested_inline(hyperlink.inner(), context)?;
+        let title = hyperlink
+            .link_text()
+            .map(|title| format!(" \"{}\"", title.replace('"', "\\\"")))
+            .unwrap_or_default();
+
+        Ok(Markdown::with_content(format!(
+            "[{}]({}{title})",
+            inner.content,
+            hyperlink.link()
+        )))
+    }
+
+    fn render_citation(
+        &mut self,
+        _citation: &Citation,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let citation = context
+            .rendered_citation(self.citation_index)
+            .expect("Rendered citation must exist for parsed citation.");
+        self.citation_index += 1;
+
+        // Citations are rendered as footnotes, which are added at the end of the document
+        self.citations.push(citation.clone());
+
+        Ok(Markdown::with_content(format!(
+            "[^cite-{}]",
+            self.citations.len()
+        )))
+    }
+
+    fn render_distinct_reference(
+        &mut self,
+        distinct_reference: &DistinctReference,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        Ok(Markdown::with_content(escape(
+            &context.distinct_reference_text(distinct_reference),
+        )))
+    }
+
+    fn render_footnote_reference(
+        &mut self,
+        footnote_reference: &FootnoteReference,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let Some((number, _)) = context.doc.footnote(footnote_reference.id()) else {
+            log!(
+                GeneralWarning::UnresolvedFootnote,
+                format!(
+                    "Footnote '{}' is referenced, but not defined.",
+                    footnote_reference.id()
+                )
+            );
+
+            return Ok(Markdown::with_content(escape(
+                &footnote_reference.as_unimarkup(),
+            )));
+        };
+        self.referenced_footnotes.insert(number);
+
+        Ok(Markdown::with_content(format!("[^{number}]")))
+    }
+
+    fn render_abbreviation_reference(
+        &mut self,
+        abbreviation_reference: &AbbreviationReference,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let Some(definition) = context
+            .doc
+            .abbreviation(abbreviation_reference.abbreviation())
+        else {
+            log!(
+                GeneralWarning::UnresolvedAbbreviation,
+                format!(
+                    "Abbreviation '{}' is referenced, but not defined.",
+                    abbreviation_reference.abbreviation()
+                )
+            );
+
+            return Ok(Markdown::with_content(escape(
+                abbreviation_reference.abbreviation(),
+            )));
+        };
+
+        if abbreviation_reference.display() == AbbreviationDisplay::Replace {
+            return self.render_nested_inline(&definition.expansion, context);
+        }
+
+        Ok(Markdown::with_content(format!(
+            "<abbr title=\"{}\">{}</abbr>",
+            plain_text(&definition.expansion).replace('"', "&quot;"),
+            abbreviation_reference.abbreviation()
+        )))
+    }
+
+    fn render_reference(
+        &mut self,
+        reference: &Reference,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let Some((target, text)) = context.resolve_reference(reference) else {
+            return Ok(Markdown::with_content(escape(&reference.as_unimarkup())));
+        };
+
+        // Only elements with an explicit ID have an anchor, headings are linked by their generated slug
+        let fragment = if target.generated_id {
+            heading_slug(&text)
+        } else {
+            target.id.clone()
+        };
+
+        Ok(Markdown::with_content(format!(
+            "[{}](#{})",
+            escape(&text),
+            fragment
+        )))
+    }
+
+    fn render_named_substitution(
+        &mut self,
+        named_substitution: &NamedSubstitution,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let content = match named_substitution.subst() {
+            Some(subst) => subst.to_string(),
+            None => {
+                let span = named_substitution.span();
+                log!(
+                    GeneralWarning::UnknownNamedSubstitution,
+                    format!(
+                        "Named substitution '{}' at {}:{}-{}:{} has an unknown alias.",
+                        named_substitution.alias(),
+                        span.start.line,
+                        span.start.col_utf8,
+                        span.end.line,
+                        span.end.col_utf8,
+                    )
+                );
+
+                named_substitution.as_unimarkup()
+            }
+        };
+
+        Ok(Markdown::with_content(escape(&content)))
+    }
+
+    fn render_direct_unicode(
+        &mut self,
+        direct_unicode: &DirectUnicode,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        Ok(Markdown::with_content(escape(
+            &direct_unicode.character().to_string(),
+        )))
+    }
+
+    fn render_implicit_substitution(
+        &mut self,
+        implicit_substitution: &ImplicitSubstitution,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        Ok(Markdown::with_content(escape(
+            implicit_substitution.subst(),
+        )))
+    }
+
+    fn render_footnotes(&mut self, context: &Context) -> Result<Markdown, RenderError> {
+        let mut md = Markdown::default();
+
+        for (index, footnote) in context.doc.footnotes.iter().enumerate() {
+            let number = index + 1;
+
+            // Unreferenced footnotes are not shown by Markdown viewers anyways
+            if !self.referenced_footnotes.contains(&number) {
+                continue;
+            }
+
+            let content = self.render_footnote_content(footnote, context)?;
+            md.append(Markdown::block(format!("[^{number}]: {content}")))?;
+        }
+
+        for (index, citation) in self.citations.iter().enumerate() {
+            md.append(Markdown::block(format!(
+                "[^cite-{}]: {citation}",
+                index + 1
+            )))?;
+        }
+
+        Ok(md)
+    }
+
+    fn render_bibliography(&mut self, context: &Context) -> Result<Markdown, RenderError> {
+        match &context.bibliography {
+            Some(bibliography) => {
+                let bibliography_string = if context.get_lang().id.language
+                    == unimarkup_commons::config::icu_locid::subtags::language!("de")
+                {
+                    "Literaturverzeichnis"
+                } else {
+                    "Bibliography"
+                };
+
+                let mut md = Markdown::block(format!("# {bibliography_string}"));
+                // The bibliography is rendered as HTML by citeproc
+                md.append(Markdown::block(bibliography))?;
+
+                Ok(md)
+            }
+            None => Ok(Markdown::default()),
+        }
+    }
+
+    fn render_glossary(&mut self, context: &Context) -> Result<Markdown, RenderError> {
+        if !context.get_config().preamble.render.glossary || context.doc.abbreviations.is_empty() {
+            return Ok(Markdown::default());
+        }
+
+        let mut abbreviations: Vec<_> = context.doc.abbreviations.iter().collect();
+        abbreviations.sort_by_key(|abbr| abbr.abbreviation.to_lowercase());
+
+        let mut entries = Vec::with_capacity(abbreviations.len());
+
+        for abbreviation in abbreviations {
+            let expansion = self.render_nested_inline(&abbreviation.expansion, context)?;
+            entries.push(format!(
+                "- **{}**: {}",
+                escape(&abbreviation.abbreviation),
+                expansion.content
+            ));
+        }
+
+        Ok(Markdown::block(entries.join("\n")))
+    }
+
+    fn render_bold(&mut self, bold: &Bold, context: &Context) -> Result<Markdown, RenderError> {
+        self.render_delimited("**", bold.inner(), context)
+    }
+
+    fn render_italic(
+        &mut self,
+        italic: &Italic,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        self.render_delimited("*", italic.inner(), context)
+    }
+
+    fn render_underline(
+        &mut self,
+        underline: &Underline,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        self.render_html_inline("ins", None, underline.inner(), context)
+    }
+
+    fn render_subscript(
+        &mut self,
+        subscript: &Subscript,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        self.render_html_inline("sub", None, subscript.inner(), context)
+    }
+
+    fn render_superscript(
+        &mut self,
+        superscript: &Superscript,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        self.render_html_inline("sup", None, superscript.inner(), context)
+    }
+
+    fn render_overline(
+        &mut self,
+        overline: &Overline,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        self.render_html_inline(
+            "span",
+            Some("text-decoration: overline;"),
+            overline.inner(),
+            context,
+        )
+    }
+
+    fn render_strikethrough(
+        &mut self,
+        strikethrough: &Strikethrough,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        self.render_delimited("~~", strikethrough.inner(), context)
+    }
+
+    fn render_highlight(
+        &mut self,
+        highlight: &Highlight,
+        context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        self.render_html_inline("mark", None, highlight.inner(), context)
+    }
+
+    fn render_quote(&mut self, quote: &Quote, context: &Context) -> Result<Markdown, RenderError> {
+        self.render_html_inline("q", None, quote.inner(), context)
+    }
+
+    fn render_inline_verbatim(
+        &mut self,
+        verbatim: &Verbatim,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        let content = verbatim.inner().as_unimarkup();
+        let ticks = "`".repeat(longest_run(&content, '`') + 1);
+
+        // Spaces are needed if the content starts or ends with a backtick
+        let padding = if content.starts_with('`') || content.ends_with('`') {
+            " "
+        } else {
+            ""
+        };
+
+        Ok(Markdown::with_content(format!(
+            "{ticks}{padding}{content}{padding}{ticks}"
+        )))
+    }
+
+    fn render_inline_math(
+        &mut self,
+        math: &Math,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        Ok(Markdown::with_content(format!(
+            "${}$",
+            math.inner().as_unimarkup()
+        )))
+    }
+
+    fn render_inline_comment(
+        &mut self,
+        comment: &Comment,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        Ok(Markdown::with_content(markdown_comment(comment.content())))
+    }
+
+    fn render_plain(&mut self, plain: &Plain, _context: &Context) -> Result<Markdown, RenderError> {
+        Ok(Markdown::with_content(escape(plain.content())))
+    }
+
+    fn render_newline(
+        &mut self,
+        _newline: &Newline,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        Ok(Markdown::with_content("\n"))
+    }
+
+    fn render_implicit_newline(
+        &mut self,
+        _implicit_newline: &Newline,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        Ok(Markdown::with_content("\\\n"))
+    }
+
+    fn render_escaped_newline(
+        &mut self,
+        _escaped_newline: &EscapedNewline,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        Ok(Markdown::with_content("\\\n"))
+    }
+
+    fn render_escaped_whitespace(
+        &mut self,
+        _escaped_whitespace: &EscapedWhitespace,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        Ok(Markdown::with_content("&nbsp;"))
+    }
+
+    fn render_escaped_plain(
+        &mut self,
+        escaped_plain: &EscapedPlain,
+        _context: &Context,
+    ) -> Result<Markdown, RenderError> {
+        Ok(Markdown::with_content(escape(escaped_plain.content())))
+    }
+}
+
+/// Escapes characters in the given content that would otherwise be interpreted as Markdown syntax.
+fn escape(content: &str) -> String {
+    let mut escaped = String::with_capacity(content.len());
+
+    for c in content.chars() {
+        if ESCAPED_CHARS.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// Escapes characters at the start of the lines of the given block content,
+/// that would otherwise start a heading, list, or thematic break.
+fn escape_line_starts(content: &str) -> String {
+    content
+        .split('\n')
+        .map(|line| {
+            let digits = line.chars().take_while(char::is_ascii_digit).count();
+
+            match line[digits..].chars().next() {
+                // Ordered list markers are only recognized with up to nine digits
+                Some('.' | ')') if (1..=9).contains(&digits) => {
+                    format!("{}\\{}", &line[..digits], &line[digits..])
+                }
+                Some('#' | '-' | '+' | '=') if digits == 0 => format!("\\{line}"),
+                _ => line.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Sets an empty HTML anchor with the given ID before the rendered element,
+/// because Markdown has no syntax to set IDs.
+///
+/// Blocks get the anchor in a separate paragraph, so it does not change how the block is parsed.
+fn with_anchor(id: &str, rendered: Markdown) -> Markdown {
+    let anchor = format!(
+        "<a id=\"{}\"></a>",
+        id.replace('&', "&amp;").replace('"', "&quot;")
+    );
+
+    if rendered.content.ends_with("\n\n") {
+        Markdown::with_content(format!("{anchor}\n\n{}", rendered.content))
+    } else {
+        Markdown::with_content(format!("{anchor}{}", rendered.content))
+    }
+}
+
+/// Returns the ID that GitHub flavored Markdown generates for a heading with the given title.
+///
+/// Letters are lowercased, spaces are replaced with `-`, and all punctuation except `-` and `_` is removed.
+fn heading_slug(title: &str) -> String {
+    title
+        .trim()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            '-' | '_' => Some(c),
+            c if c.is_alphanumeric() => Some(c),
+            _ => None,
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Creates an HTML comment with the given content, because Markdown has no comments.
+fn markdown_comment(content: &str) -> String {
+    format!("<!-- {} -->", content.trim().replace("--", "- -"))
+}
+
+/// Returns the length of the longest contiguous run of the given character in the content.
+fn longest_run(content: &str, c: char) -> usize {
+    content
+        .split(|other| other != c)
+        .map(str::len)
+        .max()
+        .unwrap_or_default()
+}
+
+/// Returns a code fence that is longer than any backtick sequence in the given content.
+fn code_fence(content: &str) -> String {
+    "`".repeat((longest_run(content, '`') + 1).max(3))
+}
+
+/// Prefixes every line of the given content with `prefix`, and the first line with `first_prefix`.
+/// Empty lines are only prefixed with the trimmed prefix to prevent trailing whitespace.
+pub(crate) fn prefix_lines(content: &str, first_prefix: &str, prefix: &str) -> String {
+    content
+        .split('\n')
+        .enumerate()
+        .map(|(index, line)| {
+            let prefix = if index == 0 { first_prefix } else { prefix };
+
+            if line.is_empty() {
+                prefix.trim_end().to_string()
+            } else {
+                format!("{prefix}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Creates one row of a GFM table with the given number of columns.
+/// Missing cells are left empty.
+fn table_row(cells: &[String], column_count: usize) -> String {
+    let cells = (0..column_count)
+        .map(|index| match cells.get(index) {
+            Some(cell) if !cell.is_empty() => format!(" {cell} "),
+            _ => " ".to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    format!("|{}|", cells.join("|"))
+}
+
+/// Joins rendered list entries.
+/// Entries of loose lists are separated by blank lines, entries of tight lists by newlines.
+fn join_entries(entries: &[String], loose: bool) -> String {
+    let separator = if loose { "\n\n" } else { "\n" };
+    entries.join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use unimarkup_commons::config::Config;
+    use unimarkup_parser::{document::Document, parse_unimarkup};
+
+    use crate::html::citeproc::get_csl_data;
+    use crate::markdown::Markdown;
+    use crate::render::{Context, OutputFormat, Renderer};
+
+    use super::MarkdownRenderer;
+
+    /// Renders the given document to Markdown with the given pre-rendered citations and bibliography,
+    /// because citeproc is not needed to test the Markdown structure.
+    fn render_with_context(
+        doc: &Document,
+        rendered_citations: Vec<String>,
+        bibliography: Option<String>,
+    ) -> String {
+        let context = Context {
+            doc,
+            rendered_citations,
+            footnotes: None,
+            bibliography,
+            csl_data: get_csl_data(&HashSet::new()),
+        };
+        let mut renderer = MarkdownRenderer::default();
+        let mut md = Markdown::new(&context);
+
+        md.append(renderer.render_blocks(&doc.blocks, &context).unwrap())
+            .unwrap();
+        md.append(renderer.render_footnotes(&context).unwrap())
+            .unwrap();
+        md.append(renderer.render_bibliography(&context).unwrap())
+            .unwrap();
+        md.append(renderer.render_glossary(&context).unwrap())
+            .unwrap();
+
+        md.to_string()
+    }
+
+    fn render(input: &str) -> String {
+        render_with_context(&parse_unimarkup(input, Config::default()), vec![], None)
+    }
+
+    #[test]
+    fn test_footnotes_numbered_by_first_reference() {
+        let md = render(
+            "Second[^^b]_ and first[^^a]_, second again[^^b]_.\n\n_[^^a] First *note*.\n\n_[^^b] Second note.\n\n_[^^unused] Unused note.\n",
+        );
+
+        assert_eq!(
+            md,
+            "Second[^1] and first[^2], second again[^1].\n\n[^1]: Second note.\n\n[^2]: First *note*.\n"
+        );
+    }
+
+    #[test]
+    fn test_citations_as_footnotes_with_bibliography() {
+        let doc = parse_unimarkup("Cited [&&id-1] and [&&id-2].\n", Config::default());
+        let md = render_with_context(
+            &doc,
+            vec!["Cook 2020".to_string(), "Doe 2021".to_string()],
+            Some("<div class=\"csl-bib-body\">Cook</div>".to_string()),
+        );
+
+        assert_eq!(
+            md,
+            "Cited [^cite-1] and [^cite-2].\n\n[^cite-1]: Cook 2020\n\n[^cite-2]: Doe 2021\n\n# Bibliography\n\n<div class=\"csl-bib-body\">Cook</div>\n"
+        );
+    }
+
+    #[test]
+    fn test_distinct_references() {
+        let doc = parse_unimarkup(
+            "&&id-1.title&& by &&id-1&& in &&id-1.issued.date-parts.0.0&&, not &&unknown.title&&.\n",
+            Config::default(),
+        );
+        let context = Context {
+            doc: &doc,
+            rendered_citations: vec![],
+            footnotes: None,
+            bibliography: None,
+            csl_data: get_csl_data(&HashSet::from([
+                "./src/html/citeproc/test_files/citation_items.csl".into(),
+            ])),
+        };
+        let mut renderer = MarkdownRenderer::default();
+
+        assert_eq!(
+            renderer
+                .render_blocks(&doc.blocks, &context)
+                .unwrap()
+                .to_string(),
+            "Some Notes on Gertrude Stein and Deixis by Cook in 1997, not &&unknown.title&&.\n"
+        );
+    }
+
+    #[test]
+    fn test_abbreviations_with_glossary() {
+        let mut config = Config::default();
+        config.preamble.render.glossary = true;
+        let doc = parse_unimarkup(
+            "Text using [::xml]_.\n\n_[::xml] Extensible Markup Language\n",
+            config,
+        );
+
+        assert_eq!(
+            render_with_context(&doc, vec![], None),
+            "Text using <abbr title=\"Extensible Markup Language\">xml</abbr>.\n\n- **xml**: Extensible Markup Language\n"
+        );
+    }
+
+    #[test]
+    fn test_table_with_spans_as_html() {
+        let md =
+            render("+---+---+---+\n| a | b | c |\n+-------+---+\n| spanned | d |\n+---+---+---+\n");
+
+        assert_eq!(
+            md,
+            "<table><tbody><tr><td>a</td><td>b</td><td>c</td></tr><tr><td colspan='2'>spanned</td><td>d</td></tr></tbody></table>\n"
+        );
+    }
+
+    #[test]
+    fn test_html_fallbacks() {
+        let md = render("Some ‾over‾ and ||highlight|| and ^super^ and __sub__ text.\n");
+
+        assert_eq!(
+            md,
+            "Some <span style=\"text-decoration: overline;\">over</span> and <mark>highlight</mark> and <sup>super</sup> and <ins>sub</ins> text.\n"
+        );
+    }
+
+    #[test]
+    fn test_escape_metacharacters() {
+        let md = render("Chars * _ ` < > | ~ $ \\\\ and [brackets\\].\n");
+
+        assert_eq!(
+            md,
+            "Chars \\* \\_ \\` \\< \\> \\| \\~ \\$ \\\\ and brackets\\].\n"
+        );
+    }
+
+    #[test]
+    fn test_escape_block_syntax_at_line_start() {
+        let md = render(
+            "1986\\. A great year.\n\n\\# No heading\n\n\\- No list\n\n- 2\\) No nested list\n",
+        );
+
+        assert_eq!(
+            md,
+            "1986\\. A great year.\n\n\\# No heading\n\n\\- No list\n\n- 2\\) No nested list\n"
+        );
+    }
+
+    #[test]
+    fn test_reference_anchors() {
+        let md = render(
+            "# Getting Started, now\n\n## Setup { \"id\": \"setup\" }\n\n$$$eq-1\nx^2\n$$$\n\nSee [##getting-started,-now]_, [##setup]_ and [##eq-1]_.\n",
+        );
+
+        assert_eq!(
+            md,
+            "# Getting Started, now\n\n<a id=\"setup\"></a>\n\n## Setup\n\n<a id=\"eq-1\"></a>\n\n```math\nx^2\n```\n\nSee [Getting Started, now](#getting-started-now), [Setup](#setup) and [1](#eq-1).\n"
+        );
+    }
+}
@@ -7,26 +7,39 @@ use serde_json::Value;
 use unimarkup_commons::config::output::OutputFormatKind;
 use unimarkup_commons::config::Config;
 use unimarkup_commons::{
+    attributes::Attributes,
     config::icu_locid::{locale, Locale},
     lexer::span::Span,
 };
-use unimarkup_inline::element::substitution::DistinctReference;
+use unimarkup_inline::element::substitution::{
+    direct::DirectUnicode, named::NamedSubstitution, DistinctReference, ImplicitSubstitution,
+};
 use unimarkup_inline::element::{
-    base::{EscapedNewline, EscapedPlain, EscapedWhitespace, Newline, Plain},
+    base::{Comment, EscapedNewline, EscapedPlain, EscapedWhitespace, Newline, Plain},
     formatting::{
         Bold, Highlight, Italic, Math, Overline, Quote, Strikethrough, Subscript, Superscript,
         Underline, Verbatim,
     },
-    textbox::{citation::Citation, hyperlink::Hyperlink, TextBox},
-    Inline,
+    textbox::{
+        abbreviation::AbbreviationReference, citation::Citation, footnote::FootnoteReference,
+        hyperlink::Hyperlink, reference::Reference, TextBox,
+    },
+    Inline, InlineElement,
 };
 use unimarkup_parser::{
     document::Document,
     elements::{
-        atomic::{Heading, Paragraph},
+        atomic::{self, Heading, HorizontalLine, LineBreak, PageBreak, Paragraph},
         blocks::Block,
+        decorations::Decorations,
+        definitions::{AbbreviationDefinition, FootnoteDefinition},
         enclosed,
-        indents::{BulletList, BulletListEntry},
+        indents::{
+            BulletList, BulletListEntry, DefinitionList, LineBlock, NumberedList,
+            NumberedListEntry, Quotation,
+        },
+        inserts::MediaInsert,
+        references::ReferenceTarget,
     },
 };
 
@@ -124,6 +137,156 @@ impl<'a> Context<'a> {
     pub fn get_config(&self) -> &Config {
         &self.doc.config
     }
+
+    /// Returns the value of the bibliography entry field the given distinct reference points to,
+    /// or `None` if the entry or field does not exist.
+    ///
+    /// The `authors` field lists the family names of all authors of the entry.
+    pub fn distinct_reference_value(
+        &self,
+        distinct_reference: &DistinctReference,
+    ) -> Option<String> {
+        let item = self
+            .csl_data
+            .items
+            .iter()
+            .find(|item| item.id.to_string() == distinct_reference.id())?;
+        let mut value = serde_json::to_value(item).ok()?;
+
+        if distinct_reference.fields().len() == 1 && distinct_reference.fields()[0] == "authors" {
+            let authors = value["author"]
+                .as_array()?
+                .iter()
+                .filter_map(|author| author["family"].as_str().or(author["literal"].as_str()))
+                .collect::<Vec<_>>();
+
+            return (!authors.is_empty()).then(|| authors.join(", "));
+        }
+
+        for field in distinct_reference.fields() {
+            value = match field.parse::<usize>() {
+                Ok(n) => value.get(n),
+                Err(_) => value.get(field.as_str()),
+            }?
+            .clone();
+        }
+
+        match value {
+            Value::Null => None,
+            Value::String(s) => Some(s),
+            // Numbers may be stored as floating point, but are integers in bibliographies
+            Value::Number(n) => Some(match n.as_f64() {
+                Some(f) if f.fract() == 0.0 && f >= 0.0 => (f as usize).to_string(),
+                _ => n.to_string(),
+            }),
+            value => Some(value.to_string()),
+        }
+    }
+
+    /// Returns the value the given distinct reference points to,
+    /// or the distinct reference as written in Unimarkup if the entry or field does not exist.
+    pub(crate) fn distinct_reference_text(&self, distinct_reference: &DistinctReference) -> String {
+        self.distinct_reference_value(distinct_reference)
+            .unwrap_or_else(|| {
+                let fields = distinct_reference.fields();
+                let source = if fields.len() == 1 && fields[0] == "authors" {
+                    format!("&&{}&&", distinct_reference.id())
+                } else {
+                    format!("&&{}.{}&&", distinct_reference.id(), fields.join("."))
+                };
+
+                log!(
+                    GeneralWarning::UnresolvedDistinctReference,
+                    format!(
+                        "Distinct reference '{source}' does not point to an existing bibliography entry field."
+                    )
+                );
+
+                source
+            })
+    }
+
+    /// Returns the element the given cross-reference points to, and the text to show for the reference.
+    /// Headings are referenced by their title, all other elements by their number,
+    /// or by their ID if they have no number.
+    ///
+    /// Logs a warning and returns `None` if no element has the referenced ID.
+    pub(crate) fn resolve_reference(
+        &self,
+        reference: &Reference,
+    ) -> Option<(&ReferenceTarget, String)> {
+        let Some(target) = self.doc.reference_target(reference.id()) else {
+            let span = reference.span();
+            log!(
+                GeneralWarning::UnresolvedReference,
+                format!(
+                    "Reference to '{}' at {}:{}-{}:{} could not be resolved, because no element has this ID.",
+                    reference.id(),
+                    span.start.line,
+                    span.start.col_utf8,
+                    span.end.line,
+                    span.end.col_utf8,
+                )
+            );
+            return None;
+        };
+
+        let text = match (&target.title, target.number) {
+            (Some(title), _) => plain_text(title),
+            (None, Some(number)) => number.to_string(),
+            (None, None) => target.id.clone(),
+        };
+
+        Some((target, text))
+    }
+}
+
+/// Name used to number decorated tables (e.g. "Table 3").
+pub const TABLE_LABEL: &str = "Table";
+/// Name used to number decorated media inserts (e.g. "Figure 3").
+pub const FIGURE_LABEL: &str = "Figure";
+/// Name used to number decorated verbatim blocks (e.g. "Listing 3").
+pub const LISTING_LABEL: &str = "Listing";
+
+/// Returns the label of a decorated element with its number (e.g. "Table 3"),
+/// or `None` if the element is not numbered.
+pub fn decoration_label(name: &str, decorations: &Decorations) -> Option<String> {
+    decorations.number.map(|number| format!("{name} {number}"))
+}
+
+/// Returns the text content of the given inlines without formatting,
+/// e.g. to be used as attribute value.
+pub fn plain_text(inlines: &[Inline]) -> String {
+    let mut text = String::new();
+
+    for inline in inlines {
+        match inline {
+            Inline::Plain(plain) => text.push_str(plain.content()),
+            Inline::EscapedPlain(escaped_plain) => text.push_str(escaped_plain.content()),
+            Inline::EscapedWhitespace(_)
+            | Inline::Newline(_)
+            | Inline::ImplicitNewline(_)
+            | Inline::EscapedNewline(_) => text.push(' '),
+            Inline::Bold(inner) => text.push_str(&plain_text(inner.inner())),
+            Inline::Italic(inner) => text.push_str(&plain_text(inner.inner())),
+            Inline::Underline(inner) => text.push_str(&plain_text(inner.inner())),
+            Inline::Subscript(inner) => text.push_str(&plain_text(inner.inner())),
+            Inline::Superscript(inner) => text.push_str(&plain_text(inner.inner())),
+            Inline::Overline(inner) => text.push_str(&plain_text(inner.inner())),
+            Inline::Strikethrough(inner) => text.push_str(&plain_text(inner.inner())),
+            Inline::Highlight(inner) => text.push_str(&plain_text(inner.inner())),
+            Inline::Quote(inner) => text.push_str(&plain_text(inner.inner())),
+            Inline::Verbatim(inner) => text.push_str(&plain_text(inner.inner())),
+            Inline::TextBox(inner) => text.push_str(&plain_text(inner.inner())),
+            Inline::Hyperlink(inner) => text.push_str(&plain_text(inner.inner())),
+            Inline::AbbreviationReference(abbreviation_reference) => {
+                text.push_str(abbreviation_reference.abbreviation())
+            }
+            other => text.push_str(&other.as_unimarkup()),
+        }
+    }
+
+    text
 }
 
 pub fn render<T: OutputFormat>(
@@ -139,6 +302,7 @@ pub fn render<T: OutputFormat>(
     // TODO: replace once logic is implemented
     t.append(renderer.render_footnotes(&context)?)?;
     t.append(renderer.render_bibliography(&context)?)?;
+    t.append(renderer.render_glossary(&context)?)?;
 
     Ok(t)
 }
@@ -202,6 +366,167 @@ pub trait Renderer<T: OutputFormat> {
         Err(RenderError::Unimplemented)
     }
 
+    /// Render a Unimarkup [`NumberedList`] to the output format `T`.
+    fn render_numbered_list(
+        &mut self,
+        _numbered_list: &NumberedList,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a Unimarkup [`NumberedListEntry`] to the output format `T`.
+    fn render_numbered_list_entry(
+        &mut self,
+        _numbered_list_entry: &NumberedListEntry,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a Unimarkup [`DefinitionList`] to the output format `T`.
+    fn render_definition_list(
+        &mut self,
+        _definition_list: &DefinitionList,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a Unimarkup [`Table`](enclosed::Table) to the output format `T`.
+    fn render_table(
+        &mut self,
+        _table: &enclosed::Table,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a Unimarkup [`Quotation`] to the output format `T`.
+    fn render_quotation(
+        &mut self,
+        _quotation: &Quotation,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a Unimarkup [`LineBlock`] to the output format `T`.
+    fn render_line_block(
+        &mut self,
+        _line_block: &LineBlock,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a Unimarkup [`MathBlock`](enclosed::MathBlock) to the output format `T`.
+    fn render_math_block(
+        &mut self,
+        _math_block: &enclosed::MathBlock,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a Unimarkup [`RenderBlock`](enclosed::RenderBlock) to the output format `T`.
+    fn render_render_block(
+        &mut self,
+        _render_block: &enclosed::RenderBlock,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a Unimarkup [`Columns`](enclosed::Columns) block to the output format `T`.
+    fn render_columns(
+        &mut self,
+        _columns: &enclosed::Columns,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a Unimarkup [`TextBlock`](enclosed::TextBlock) to the output format `T`.
+    fn render_text_block(
+        &mut self,
+        _text_block: &enclosed::TextBlock,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a Unimarkup [`MediaInsert`] to the output format `T`.
+    fn render_media_insert(
+        &mut self,
+        _media_insert: &MediaInsert,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a Unimarkup [`FootnoteDefinition`] to the output format `T`.
+    ///
+    /// **Note:** Footnote content is available at the position of its references,
+    /// and in [`Context::doc`] to render it with [`Renderer::render_footnotes()`].
+    fn render_footnote_definition(
+        &mut self,
+        _footnote_definition: &FootnoteDefinition,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a Unimarkup [`AbbreviationDefinition`] to the output format `T`.
+    ///
+    /// **Note:** The expansion is available at the position of abbreviation references,
+    /// and in [`Context::doc`] to render it with [`Renderer::render_glossary()`].
+    fn render_abbreviation_definition(
+        &mut self,
+        _abbreviation_definition: &AbbreviationDefinition,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a Unimarkup [`HorizontalLine`] to the output format `T`.
+    fn render_horizontal_line(
+        &mut self,
+        _horizontal_line: &HorizontalLine,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a Unimarkup [`LineBreak`] to the output format `T`.
+    fn render_line_break(
+        &mut self,
+        _line_break: &LineBreak,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a Unimarkup [`PageBreak`] to the output format `T`.
+    fn render_page_break(
+        &mut self,
+        _page_break: &PageBreak,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a Unimarkup [`Comment`](atomic::Comment) block to the output format `T`.
+    ///
+    /// **Note:** Only called if comments should be kept (see `--keep-comments`).
+    fn render_comment_block(
+        &mut self,
+        _comment: &atomic::Comment,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
     fn render_blankline(
         &mut self,
         _blankline: &Span,
@@ -234,6 +559,60 @@ pub trait Renderer<T: OutputFormat> {
         Err(RenderError::Unimplemented)
     }
 
+    /// Render a [`FootnoteReference`] to the output format `T`.
+    fn render_footnote_reference(
+        &mut self,
+        _footnote_reference: &FootnoteReference,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render an [`AbbreviationReference`] to the output format `T`.
+    fn render_abbreviation_reference(
+        &mut self,
+        _abbreviation_reference: &AbbreviationReference,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a cross-[`Reference`] to the output format `T`.
+    fn render_reference(
+        &mut self,
+        _reference: &Reference,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a [`NamedSubstitution`] to the output format `T`.
+    fn render_named_substitution(
+        &mut self,
+        _named_substitution: &NamedSubstitution,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render a [`DirectUnicode`] substitution to the output format `T`.
+    fn render_direct_unicode(
+        &mut self,
+        _direct_unicode: &DirectUnicode,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
+    /// Render an [`ImplicitSubstitution`] to the output format `T`.
+    fn render_implicit_substitution(
+        &mut self,
+        _implicit_substitution: &ImplicitSubstitution,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
     fn render_distinct_reference(
         &mut self,
         _distinct_reference: &DistinctReference,
@@ -250,6 +629,11 @@ pub trait Renderer<T: OutputFormat> {
         Err(RenderError::Unimplemented)
     }
 
+    /// Render the glossary of all abbreviations defined in [`Context::doc`] to the output format `T`.
+    fn render_glossary(&mut self, _context: &Context) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
     /// Render a [`Bold` formatting](unimarkup_inline::inlines::Inline) to the output format `T`.
     fn render_bold(&mut self, _bold: &Bold, _context: &Context) -> Result<T, RenderError> {
         Err(RenderError::Unimplemented)
@@ -333,6 +717,17 @@ pub trait Renderer<T: OutputFormat> {
         Err(RenderError::Unimplemented)
     }
 
+    /// Render a [`Comment`] to the output format `T`.
+    ///
+    /// **Note:** Only called if comments should be kept (see `--keep-comments`).
+    fn render_inline_comment(
+        &mut self,
+        _comment: &Comment,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Err(RenderError::Unimplemented)
+    }
+
     /// Render [`Plain` content](unimarkup_inline::inlines::Inline) to the output format `T`.
     fn render_plain(&mut self, _plain: &Plain, _context: &Context) -> Result<T, RenderError> {
         Err(RenderError::Unimplemented)
@@ -381,6 +776,17 @@ pub trait Renderer<T: OutputFormat> {
 
     //----------------------------- GENERIC ELEMENTS -----------------------------
 
+    /// Applies the [`Attributes`] of a block or inline element to its rendered output `T`. <br>
+    /// By default, attributes are ignored and the rendered output is returned unchanged.
+    fn render_attributes(
+        &mut self,
+        rendered: T,
+        _attributes: &Attributes,
+        _context: &Context,
+    ) -> Result<T, RenderError> {
+        Ok(rendered)
+    }
+
     /// Render Unimarkup [`Block`s](Block) to the output format `T`.
     fn render_blocks(&mut self, blocks: &[Block], context: &Context) -> Result<T, RenderError> {
         let mut t = self.get_target()?;
@@ -408,12 +814,42 @@ pub trait Renderer<T: OutputFormat> {
 
     /// Render a Unimarkup [`Block`] to the output format `T`.
     fn render_block(&mut self, block: &Block, context: &Context) -> Result<T, RenderError> {
-        match block {
+        let rendered = match block {
             Block::Heading(heading) => self.render_heading(heading, context),
             Block::Paragraph(paragraph) => self.render_paragraph(paragraph, context),
             Block::VerbatimBlock(verbatim) => self.render_verbatim_block(verbatim, context),
             Block::BulletList(bullet_list) => self.render_bullet_list(bullet_list, context),
+            Block::NumberedList(numbered_list) => self.render_numbered_list(numbered_list, context),
+            Block::DefinitionList(definition_list) => {
+                self.render_definition_list(definition_list, context)
+            }
+            Block::Table(table) => self.render_table(table, context),
+            Block::Quotation(quotation) => self.render_quotation(quotation, context),
+            Block::LineBlock(line_block) => self.render_line_block(line_block, context),
+            Block::MathBlock(math_block) => self.render_math_block(math_block, context),
+            Block::RenderBlock(render_block) => self.render_render_block(render_block, context),
+            Block::FootnoteDefinition(footnote_definition) => {
+                self.render_footnote_definition(footnote_definition, context)
+            }
+            Block::AbbreviationDefinition(abbreviation_definition) => {
+                self.render_abbreviation_definition(abbreviation_definition, context)
+            }
+            Block::Columns(columns) => self.render_columns(columns, context),
+            Block::TextBlock(text_block) => self.render_text_block(text_block, context),
+            Block::MediaInsert(media_insert) => self.render_media_insert(media_insert, context),
+            Block::HorizontalLine(horizontal_line) => {
+                self.render_horizontal_line(horizontal_line, context)
+            }
+            Block::LineBreak(line_break) => self.render_line_break(line_break, context),
+            Block::PageBreak(page_break) => self.render_page_break(page_break, context),
             Block::Blankline(blankline) => self.render_blankline(blankline, context),
+            Block::Comment(comment) => {
+                if context.get_config().preamble.render.keep_comments {
+                    self.render_comment_block(comment, context)
+                } else {
+                    Ok(T::default())
+                }
+            }
             Block::BulletListEntry(_) => {
                 debug_assert!(
                     false,
@@ -421,6 +857,18 @@ pub trait Renderer<T: OutputFormat> {
                 );
                 Err(RenderError::Unimplemented)
             }
+            Block::NumberedListEntry(_) => {
+                debug_assert!(
+                    false,
+                    "Numbered list entries are rendered directly inside a numbered list."
+                );
+                Err(RenderError::Unimplemented)
+            }
+        }?;
+
+        match block.attributes() {
+            Some(attributes) => self.render_attributes(rendered, attributes, context),
+            None => Ok(rendered),
         }
     }
 
@@ -451,7 +899,7 @@ pub trait Renderer<T: OutputFormat> {
 
     /// Render a Unimarkup [`Inline`] to the output format `T`.
     fn render_inline(&mut self, inline: &Inline, context: &Context) -> Result<T, RenderError> {
-        match inline {
+        let rendered = match inline {
             Inline::Bold(bold) => self.render_bold(bold, context),
             Inline::Italic(italic) => self.render_italic(italic, context),
             Inline::Underline(underline) => self.render_underline(underline, context),
@@ -482,13 +930,39 @@ pub trait Renderer<T: OutputFormat> {
             Inline::TextBox(textbox) => self.render_textbox(textbox, context),
             Inline::Hyperlink(hyperlink) => self.render_hyperlink(hyperlink, context),
             Inline::Citation(citation) => self.render_citation(citation, context),
+            Inline::FootnoteReference(footnote_reference) => {
+                self.render_footnote_reference(footnote_reference, context)
+            }
+            Inline::AbbreviationReference(abbreviation_reference) => {
+                self.render_abbreviation_reference(abbreviation_reference, context)
+            }
+            Inline::Reference(reference) => self.render_reference(reference, context),
 
             Inline::DistinctReference(distinct_reference) => {
                 self.render_distinct_reference(distinct_reference, context)
             }
-            Inline::NamedSubstitution(_) => todo!(),
-            Inline::ImplicitSubstitution(_) => todo!(),
+            Inline::NamedSubstitution(named_substitution) => {
+                self.render_named_substitution(named_substitution, context)
+            }
+            Inline::DirectUnicode(direct_unicode) => {
+                self.render_direct_unicode(direct_unicode, context)
+            }
+            Inline::ImplicitSubstitution(implicit_substitution) => {
+                self.render_implicit_substitution(implicit_substitution, context)
+            }
+            Inline::Comment(comment) => {
+                if context.get_config().preamble.render.keep_comments {
+                    self.render_inline_comment(comment, context)
+                } else {
+                    Ok(T::default())
+                }
+            }
             Inline::DirectUri(_) => todo!(),
+        }?;
+
+        match inline.attributes() {
+            Some(attributes) => self.render_attributes(rendered, attributes, context),
+            None => Ok(rendered),
         }
     }
 
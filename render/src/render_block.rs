@@ -0,0 +1,54 @@
+//! Contains the [`RenderBlockHandler`] trait and the [`RenderBlockHandlers`] registry
+//! that renderers use to render the content of render blocks.
+
+use unimarkup_parser::elements::enclosed::RenderBlock;
+
+use crate::{
+    log_id::RenderError,
+    render::{Context, OutputFormat},
+};
+
+/// Trait to implement to render the content of [`RenderBlock`]s written in some language to the output format `T`.
+pub trait RenderBlockHandler<T: OutputFormat>: std::fmt::Debug {
+    /// Returns `true` if this handler is able to render content written in the given language.
+    fn handles(&self, lang: &str) -> bool;
+
+    /// Renders the given render block to the output format `T`.
+    fn render(&self, render_block: &RenderBlock, context: &Context) -> Result<T, RenderError>;
+}
+
+/// Registry of [`RenderBlockHandler`]s for the output format `T`.
+#[derive(Debug)]
+pub struct RenderBlockHandlers<T: OutputFormat> {
+    handlers: Vec<Box<dyn RenderBlockHandler<T>>>,
+}
+
+impl<T: OutputFormat> RenderBlockHandlers<T> {
+    /// Creates a registry without any handlers.
+    pub fn new() -> Self {
+        RenderBlockHandlers {
+            handlers: Vec::new(),
+        }
+    }
+
+    /// Adds the given handler to the registry.
+    /// Handlers registered later take precedence over earlier ones for the same language.
+    pub fn register(&mut self, handler: impl RenderBlockHandler<T> + 'static) {
+        self.handlers.push(Box::new(handler));
+    }
+
+    /// Returns the handler responsible for the given language, if any.
+    pub fn get(&self, lang: &str) -> Option<&dyn RenderBlockHandler<T>> {
+        self.handlers
+            .iter()
+            .rev()
+            .find(|handler| handler.handles(lang))
+            .map(|handler| handler.as_ref())
+    }
+}
+
+impl<T: OutputFormat> Default for RenderBlockHandlers<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
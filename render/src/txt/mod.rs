@@ -0,0 +1,61 @@
+//! Defines the [`PlainText`] struct that is returned when rendering Unimarkup to plain text.
+//!
+//! Plain text is meant to be read as is (e.g. in emails or terminal previews),
+//! so formatting is dropped and lines are wrapped at the configured text width.
+
+use crate::render::{Context, OutputFormat};
+
+pub mod render;
+
+/// Plain text rendered from Unimarkup.
+///
+/// Rendered blocks end with a blank line, so blocks may be appended without additional separation.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PlainText {
+    pub content: String,
+}
+
+impl PlainText {
+    /// Creates plain text with the given content.
+    pub fn with_content(content: impl Into<String>) -> Self {
+        PlainText {
+            content: content.into(),
+        }
+    }
+
+    /// Creates a plain text block with the given content.
+    /// The content is terminated with a blank line to separate it from following blocks.
+    pub fn block(content: impl AsRef<str>) -> Self {
+        let content = content.as_ref().trim_end_matches('\n');
+
+        if content.is_empty() {
+            return PlainText::default();
+        }
+
+        PlainText::with_content(format!("{content}\n\n"))
+    }
+}
+
+impl OutputFormat for PlainText {
+    fn new(_context: &Context) -> Self {
+        PlainText::default()
+    }
+
+    fn append(&mut self, other: Self) -> Result<(), crate::log_id::RenderError> {
+        self.content.push_str(&other.content);
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for PlainText {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let content = self.content.trim_end_matches('\n');
+
+        if content.is_empty() {
+            Ok(())
+        } else {
+            writeln!(f, "{content}")
+        }
+    }
+}
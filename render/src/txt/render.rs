@@ -0,0 +1,1232 @@
+use std::collections::HashSet;
+
+use logid::log;
+use unimarkup_commons::lexer::span::Span;
+use unimarkup_inline::element::substitution::{
+    direct::DirectUnicode, named::NamedSubstitution, DistinctReference, ImplicitSubstitution,
+};
+use unimarkup_inline::element::{
+    base::{Comment, EscapedNewline, EscapedPlain, EscapedWhitespace, Newline, Plain},
+    formatting::{
+        Bold, Highlight, Italic, Math, Overline, Quote, Strikethrough, Subscript, Superscript,
+        Underline, Verbatim,
+    },
+    textbox::{
+        abbreviation::{AbbreviationDisplay, AbbreviationReference},
+        citation::Citation,
+        footnote::FootnoteReference,
+        hyperlink::Hyperlink,
+        reference::Reference,
+        TextBox,
+    },
+    Inline, InlineElement,
+};
+use unimarkup_parser::elements::{
+    atomic::{self, Heading, HeadingLevel, HorizontalLine, LineBreak, PageBreak, Paragraph},
+    blocks::Block,
+    decorations::Decorations,
+    definitions::{AbbreviationDefinition, FootnoteDefinition},
+    enclosed::{Columns, MathBlock, RenderBlock, Table, TableRow, TextBlock, VerbatimBlock},
+    indents::{
+        BulletList, BulletListEntry, DefinitionList, EnumerationKind, LineBlock, NumberedList,
+        NumberedListEntry, Quotation, TaskStatus,
+    },
+    inserts::{MediaInsert, MediaKind},
+};
+
+use crate::log_id::{GeneralWarning, RenderError};
+use crate::markdown::render::prefix_lines;
+use crate::render::{
+    decoration_label, Context, OutputFormat, Renderer, FIGURE_LABEL, LISTING_LABEL, TABLE_LABEL,
+};
+
+use super::PlainText;
+
+/// Line width that is used if no text width is configured.
+const DEFAULT_TEXT_WIDTH: usize = 80;
+
+/// Minimal line width for nested blocks, so deeply nested content stays readable.
+const MIN_TEXT_WIDTH: usize = 20;
+
+/// Non-breaking space used for escaped whitespace.
+/// It prevents wrapping, and is replaced by a regular space after wrapping.
+const NBSP: char = '\u{a0}';
+
+#[derive(Debug, Default)]
+pub struct PlainTextRenderer {
+    /// Number of columns used by the indentation of the currently rendered blocks.
+    indent: usize,
+    /// Targets of rendered hyperlinks.
+    /// Links are numbered by their position in this list.
+    links: Vec<String>,
+    /// Numbers of footnotes that were already referenced.
+    referenced_footnotes: HashSet<usize>,
+}
+
+impl PlainTextRenderer {
+    /// Returns the available line width for the currently rendered blocks,
+    /// or `None` if lines must not be wrapped.
+    fn width(&self, context: &Context) -> Option<usize> {
+        let width = context
+            .get_config()
+            .preamble
+            .render
+            .text_width
+            .unwrap_or(DEFAULT_TEXT_WIDTH);
+
+        if width == 0 {
+            return None;
+        }
+
+        Some(width.saturating_sub(self.indent).max(MIN_TEXT_WIDTH))
+    }
+
+    /// Renders the given blocks indented by the width of `prefix`.
+    /// The first line is prefixed with `first_prefix` instead.
+    fn render_indented(
+        &mut self,
+        blocks: &[Block],
+        first_prefix: &str,
+        prefix: &str,
+        context: &Context,
+    ) -> Result<String, RenderError> {
+        let indent = prefix.chars().count();
+        self.indent += indent;
+        let rendered = self.render_blocks(blocks, context);
+        self.indent -= indent;
+
+        Ok(prefix_lines(
+            rendered?.content.trim_end(),
+            first_prefix,
+            prefix,
+        ))
+    }
+
+    /// Renders the given inlines, and wraps them indented by the width of `prefix`.
+    /// The first line is prefixed with `first_prefix` instead.
+    fn render_wrapped(
+        &mut self,
+        inlines: &[Inline],
+        first_prefix: &str,
+        prefix: &str,
+        context: &Context,
+    ) -> Result<String, RenderError> {
+        let indent = prefix.chars().count();
+        self.indent += indent;
+        let text = self.render_inlines(inlines, context);
+        let width = self.width(context);
+        self.indent -= indent;
+
+        Ok(prefix_lines(
+            &wrap(text?.content.trim(), width),
+            first_prefix,
+            prefix,
+        ))
+    }
+
+    /// Renders the title and caption of a decorated element, prefixed with its label (e.g. "Table 3: ").
+    ///
+    /// Returns `None` if the element is not decorated.
+    fn render_decorations(
+        &mut self,
+        label: &str,
+        decorations: &Decorations,
+        context: &Context,
+    ) -> Result<Option<PlainText>, RenderError> {
+        let mut text = match decoration_label(label, decorations) {
+            Some(label) => PlainText::with_content(format!("{label}: ")),
+            None => PlainText::default(),
+        };
+
+        match (&decorations.title, &decorations.caption) {
+            (Some(title), Some(caption)) => {
+                text.append(self.render_inlines(title, context)?)?;
+                text.content.push('\n');
+                text.append(self.render_inlines(caption, context)?)?;
+            }
+            (Some(content), None) | (None, Some(content)) => {
+                text.append(self.render_inlines(content, context)?)?
+            }
+            (None, None) => return Ok(None),
+        }
+
+        Ok(Some(PlainText::block(wrap(
+            &text.content,
+            self.width(context),
+        ))))
+    }
+
+    /// Places the decorations of an element around the given content.
+    /// Decorations are placed before the content if a title is set, and after the content otherwise.
+    fn render_decorated(
+        &mut self,
+        content: PlainText,
+        label: &str,
+        decorations: &Decorations,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        let Some(decorations_text) = self.render_decorations(label, decorations, context)? else {
+            return Ok(content);
+        };
+
+        if decorations.title.is_some() {
+            let mut text = decorations_text;
+            text.append(content)?;
+            Ok(text)
+        } else {
+            let mut text = content;
+            text.append(decorations_text)?;
+            Ok(text)
+        }
+    }
+
+    /// Renders the content of all cells of the given row in one line per cell.
+    fn render_table_cells(
+        &mut self,
+        row: &TableRow,
+        context: &Context,
+    ) -> Result<Vec<String>, RenderError> {
+        let mut cells = Vec::with_capacity(row.cells.len());
+
+        for cell in &row.cells {
+            let mut content = Vec::new();
+
+            for block in &cell.content {
+                let rendered = match block {
+                    Block::Paragraph(paragraph) => {
+                        self.render_inlines(&paragraph.content, context)?
+                    }
+                    block => self.render_block(block, context)?,
+                };
+                content.push(
+                    rendered
+                        .content
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                );
+            }
+
+            cells.push(content.join(" "));
+        }
+
+        Ok(cells)
+    }
+}
+
+impl Renderer<PlainText> for PlainTextRenderer {
+    fn get_target(&mut self) -> Result<PlainText, RenderError> {
+        Ok(PlainText::default())
+    }
+
+    fn render_paragraph(
+        &mut self,
+        paragraph: &Paragraph,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        Ok(PlainText::block(self.render_wrapped(
+            &paragraph.content,
+            "",
+            "",
+            context,
+        )?))
+    }
+
+    fn render_heading(
+        &mut self,
+        heading: &Heading,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        let text = self.render_inlines(&heading.content, context)?.content;
+        // Headings must be in one line to be underlined
+        let text = text
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .replace(NBSP, " ");
+
+        let underline = match heading.level {
+            HeadingLevel::Level1 => "=",
+            _ => "-",
+        };
+
+        Ok(PlainText::block(format!(
+            "{text}\n{}",
+            underline.repeat(text.chars().count())
+        )))
+    }
+
+    fn render_verbatim_block(
+        &mut self,
+        verbatim: &VerbatimBlock,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        self.render_decorated(
+            PlainText::block(prefix_lines(&verbatim.content, "    ", "    ")),
+            LISTING_LABEL,
+            &verbatim.decorations,
+            context,
+        )
+    }
+
+    fn render_render_block(
+        &mut self,
+        render_block: &RenderBlock,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        Ok(PlainText::block(prefix_lines(
+            &render_block.content,
+            "    ",
+            "    ",
+        )))
+    }
+
+    fn render_bullet_list(
+        &mut self,
+        bullet_list: &BulletList,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        let mut entries = Vec::with_capacity(bullet_list.entries.len());
+        let mut loose = false;
+
+        for entry in &bullet_list.entries {
+            loose |= !entry.body.is_empty();
+            entries.push(self.render_bullet_list_entry(entry, context)?.content);
+        }
+
+        Ok(PlainText::block(join_entries(&entries, loose)))
+    }
+
+    fn render_bullet_list_entry(
+        &mut self,
+        bullet_list_entry: &BulletListEntry,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        let marker = match bullet_list_entry.task_status() {
+            Some(TaskStatus::Completed) => "- [x] ",
+            Some(_) => "- [ ] ",
+            None => "- ",
+        };
+        let indent = " ".repeat(marker.len());
+
+        let mut entry =
+            self.render_wrapped(&bullet_list_entry.heading, marker, &indent, context)?;
+
+        if !bullet_list_entry.body.is_empty() {
+            entry.push_str("\n\n");
+            entry.push_str(&self.render_indented(
+                &bullet_list_entry.body,
+                &indent,
+                &indent,
+                context,
+            )?);
+        }
+
+        Ok(PlainText::with_content(entry))
+    }
+
+    fn render_numbered_list(
+        &mut self,
+        numbered_list: &NumberedList,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        let mut entries = Vec::with_capacity(numbered_list.entries.len());
+        let mut loose = false;
+
+        for (index, entry) in numbered_list.entries.iter().enumerate() {
+            let enumerator = enumerator(numbered_list.kind, numbered_list.start_number + index);
+            let marker = format!("{} ", numbered_list.delimiter.enclose(&enumerator));
+            let indent = " ".repeat(marker.chars().count());
+
+            let mut rendered = self.render_wrapped(&entry.heading, &marker, &indent, context)?;
+
+            if !entry.body.is_empty() {
+                loose = true;
+                rendered.push_str("\n\n");
+                rendered.push_str(&self.render_indented(&entry.body, &indent, &indent, context)?);
+            }
+
+            entries.push(rendered);
+        }
+
+        Ok(PlainText::block(join_entries(&entries, loose)))
+    }
+
+    fn render_numbered_list_entry(
+        &mut self,
+        numbered_list_entry: &NumberedListEntry,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        let marker = format!("{} ", numbered_list_entry.keyword.as_string());
+        let indent = " ".repeat(marker.chars().count());
+
+        let mut entry =
+            self.render_wrapped(&numbered_list_entry.heading, &marker, &indent, context)?;
+
+        if !numbered_list_entry.body.is_empty() {
+            entry.push_str("\n\n");
+            entry.push_str(&self.render_indented(
+                &numbered_list_entry.body,
+                &indent,
+                &indent,
+                context,
+            )?);
+        }
+
+        Ok(PlainText::with_content(entry))
+    }
+
+    fn render_definition_list(
+        &mut self,
+        definition_list: &DefinitionList,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        let mut entries = Vec::with_capacity(definition_list.entries.len());
+
+        for entry in &definition_list.entries {
+            let mut term = self.render_wrapped(&entry.term, "", "", context)?;
+
+            if let Some(classifier) = &entry.classifier {
+                let classifier = self.render_inlines(classifier, context)?;
+                term.push_str(&format!(" ({})", classifier.content.trim()));
+            }
+
+            if !entry.description.is_empty() {
+                term.push('\n');
+                term.push_str(&self.render_indented(
+                    &entry.description,
+                    "    ",
+                    "    ",
+                    context,
+                )?);
+            }
+
+            entries.push(term);
+        }
+
+        Ok(PlainText::block(entries.join("\n\n")))
+    }
+
+    fn render_table(&mut self, table: &Table, context: &Context) -> Result<PlainText, RenderError> {
+        let mut rows = Vec::with_capacity(table.rows.len());
+        for row in &table.rows {
+            rows.push(self.render_table_cells(row, context)?);
+        }
+
+        // Spanning cells are shown in the column they start in
+        let mut widths = Vec::new();
+        for row in &rows {
+            for (index, cell) in row.iter().enumerate() {
+                let width = cell.chars().count();
+                match widths.get_mut(index) {
+                    Some(max) if *max < width => *max = width,
+                    Some(_) => {}
+                    None => widths.push(width),
+                }
+            }
+        }
+
+        let format_row = |row: &[String]| {
+            let cells = row
+                .iter()
+                .enumerate()
+                .map(|(index, cell)| format!("{cell:<width$}", width = widths[index]))
+                .collect::<Vec<_>>();
+
+            cells.join(" | ").trim_end().to_string()
+        };
+
+        let head_len = table.head().len();
+        let foot_start = table.rows.len() - table.foot().len();
+        let separator = widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<_>>()
+            .join("-+-");
+
+        let mut lines = Vec::with_capacity(rows.len() + 2);
+        for (index, row) in rows.iter().enumerate() {
+            if (index == foot_start && foot_start > head_len) || (index > 0 && index == head_len) {
+                lines.push(separator.clone());
+            }
+            lines.push(format_row(row));
+        }
+
+        self.render_decorated(
+            PlainText::block(lines.join("\n")),
+            TABLE_LABEL,
+            &table.decorations,
+            context,
+        )
+    }
+
+    fn render_quotation(
+        &mut self,
+        quotation: &Quotation,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        let mut content = self.render_indented(&quotation.content, "> ", "> ", context)?;
+
+        if let Some(author) = &quotation.author {
+            content.push_str("\n>\n");
+            content.push_str(&self.render_wrapped(author, "> — ", ">   ", context)?);
+        }
+
+        Ok(PlainText::block(content))
+    }
+
+    fn render_line_block(
+        &mut self,
+        line_block: &LineBlock,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        // Newlines inside line blocks are already rendered as line breaks
+        self.render_blocks(&line_block.content, context)
+    }
+
+    fn render_math_block(
+        &mut self,
+        math_block: &MathBlock,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        Ok(PlainText::block(prefix_lines(
+            math_block.content.trim(),
+            "    ",
+            "    ",
+        )))
+    }
+
+    fn render_columns(
+        &mut self,
+        columns: &Columns,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        // Plain text has no layout, so columns are rendered one after another
+        let mut text = PlainText::default();
+
+        for column in &columns.columns {
+            text.append(self.render_blocks(column, context)?)?;
+        }
+
+        Ok(text)
+    }
+
+    fn render_text_block(
+        &mut self,
+        text_block: &TextBlock,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        self.render_blocks(&text_block.content, context)
+    }
+
+    fn render_media_insert(
+        &mut self,
+        media_insert: &MediaInsert,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        let kind = match media_insert.kind {
+            MediaKind::Image => "Image",
+            MediaKind::Video => "Video",
+            MediaKind::Audio => "Audio",
+        };
+
+        self.render_decorated(
+            PlainText::block(format!(
+                "[{kind}: {}] ({})",
+                media_insert.alt, media_insert.source
+            )),
+            FIGURE_LABEL,
+            &media_insert.decorations,
+            context,
+        )
+    }
+
+    fn render_footnote_definition(
+        &mut self,
+        _footnote_definition: &FootnoteDefinition,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        // Content is rendered with the other footnotes
+        Ok(PlainText::default())
+    }
+
+    fn render_abbreviation_definition(
+        &mut self,
+        _abbreviation_definition: &AbbreviationDefinition,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        // Expansion is rendered at the abbreviation reference, or in the glossary
+        Ok(PlainText::default())
+    }
+
+    fn render_horizontal_line(
+        &mut self,
+        _horizontal_line: &HorizontalLine,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        let width = self.width(context).unwrap_or(DEFAULT_TEXT_WIDTH);
+        Ok(PlainText::block("-".repeat(width)))
+    }
+
+    fn render_line_break(
+        &mut self,
+        _line_break: &LineBreak,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        // Blocks are already separated by a blank line, so one additional newline is enough
+        Ok(PlainText::with_content("\n"))
+    }
+
+    fn render_page_break(
+        &mut self,
+        _page_break: &PageBreak,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        // Form feed is the page break control character of plain text
+        Ok(PlainText::block("\u{c}"))
+    }
+
+    fn render_comment_block(
+        &mut self,
+        _comment: &atomic::Comment,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        // Plain text has no comments, so kept comments would be shown to readers
+        Ok(PlainText::default())
+    }
+
+    fn render_blankline(
+        &mut self,
+        _blankline: &Span,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        // Blocks are already separated by blank lines
+        Ok(PlainText::default())
+    }
+
+    fn render_textbox(
+        &mut self,
+        textbox: &TextBox,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        self.render_nested_inline(textbox.inner(), context)
+    }
+
+    fn render_hyperlink(
+        &mut self,
+        hyperlink: &Hyperlink,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        let inner = self.render_nested_inline(hyperlink.inner(), context)?;
+        let link = hyperlink.link().to_string();
+
+        if inner.content.trim().is_empty() || inner.content == link {
+            return Ok(PlainText::with_content(link));
+        }
+
+        // Links are shown as numbered references that are listed at the end of the document
+        let number = match self.links.iter().position(|l| *l == link) {
+            Some(index) => index + 1,
+            None => {
+                self.links.push(link);
+                self.links.len()
+            }
+        };
+
+        Ok(PlainText::with_content(format!(
+            "{}{NBSP}[{number}]",
+            inner.content
+        )))
+    }
+
+    fn render_citation(
+        &mut self,
+        citation: &Citation,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        let ids = citation
+            .entries()
+            .iter()
+            .map(|entry| entry.id())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Ok(PlainText::with_content(format!("[{ids}]")))
+    }
+
+    fn render_distinct_reference(
+        &mut self,
+        distinct_reference: &DistinctReference,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        Ok(PlainText::with_content(
+            context.distinct_reference_text(distinct_reference),
+        ))
+    }
+
+    fn render_footnote_reference(
+        &mut self,
+        footnote_reference: &FootnoteReference,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        let Some((number, _)) = context.doc.footnote(footnote_reference.id()) else {
+            log!(
+                GeneralWarning::UnresolvedFootnote,
+                format!(
+                    "Footnote '{}' is referenced, but not defined.",
+                    footnote_reference.id()
+                )
+            );
+
+            return Ok(PlainText::with_content(footnote_reference.as_unimarkup()));
+        };
+        self.referenced_footnotes.insert(number);
+
+        Ok(PlainText::with_content(format!("[^{number}]")))
+    }
+
+    fn render_abbreviation_reference(
+        &mut self,
+        abbreviation_reference: &AbbreviationReference,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        let Some(definition) = context
+            .doc
+            .abbreviation(abbreviation_reference.abbreviation())
+        else {
+            log!(
+                GeneralWarning::UnresolvedAbbreviation,
+                format!(
+                    "Abbreviation '{}' is referenced, but not defined.",
+                    abbreviation_reference.abbreviation()
+                )
+            );
+
+            return Ok(PlainText::with_content(
+                abbreviation_reference.abbreviation(),
+            ));
+        };
+
+        if abbreviation_reference.display() == AbbreviationDisplay::Replace {
+            return self.render_nested_inline(&definition.expansion, context);
+        }
+
+        Ok(PlainText::with_content(
+            abbreviation_reference.abbreviation(),
+        ))
+    }
+
+    fn render_reference(
+        &mut self,
+        reference: &Reference,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        let Some((_, text)) = context.resolve_reference(reference) else {
+            return Ok(PlainText::with_content(reference.as_unimarkup()));
+        };
+
+        Ok(PlainText::with_content(text))
+    }
+
+    fn render_named_substitution(
+        &mut self,
+        named_substitution: &NamedSubstitution,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        let content = match named_substitution.subst() {
+            Some(subst) => subst.to_string(),
+            None => {
+                let span = named_substitution.span();
+                log!(
+                    GeneralWarning::UnknownNamedSubstitution,
+                    format!(
+                        "Named substitution '{}' at {}:{}-{}:{} has an unknown alias.",
+                        named_substitution.alias(),
+                        span.start.line,
+                        span.start.col_utf8,
+                        span.end.line,
+                        span.end.col_utf8,
+                    )
+                );
+
+                named_substitution.as_unimarkup()
+            }
+        };
+
+        Ok(PlainText::with_content(content))
+    }
+
+    fn render_direct_unicode(
+        &mut self,
+        direct_unicode: &DirectUnicode,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        Ok(PlainText::with_content(
+            direct_unicode.character().to_string(),
+        ))
+    }
+
+    fn render_implicit_substitution(
+        &mut self,
+        implicit_substitution: &ImplicitSubstitution,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        Ok(PlainText::with_content(implicit_substitution.subst()))
+    }
+
+    fn render_footnotes(&mut self, context: &Context) -> Result<PlainText, RenderError> {
+        let mut text = PlainText::default();
+
+        for (index, footnote) in context.doc.footnotes.iter().enumerate() {
+            let number = index + 1;
+
+            if !self.referenced_footnotes.contains(&number) {
+                continue;
+            }
+
+            let marker = format!("[^{number}]: ");
+            let content = self.render_indented(&footnote.content, "", "    ", context)?;
+            text.append(PlainText::block(format!("{marker}{content}")))?;
+        }
+
+        // Links may be referenced inside footnotes, so they are listed last
+        let links = self
+            .links
+            .iter()
+            .enumerate()
+            .map(|(index, link)| format!("[{}] {link}", index + 1))
+            .collect::<Vec<_>>();
+        text.append(PlainText::block(links.join("\n")))?;
+
+        Ok(text)
+    }
+
+    fn render_bibliography(&mut self, context: &Context) -> Result<PlainText, RenderError> {
+        match &context.bibliography {
+            Some(bibliography) => {
+                let bibliography_string = if context.get_lang().id.language
+                    == unimarkup_commons::config::icu_locid::subtags::language!("de")
+                {
+                    "Literaturverzeichnis"
+                } else {
+                    "Bibliography"
+                };
+
+                let mut text = PlainText::block(format!(
+                    "{bibliography_string}\n{}",
+                    "=".repeat(bibliography_string.chars().count())
+                ));
+
+                // The bibliography is rendered as HTML by citeproc
+                let entries = strip_html(bibliography)
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(|line| wrap(line, self.width(context)))
+                    .collect::<Vec<_>>();
+                text.append(PlainText::block(entries.join("\n")))?;
+
+                Ok(text)
+            }
+            None => Ok(PlainText::default()),
+        }
+    }
+
+    fn render_glossary(&mut self, context: &Context) -> Result<PlainText, RenderError> {
+        if !context.get_config().preamble.render.glossary || context.doc.abbreviations.is_empty() {
+            return Ok(PlainText::default());
+        }
+
+        let mut abbreviations: Vec<_> = context.doc.abbreviations.iter().collect();
+        abbreviations.sort_by_key(|abbr| abbr.abbreviation.to_lowercase());
+
+        let mut entries = Vec::with_capacity(abbreviations.len());
+
+        for abbreviation in abbreviations {
+            let expansion = self.render_nested_inline(&abbreviation.expansion, context)?;
+            entries.push(format!(
+                "{}: {}",
+                abbreviation.abbreviation, expansion.content
+            ));
+        }
+
+        Ok(PlainText::block(entries.join("\n")))
+    }
+
+    fn render_bold(&mut self, bold: &Bold, context: &Context) -> Result<PlainText, RenderError> {
+        self.render_nested_inline(bold.inner(), context)
+    }
+
+    fn render_italic(
+        &mut self,
+        italic: &Italic,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        self.render_nested_inline(italic.inner(), context)
+    }
+
+    fn render_underline(
+        &mut self,
+        underline: &Underline,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        self.render_nested_inline(underline.inner(), context)
+    }
+
+    fn render_subscript(
+        &mut self,
+        subscript: &Subscript,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        self.render_nested_inline(subscript.inner(), context)
+    }
+
+    fn render_superscript(
+        &mut self,
+        superscript: &Superscript,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        self.render_nested_inline(superscript.inner(), context)
+    }
+
+    fn render_overline(
+        &mut self,
+        overline: &Overline,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        self.render_nested_inline(overline.inner(), context)
+    }
+
+    fn render_strikethrough(
+        &mut self,
+        strikethrough: &Strikethrough,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        self.render_nested_inline(strikethrough.inner(), context)
+    }
+
+    fn render_highlight(
+        &mut self,
+        highlight: &Highlight,
+        context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        self.render_nested_inline(highlight.inner(), context)
+    }
+
+    fn render_quote(&mut self, quote: &Quote, context: &Context) -> Result<PlainText, RenderError> {
+        let inner = self.render_nested_inline(quote.inner(), context)?;
+        Ok(PlainText::with_content(format!("\"{}\"", inner.content)))
+    }
+
+    fn render_inline_verbatim(
+        &mut self,
+        verbatim: &Verbatim,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        Ok(PlainText::with_content(verbatim.inner().as_unimarkup()))
+    }
+
+    fn render_inline_math(
+        &mut self,
+        math: &Math,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        Ok(PlainText::with_content(math.inner().as_unimarkup()))
+    }
+
+    fn render_inline_comment(
+        &mut self,
+        _comment: &Comment,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        // Same as comment blocks, kept comments must not be shown to readers
+        Ok(PlainText::default())
+    }
+
+    fn render_plain(
+        &mut self,
+        plain: &Plain,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        Ok(PlainText::with_content(plain.content()))
+    }
+
+    fn render_newline(
+        &mut self,
+        _newline: &Newline,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        // Lines are wrapped again, so newlines in the original content are not kept
+        Ok(PlainText::with_content(" "))
+    }
+
+    fn render_implicit_newline(
+        &mut self,
+        _implicit_newline: &Newline,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        Ok(PlainText::with_content("\n"))
+    }
+
+    fn render_escaped_newline(
+        &mut self,
+        _escaped_newline: &EscapedNewline,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        Ok(PlainText::with_content("\n"))
+    }
+
+    fn render_escaped_whitespace(
+        &mut self,
+        _escaped_whitespace: &EscapedWhitespace,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        Ok(PlainText::with_content(NBSP.to_string()))
+    }
+
+    fn render_escaped_plain(
+        &mut self,
+        escaped_plain: &EscapedPlain,
+        _context: &Context,
+    ) -> Result<PlainText, RenderError> {
+        Ok(PlainText::with_content(escaped_plain.content()))
+    }
+}
+
+/// Wraps every line of the given text at word boundaries, so no line is longer than `width`.
+/// Words longer than `width` are kept in their own line.
+///
+/// Lines are not wrapped if `width` is `None`.
+fn wrap(text: &str, width: Option<usize>) -> String {
+    let Some(width) = width else {
+        return text.replace(NBSP, " ");
+    };
+
+    let mut lines = Vec::new();
+
+    for hard_line in text.split('\n') {
+        let mut line = String::new();
+        let mut line_width = 0;
+
+        for word in hard_line.split(' ').filter(|word| !word.is_empty()) {
+            let word_width = word.chars().count();
+
+            if line_width > 0 && line_width + 1 + word_width > width {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+
+            if line_width > 0 {
+                line.push(' ');
+                line_width += 1;
+            }
+
+            line.push_str(word);
+            line_width += word_width;
+        }
+
+        lines.push(line);
+    }
+
+    lines.join("\n").replace(NBSP, " ")
+}
+
+/// Removes all HTML tags from the given content, and decodes the escaped HTML characters.
+/// Closing block tags (e.g. `</div>`) are replaced by newlines.
+fn strip_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut tag = None;
+
+    for c in html.chars() {
+        match (&mut tag, c) {
+            (None, '<') => tag = Some(String::new()),
+            (Some(name), '>') => {
+                if matches!(name.as_str(), "/div" | "/p" | "br" | "br/") {
+                    text.push('\n');
+                }
+                tag = None;
+            }
+            (Some(name), c) => name.push(c),
+            (None, c) => text.push(c),
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Returns the enumerator of the given number for the given [`EnumerationKind`].
+fn enumerator(kind: EnumerationKind, number: usize) -> String {
+    match kind {
+        EnumerationKind::Arabic => number.to_string(),
+        EnumerationKind::LowerLatin => latin(number),
+        EnumerationKind::UpperLatin => latin(number).to_uppercase(),
+        EnumerationKind::LowerRoman => roman(number).to_lowercase(),
+        EnumerationKind::UpperRoman => roman(number),
+    }
+}
+
+/// Converts the given number to latin letters (e.g. `1` to `a`, and `27` to `aa`).
+fn latin(mut number: usize) -> String {
+    let mut letters = Vec::new();
+
+    while number > 0 {
+        number -= 1;
+        letters.push((b'a' + (number % 26) as u8) as char);
+        number /= 26;
+    }
+
+    letters.iter().rev().collect()
+}
+
+/// Converts the given number to upper roman numerals.
+/// `0` is kept as arabic number, because it has no roman numeral.
+fn roman(mut number: usize) -> String {
+    const NUMERALS: [(usize, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+
+    if number == 0 {
+        return number.to_string();
+    }
+
+    let mut numerals = String::new();
+
+    for (value, numeral) in NUMERALS {
+        while number >= value {
+            numerals.push_str(numeral);
+            number -= value;
+        }
+    }
+
+    numerals
+}
+
+/// Joins rendered list entries.
+/// Entries of loose lists are separated by blank lines, entries of tight lists by newlines.
+fn join_entries(entries: &[String], loose: bool) -> String {
+    let separator = if loose { "\n\n" } else { "\n" };
+    entries.join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use unimarkup_commons::config::{output::OutputFormatKind, Config};
+    use unimarkup_parser::parse_unimarkup;
+
+    use super::PlainTextRenderer;
+
+    fn render(input: &str, text_width: usize) -> String {
+        let mut config = Config::default();
+        config.preamble.render.text_width = Some(text_width);
+        let doc = parse_unimarkup(input, config);
+
+        crate::render::render(&doc, OutputFormatKind::Txt, PlainTextRenderer::default())
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_kept_comments_and_distinct_references() {
+        let mut config = Config::default();
+        config.preamble.render.keep_comments = true;
+        config
+            .preamble
+            .cite
+            .references
+            .insert("./src/html/citeproc/test_files/citation_items.csl".into());
+        let doc = parse_unimarkup(
+            "Some ;; inline comment ;; text by &&id-1&& in [&&id-1.title&&], not &&unknown.title&&.\n",
+            config,
+        );
+        let txt = crate::render::render(&doc, OutputFormatKind::Txt, PlainTextRenderer::default())
+            .unwrap()
+            .to_string();
+
+        assert_eq!(
+            txt,
+            "Some text by Cook in Some Notes on Gertrude Stein and Deixis, not\n&&unknown.title&&.\n"
+        );
+    }
+
+    #[test]
+    fn test_wrap_at_configured_width() {
+        let input = "The quick brown fox jumps over the lazy dog and keeps running.\n";
+
+        assert_eq!(
+            render(input, 20),
+            "The quick brown fox\njumps over the lazy\ndog and keeps\nrunning.\n"
+        );
+        assert_eq!(
+            render(input, 0),
+            "The quick brown fox jumps over the lazy dog and keeps running.\n",
+            "Width `0` must disable wrapping."
+        );
+    }
+
+    #[test]
+    fn test_long_words_in_own_line() {
+        let text = render(
+            "A supercalifragilisticexpialidocious word and https://example.com/a/very/long/path here.\n",
+            16,
+        );
+
+        assert_eq!(
+            text,
+            "A\nsupercalifragilisticexpialidocious\nword and\nhttps://example.com/a/very/long/path\nhere.\n"
+        );
+    }
+
+    #[test]
+    fn test_nested_list_indentation() {
+        let text = render(
+            "- first entry with some text\n\n  1. nested numbered entry that wraps\n  2. second\n\n    - deeply nested entry that also wraps\n- second\n",
+            30,
+        );
+
+        assert_eq!(
+            text,
+            "- first entry with some text\n\n  1. nested numbered entry\n     that wraps\n\n  2. second\n\n     - deeply nested entry\n       that also wraps\n\n- second\n"
+        );
+    }
+
+    #[test]
+    fn test_table_layout() {
+        let text = render(
+            "+======+=============+\n| Name | Description |\n+------+-------------+\n| a    | first       |\n+------+-------------+\n| b    | second one  |\n+------+-------------+\n",
+            80,
+        );
+
+        assert_eq!(
+            text,
+            "Name | Description\n-----+------------\na    | first\nb    | second one\n"
+        );
+    }
+
+    #[test]
+    fn test_table_with_spans_layout() {
+        let text = render(
+            "+---+---+---+\n| a | b | c |\n+-------+---+\n| spanned | d |\n+---+---+---+\n",
+            80,
+        );
+
+        // Spanning cells are shown in the column they start in
+        assert_eq!(text, "a       | b | c\nspanned | d\n");
+    }
+}
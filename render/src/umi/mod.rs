@@ -7,6 +7,7 @@ use crate::log_id::UmiParserError;
 use spreadsheet_ods::{
     read_ods_buf, write_ods_buf_uncompressed, Sheet, Value, ValueType, WorkBook,
 };
+use unimarkup_commons::attributes::Attributes;
 use unimarkup_commons::config::icu_locid::locale;
 use unimarkup_commons::config::Config;
 use unimarkup_commons::lexer::position::Position;
@@ -22,10 +23,21 @@ use unimarkup_inline::{
 use unimarkup_parser::{
     document::Document,
     elements::{
-        atomic::{Heading, Paragraph},
+        atomic::{Comment, Heading, HeadingLevel, HorizontalLine, LineBreak, PageBreak, Paragraph},
         blocks::Block,
-        enclosed::VerbatimBlock,
-        indents::{BulletList, BulletListEntry},
+        decorations::Decorations,
+        definitions::{AbbreviationDefinition, FootnoteDefinition},
+        enclosed::{
+            ColumnAlignment, Columns, ColumnsKind, MathBlock, RenderBlock, Table, TableCell,
+            TableCellKind, TableRow, TextBlock, VerbatimBlock,
+        },
+        indents::{
+            BulletList, BulletListEntry, DefinitionList, DefinitionListEntry,
+            DefinitionListEntryKeyword, EnumerationDelimiter, EnumerationKind, LineBlock,
+            NumberedList, NumberedListEntry, NumberedListEntryKeyword, Quotation, TaskMarker,
+        },
+        inserts::{MediaInsert, MediaKind},
+        references::reference_targets,
     },
 };
 
@@ -41,6 +53,13 @@ fn unpack_content_safe(value: Value) -> String {
 
 const DEFAULT_CONTENT_COLUMN: u32 = 5;
 const DEFAULT_ATTRIBUTES_COLUMN: u32 = 6;
+/// Column containing the status of an element, e.g. the status of a task list entry.
+const STATUS_COLUMN: u32 = 7;
+
+/// Parses the attributes stored in the attributes column of a row.
+fn read_attributes(attributes: &str) -> Option<Attributes> {
+    Attributes::try_from(attributes).ok()
+}
 
 fn retrieve_localised_content(sheet: &Sheet, row_index: u32, col_index: u32) -> String {
     let content_localised =
@@ -87,6 +106,7 @@ pub struct UmiRow {
     depth: u8,
     content: String,
     attributes: String,
+    status: String,
 }
 
 impl UmiRow {
@@ -107,8 +127,15 @@ impl UmiRow {
             depth,
             content,
             attributes,
+            status: String::new(),
         }
     }
+
+    /// Sets the status of this row, e.g. the status of a task list entry.
+    fn with_status(mut self, status: String) -> Self {
+        self.status = status;
+        self
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -149,6 +176,7 @@ impl Umi {
             6,
             String::from("attributes-") + self.lang.to_string().as_str(),
         );
+        sheet.set_value(0, STATUS_COLUMN, "status");
 
         let mut hashmap: HashMap<String, String> = HashMap::new();
         hashmap.insert(
@@ -176,6 +204,9 @@ impl Umi {
             sheet.set_value(row.into(), 4, element.depth);
             sheet.set_value(row.into(), 5, element.content.clone());
             sheet.set_value(row.into(), 6, element.attributes.clone());
+            if !element.status.is_empty() {
+                sheet.set_value(row.into(), STATUS_COLUMN, element.status.clone());
+            }
         }
 
         wb.push_sheet(sheet);
@@ -202,6 +233,62 @@ impl Umi {
         }
     }
 
+    /// Reads the title, caption, and number of a decorated element from its properties.
+    fn read_decorations(&mut self, properties: &HashMap<String, String>) -> Decorations {
+        Decorations {
+            title: properties
+                .get("title")
+                .map(|title| self.read_inlines(title.clone())),
+            caption: properties
+                .get("caption")
+                .map(|caption| self.read_inlines(caption.clone())),
+            number: properties
+                .get("number")
+                .and_then(|number| number.parse().ok()),
+        }
+    }
+
+    fn read_table_cell(&mut self, row: &UmiRow) -> Result<(usize, TableCell), UmiParserError> {
+        let properties: HashMap<String, String> =
+            serde_json::from_str(&row.properties).unwrap_or_default();
+        let property = |name: &str| {
+            properties
+                .get(name)
+                .ok_or(UmiParserError::MissingProperty((name.into(), row.position)))
+        };
+
+        let row_index = property("row")?.parse().unwrap_or_default();
+        let kind = match property("kind")?.as_str() {
+            "=" => TableCellKind::Header,
+            "_" => TableCellKind::Footer,
+            "-" => TableCellKind::Data,
+            _ => {
+                return Err(UmiParserError::InvalidPropertyValue((
+                    "kind".into(),
+                    row.position,
+                )))
+            }
+        };
+        let alignment = match property("alignment")?.as_str() {
+            "left" => Some(ColumnAlignment::Left),
+            "center" => Some(ColumnAlignment::Center),
+            "right" => Some(ColumnAlignment::Right),
+            _ => None,
+        };
+
+        let cell = TableCell {
+            content: unimarkup_parser::parse_unimarkup(&row.content, self.config.clone()).blocks,
+            kind,
+            alignment,
+            column_span: property("column_span")?.parse().unwrap_or(1),
+            row_span: property("row_span")?.parse().unwrap_or(1),
+            start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+            end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+        };
+
+        Ok((row_index, cell))
+    }
+
     fn read_row(&mut self, line: usize) -> Result<Block, UmiParserError> {
         let mut current_line = self.elements[line].clone();
         let properties: HashMap<String, String> =
@@ -209,6 +296,7 @@ impl Umi {
         match current_line.kind.as_str() {
             "Heading" => {
                 let heading = Heading {
+                    attributes: read_attributes(&current_line.attributes),
                     id: current_line.id.clone(),
                     level: unimarkup_parser::elements::atomic::HeadingLevel::try_from(
                         properties
@@ -225,7 +313,6 @@ impl Umi {
                         current_line.position,
                     )))?,
                     content: self.read_inlines(current_line.content.clone()),
-                    attributes: Some(current_line.attributes).filter(|s| !s.is_empty()),
                     start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
                     end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
                 };
@@ -233,15 +320,17 @@ impl Umi {
             }
             "Paragraph" => {
                 let paragraph = Paragraph {
+                    attributes: read_attributes(&current_line.attributes),
                     content: self.read_inlines(current_line.content.clone()),
                 };
                 Ok(Block::Paragraph(paragraph))
             }
             "VerbatimBlock" => {
                 let verbatim = VerbatimBlock {
+                    attributes: read_attributes(&current_line.attributes),
+                    decorations: self.read_decorations(&properties),
                     content: current_line.content.clone(), // TODO: use inline parser, but only allow 'logic' and plain text
                     data_lang: properties.get("data_lang").cloned(),
-                    attributes: Some(current_line.attributes).filter(|s| !s.is_empty()),
                     implicit_closed: properties
                         .get("implicit_closed")
                         .ok_or(UmiParserError::MissingProperty((
@@ -263,8 +352,67 @@ impl Umi {
                 };
                 Ok(Block::VerbatimBlock(verbatim))
             }
+            "MathBlock" => {
+                let math_block = MathBlock {
+                    attributes: read_attributes(&current_line.attributes),
+                    content: current_line.content.clone(),
+                    id: properties.get("id").cloned().filter(|id| !id.is_empty()),
+                    implicit_closed: properties
+                        .get("implicit_closed")
+                        .ok_or(UmiParserError::MissingProperty((
+                            "implicit_closed".into(),
+                            current_line.position,
+                        )))?
+                        .parse()
+                        .unwrap_or_default(),
+                    dollar_len: properties
+                        .get("dollar_len")
+                        .ok_or(UmiParserError::MissingProperty((
+                            "dollar_len".into(),
+                            current_line.position,
+                        )))?
+                        .parse()
+                        .unwrap_or_default(),
+                    start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                    end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                };
+                Ok(Block::MathBlock(math_block))
+            }
+            "RenderBlock" => {
+                let render_block = RenderBlock {
+                    attributes: read_attributes(&current_line.attributes),
+                    content: current_line.content.clone(),
+                    lang: properties
+                        .get("lang")
+                        .ok_or(UmiParserError::MissingProperty((
+                            "lang".into(),
+                            current_line.position,
+                        )))?
+                        .clone(),
+                    implicit_closed: properties
+                        .get("implicit_closed")
+                        .ok_or(UmiParserError::MissingProperty((
+                            "implicit_closed".into(),
+                            current_line.position,
+                        )))?
+                        .parse()
+                        .unwrap_or_default(),
+                    quote_len: properties
+                        .get("quote_len")
+                        .ok_or(UmiParserError::MissingProperty((
+                            "quote_len".into(),
+                            current_line.position,
+                        )))?
+                        .parse()
+                        .unwrap_or_default(),
+                    start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                    end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                };
+                Ok(Block::RenderBlock(render_block))
+            }
             "BulletList" => {
                 let mut bullet_list = BulletList {
+                    attributes: read_attributes(&current_line.attributes),
                     entries: vec![],
                     start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
                     end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
@@ -296,6 +444,7 @@ impl Umi {
             }
             "BulletListEntry" => {
                 let mut bullet_list_entry = BulletListEntry {
+                    attributes: read_attributes(&current_line.attributes),
                     keyword: TokenKind::from(SymbolKind::from(
                         properties
                             .get("keyword")
@@ -311,6 +460,15 @@ impl Umi {
                         "keyword".into(),
                         current_line.position,
                     )))?,
+                    task: match properties.get("task") {
+                        Some(task) => Some(TaskMarker::try_from(task.as_str()).ok().ok_or(
+                            UmiParserError::InvalidPropertyValue((
+                                "task".into(),
+                                current_line.position,
+                            )),
+                        )?),
+                        None => None,
+                    },
                     heading: self.read_inlines(current_line.content.clone()),
                     body: vec![],
                     start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
@@ -339,6 +497,441 @@ impl Umi {
 
                 Ok(Block::BulletListEntry(bullet_list_entry))
             }
+            "NumberedList" => {
+                let numbered_list_depth = current_line.depth;
+                let mut entries = Vec::new();
+                let mut current_line_index = line + 1;
+
+                while let Some(content_line) = self.fetch_next_line(current_line_index) {
+                    if content_line.depth <= numbered_list_depth {
+                        break;
+                    }
+
+                    if content_line.depth == numbered_list_depth + 1 {
+                        // Append Element to Numbered List
+                        match self.read_row(current_line_index) {
+                            Ok(Block::NumberedListEntry(entry)) => entries.push(entry),
+                            _ => break,
+                        }
+                    }
+
+                    current_line_index += 1;
+                }
+
+                // Kind, delimiter, and start number are taken from the keyword of the first entry
+                let (kind, delimiter, start_number) = entries.first().map_or(
+                    (EnumerationKind::Arabic, EnumerationDelimiter::Dot, 1),
+                    |first| {
+                        (
+                            first.keyword.kind,
+                            first.keyword.delimiter,
+                            first.keyword.number,
+                        )
+                    },
+                );
+
+                Ok(Block::NumberedList(NumberedList {
+                    entries,
+                    kind,
+                    delimiter,
+                    start_number,
+                    attributes: read_attributes(&current_line.attributes),
+                    start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                    end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                }))
+            }
+            "NumberedListEntry" => {
+                let mut numbered_list_entry = NumberedListEntry {
+                    attributes: read_attributes(&current_line.attributes),
+                    keyword: NumberedListEntryKeyword::try_from(
+                        properties
+                            .get("keyword")
+                            .ok_or(UmiParserError::MissingProperty((
+                                "keyword".into(),
+                                current_line.position,
+                            )))?
+                            .as_str(),
+                    )
+                    .ok()
+                    .ok_or(UmiParserError::InvalidPropertyValue((
+                        "keyword".into(),
+                        current_line.position,
+                    )))?,
+                    heading: self.read_inlines(current_line.content.clone()),
+                    body: vec![],
+                    start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                    end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                };
+
+                let numbered_list_entry_depth = current_line.depth;
+                let mut current_line_index = line + 1;
+
+                while let Some(content_line) = self.fetch_next_line(current_line_index) {
+                    if content_line.depth <= numbered_list_entry_depth {
+                        break;
+                    }
+
+                    if content_line.depth == numbered_list_entry_depth + 1 {
+                        // Append Element to Numbered List Entry Body
+                        let block = self.read_row(current_line_index)?;
+                        numbered_list_entry.body.push(block);
+                    }
+
+                    current_line_index += 1;
+                }
+
+                Ok(Block::NumberedListEntry(numbered_list_entry))
+            }
+            "DefinitionList" => {
+                let keyword = match properties.get("keyword") {
+                    Some(keyword) => TokenKind::from(SymbolKind::from(keyword.as_str()))
+                        .try_into()
+                        .ok()
+                        .ok_or(UmiParserError::InvalidPropertyValue((
+                            "keyword".into(),
+                            current_line.position,
+                        )))?,
+                    None => DefinitionListEntryKeyword::Colon,
+                };
+
+                let mut definition_list = DefinitionList {
+                    attributes: read_attributes(&current_line.attributes),
+                    entries: vec![],
+                    start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                    end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                };
+
+                let definition_list_depth = current_line.depth;
+                let mut current_line_index = line + 1;
+
+                while let Some(content_line) = self.fetch_next_line(current_line_index) {
+                    if content_line.depth <= definition_list_depth {
+                        break;
+                    }
+
+                    if content_line.depth == definition_list_depth + 1
+                        && content_line.kind == "DefinitionListEntry"
+                    {
+                        let entry_properties: HashMap<String, String> =
+                            serde_json::from_str(&content_line.properties).unwrap_or_default();
+
+                        definition_list.entries.push(DefinitionListEntry {
+                            keyword,
+                            term: self.read_inlines(content_line.content.clone()),
+                            classifier: entry_properties
+                                .get("classifier")
+                                .map(|classifier| self.read_inlines(classifier.clone())),
+                            description: vec![],
+                            start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                            end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                        });
+                    } else if content_line.depth == definition_list_depth + 2 {
+                        let block = self.read_row(current_line_index)?;
+                        if let Some(entry) = definition_list.entries.last_mut() {
+                            entry.description.push(block);
+                        }
+                    }
+
+                    current_line_index += 1;
+                }
+
+                Ok(Block::DefinitionList(definition_list))
+            }
+            "Table" => {
+                let mut table = Table {
+                    attributes: read_attributes(&current_line.attributes),
+                    decorations: self.read_decorations(&properties),
+                    rows: vec![],
+                    column_count: properties
+                        .get("column_count")
+                        .ok_or(UmiParserError::MissingProperty((
+                            "column_count".into(),
+                            current_line.position,
+                        )))?
+                        .parse()
+                        .unwrap_or_default(),
+                    start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                    end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                };
+
+                let table_depth = current_line.depth;
+                let mut current_line_index = line + 1;
+
+                while let Some(cell_line) = self.fetch_next_line(current_line_index) {
+                    if cell_line.depth <= table_depth {
+                        break;
+                    }
+
+                    if cell_line.depth == table_depth + 1 && cell_line.kind == "TableCell" {
+                        let (row_index, cell) = self.read_table_cell(&cell_line)?;
+
+                        while table.rows.len() <= row_index {
+                            table.rows.push(TableRow {
+                                cells: vec![],
+                                start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                                end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                            });
+                        }
+
+                        table.rows[row_index].cells.push(cell);
+                    }
+
+                    current_line_index += 1;
+                }
+
+                Ok(Block::Table(table))
+            }
+            "Columns" => {
+                let pipe_len = properties
+                    .get("pipe_len")
+                    .ok_or(UmiParserError::MissingProperty((
+                        "pipe_len".into(),
+                        current_line.position,
+                    )))?
+                    .parse()
+                    .unwrap_or_default();
+                let implicit_closed = properties
+                    .get("implicit_closed")
+                    .ok_or(UmiParserError::MissingProperty((
+                        "implicit_closed".into(),
+                        current_line.position,
+                    )))?
+                    .parse()
+                    .unwrap_or_default();
+                let kind = match properties.get("kind").map(String::as_str) {
+                    Some("implicit") => ColumnsKind::Implicit {
+                        count: properties
+                            .get("count")
+                            .ok_or(UmiParserError::MissingProperty((
+                                "count".into(),
+                                current_line.position,
+                            )))?
+                            .parse()
+                            .unwrap_or_default(),
+                        split_level: properties
+                            .get("split_level")
+                            .and_then(|level| HeadingLevel::try_from(level.as_str()).ok())
+                            .unwrap_or(HeadingLevel::Level1),
+                    },
+                    _ => ColumnsKind::Explicit,
+                };
+
+                let mut columns = Columns {
+                    attributes: read_attributes(&current_line.attributes),
+                    columns: vec![],
+                    kind,
+                    pipe_len,
+                    implicit_closed,
+                    start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                    end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                };
+
+                let columns_depth = current_line.depth;
+                let mut current_line_index = line + 1;
+
+                while let Some(content_line) = self.fetch_next_line(current_line_index) {
+                    if content_line.depth <= columns_depth {
+                        break;
+                    }
+
+                    if content_line.depth == columns_depth + 1 && content_line.kind == "Column" {
+                        columns.columns.push(vec![]);
+                    } else if content_line.depth == columns_depth + 2 {
+                        let block = self.read_row(current_line_index)?;
+                        if let Some(column) = columns.columns.last_mut() {
+                            column.push(block);
+                        }
+                    }
+
+                    current_line_index += 1;
+                }
+
+                Ok(Block::Columns(columns))
+            }
+            "MediaInsert" => {
+                let source = properties
+                    .get("source")
+                    .ok_or(UmiParserError::MissingProperty((
+                        "source".into(),
+                        current_line.position,
+                    )))?
+                    .clone();
+                let media_insert = MediaInsert {
+                    attributes: read_attributes(&current_line.attributes),
+                    alt: properties.get("alt").cloned().unwrap_or_default(),
+                    kind: MediaKind::from_source(&source),
+                    resolved_path: properties
+                        .get("resolved_path")
+                        .filter(|path| !path.is_empty())
+                        .map(PathBuf::from),
+                    source,
+                    decorations: self.read_decorations(&properties),
+                    start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                    end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                };
+                Ok(Block::MediaInsert(media_insert))
+            }
+            "Quotation" => {
+                let author = Some(self.read_inlines(current_line.content.clone()))
+                    .filter(|author| !author.is_empty());
+                let mut quotation = Quotation {
+                    attributes: read_attributes(&current_line.attributes),
+                    content: vec![],
+                    author,
+                    start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                    end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                };
+
+                let quotation_depth = current_line.depth;
+                let mut current_line_index = line + 1;
+
+                while let Some(content_line) = self.fetch_next_line(current_line_index) {
+                    if content_line.depth <= quotation_depth {
+                        break;
+                    }
+
+                    if content_line.depth == quotation_depth + 1 {
+                        quotation.content.push(self.read_row(current_line_index)?);
+                    }
+
+                    current_line_index += 1;
+                }
+
+                Ok(Block::Quotation(quotation))
+            }
+            "TextBlock" => {
+                let mut text_block = TextBlock {
+                    content: vec![],
+                    bracket_len: properties
+                        .get("bracket_len")
+                        .and_then(|len| len.parse().ok())
+                        .unwrap_or(3),
+                    implicit_closed: properties
+                        .get("implicit_closed")
+                        .is_some_and(|closed| closed == "true"),
+                    attributes: read_attributes(&current_line.attributes),
+                    start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                    end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                };
+
+                let text_block_depth = current_line.depth;
+                let mut current_line_index = line + 1;
+
+                while let Some(content_line) = self.fetch_next_line(current_line_index) {
+                    if content_line.depth <= text_block_depth {
+                        break;
+                    }
+
+                    if content_line.depth == text_block_depth + 1 {
+                        text_block.content.push(self.read_row(current_line_index)?);
+                    }
+
+                    current_line_index += 1;
+                }
+
+                Ok(Block::TextBlock(text_block))
+            }
+            "AbbreviationDefinition" => {
+                let abbreviation_definition = AbbreviationDefinition {
+                    attributes: read_attributes(&current_line.attributes),
+                    abbreviation: properties
+                        .get("abbreviation")
+                        .ok_or(UmiParserError::MissingProperty((
+                            "abbreviation".into(),
+                            current_line.position,
+                        )))?
+                        .clone(),
+                    expansion: self.read_inlines(current_line.content.clone()),
+                    start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                    end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                };
+                Ok(Block::AbbreviationDefinition(abbreviation_definition))
+            }
+            "HorizontalLine" => Ok(Block::HorizontalLine(HorizontalLine {
+                minus_len: properties
+                    .get("minus_len")
+                    .and_then(|len| len.parse().ok())
+                    .unwrap_or(3),
+                attributes: read_attributes(&current_line.attributes),
+                start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+            })),
+            "LineBreak" => Ok(Block::LineBreak(LineBreak {
+                start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+            })),
+            "PageBreak" => Ok(Block::PageBreak(PageBreak {
+                start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+            })),
+            "Comment" => Ok(Block::Comment(Comment {
+                content: properties.get("content").cloned().unwrap_or_default(),
+                implicit_close: properties
+                    .get("implicit_close")
+                    .and_then(|implicit_close| implicit_close.parse().ok())
+                    .unwrap_or(true),
+                start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+            })),
+            "FootnoteDefinition" => {
+                let mut footnote_definition = FootnoteDefinition {
+                    attributes: read_attributes(&current_line.attributes),
+                    id: properties
+                        .get("id")
+                        .ok_or(UmiParserError::MissingProperty((
+                            "id".into(),
+                            current_line.position,
+                        )))?
+                        .clone(),
+                    content: vec![],
+                    start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                    end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                };
+
+                let footnote_depth = current_line.depth;
+                let mut current_line_index = line + 1;
+
+                while let Some(content_line) = self.fetch_next_line(current_line_index) {
+                    if content_line.depth <= footnote_depth {
+                        break;
+                    }
+
+                    if content_line.depth == footnote_depth + 1 {
+                        footnote_definition
+                            .content
+                            .push(self.read_row(current_line_index)?);
+                    }
+
+                    current_line_index += 1;
+                }
+
+                Ok(Block::FootnoteDefinition(footnote_definition))
+            }
+            "LineBlock" => {
+                let mut line_block = LineBlock {
+                    attributes: read_attributes(&current_line.attributes),
+                    content: vec![],
+                    start: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                    end: Position::new(1, 1), // Fallback in case content has been changed manually in .umi
+                };
+
+                let line_block_depth = current_line.depth;
+                let mut current_line_index = line + 1;
+
+                while let Some(content_line) = self.fetch_next_line(current_line_index) {
+                    if content_line.depth <= line_block_depth {
+                        break;
+                    }
+
+                    if content_line.depth == line_block_depth + 1 {
+                        line_block.content.push(self.read_row(current_line_index)?);
+                    }
+
+                    current_line_index += 1;
+                }
+
+                Ok(Block::LineBlock(line_block))
+            }
             "Bibliography" => {
                 let paragraph = Paragraph {
                     content: vec![Inline::Plain(Plain::new(
@@ -346,6 +939,7 @@ impl Umi {
                         Position::default(),
                         Position::default(),
                     ))],
+                    attributes: None,
                 };
                 Ok(Block::Paragraph(paragraph))
             }
@@ -356,6 +950,7 @@ impl Umi {
                         Position::default(),
                         Position::default(),
                     ))],
+                    attributes: None,
                 };
                 Ok(Block::Paragraph(paragraph))
             }
@@ -491,6 +1086,8 @@ impl Umi {
             index += 1;
         }
 
+        let references = reference_targets(&um);
+
         Ok(Document {
             blocks: um,
             config: umi.config.clone(),
@@ -499,6 +1096,9 @@ impl Umi {
             metadata: vec![],
             resources: vec![],
             citations: vec![],
+            footnotes: vec![],
+            abbreviations: vec![],
+            references,
         })
     }
 }
@@ -1,5 +1,8 @@
+use unimarkup_commons::attributes::Attributes;
 use unimarkup_inline::element::InlineElement;
-use unimarkup_parser::elements::blocks::Block;
+use unimarkup_parser::elements::{
+    blocks::Block, decorations::Decorations, enclosed::ColumnsKind, BlockElement,
+};
 
 use crate::log_id::RenderError;
 use crate::render::{Context, OutputFormat, Renderer};
@@ -36,6 +39,26 @@ impl UmiRenderer {
     }
 }
 
+/// Returns the attributes of an element in their original form to be stored in the attributes column.
+fn attributes_column(attributes: Option<&Attributes>) -> String {
+    attributes
+        .map(|attributes| attributes.as_unimarkup().to_string())
+        .unwrap_or_default()
+}
+
+/// Inserts the title, caption, and number of a decorated element into its properties.
+fn insert_decorations(properties: &mut HashMap<String, String>, decorations: &Decorations) {
+    if let Some(title) = &decorations.title {
+        properties.insert(String::from("title"), title.as_unimarkup());
+    }
+    if let Some(caption) = &decorations.caption {
+        properties.insert(String::from("caption"), caption.as_unimarkup());
+    }
+    if let Some(number) = decorations.number {
+        properties.insert(String::from("number"), number.to_string());
+    }
+}
+
 impl Renderer<Umi> for UmiRenderer {
     fn render_paragraph(
         &mut self,
@@ -54,7 +77,7 @@ impl Renderer<Umi> for UmiRenderer {
             properties,
             self.depth,
             content.elements[0].content.clone(),
-            String::new(),
+            attributes_column(paragraph.attributes.as_ref()),
         );
         self.pos += 1;
 
@@ -80,6 +103,7 @@ impl Renderer<Umi> for UmiRenderer {
             String::from("implicit_closed"),
             verbatim.implicit_closed.to_string(),
         );
+        insert_decorations(&mut hashmap, &verbatim.decorations);
         let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
 
         let verbatim = UmiRow::new(
@@ -89,7 +113,7 @@ impl Renderer<Umi> for UmiRenderer {
             properties,
             self.depth,
             verbatim.content.clone(),
-            verbatim.attributes.clone().unwrap_or_default(),
+            attributes_column(verbatim.attributes.as_ref()),
         );
         self.pos += 1;
 
@@ -100,6 +124,83 @@ impl Renderer<Umi> for UmiRenderer {
         ))
     }
 
+    fn render_render_block(
+        &mut self,
+        render_block: &unimarkup_parser::elements::enclosed::RenderBlock,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        let mut hashmap: HashMap<String, String> = HashMap::new();
+        hashmap.insert(String::from("lang"), render_block.lang.clone());
+        hashmap.insert(
+            String::from("quote_len"),
+            render_block.quote_len.to_string(),
+        );
+        hashmap.insert(
+            String::from("implicit_closed"),
+            render_block.implicit_closed.to_string(),
+        );
+        let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+
+        let render = UmiRow::new(
+            self.pos,
+            String::new(),
+            Block::RenderBlock(render_block.to_owned())
+                .variant_str()
+                .to_string(),
+            properties,
+            self.depth,
+            render_block.content.clone(),
+            attributes_column(render_block.attributes.as_ref()),
+        );
+        self.pos += 1;
+
+        self.proceed(Umi::with_um(
+            vec![render],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        ))
+    }
+
+    fn render_math_block(
+        &mut self,
+        math_block: &unimarkup_parser::elements::enclosed::MathBlock,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        let mut hashmap: HashMap<String, String> = HashMap::new();
+        hashmap.insert(
+            String::from("id"),
+            math_block.id.clone().unwrap_or_default(),
+        );
+        hashmap.insert(
+            String::from("dollar_len"),
+            math_block.dollar_len.to_string(),
+        );
+        hashmap.insert(
+            String::from("implicit_closed"),
+            math_block.implicit_closed.to_string(),
+        );
+        let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+
+        let math = UmiRow::new(
+            self.pos,
+            String::new(),
+            Block::MathBlock(math_block.to_owned())
+                .variant_str()
+                .to_string(),
+            properties,
+            self.depth,
+            math_block.content.clone(),
+            attributes_column(math_block.attributes.as_ref()),
+        );
+        self.pos += 1;
+
+        self.proceed(Umi::with_um(
+            vec![math],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        ))
+    }
+
     fn render_heading(
         &mut self,
         heading: &unimarkup_parser::elements::atomic::Heading,
@@ -118,7 +219,7 @@ impl Renderer<Umi> for UmiRenderer {
             properties,
             self.depth,
             content.elements[0].content.clone(),
-            heading.attributes.clone().unwrap_or_default(),
+            attributes_column(heading.attributes.as_ref()),
         );
         self.pos += 1;
 
@@ -144,7 +245,7 @@ impl Renderer<Umi> for UmiRenderer {
             properties,
             self.depth,
             String::new(),
-            String::new(),
+            attributes_column(bullet_list.attributes.as_ref()),
         );
         self.pos += 1;
 
@@ -173,6 +274,9 @@ impl Renderer<Umi> for UmiRenderer {
             String::from("keyword"),
             bullet_list_entry.keyword.as_str().to_string(),
         );
+        if let Some(task) = bullet_list_entry.task {
+            hashmap.insert(String::from("task"), task.as_str().to_string());
+        }
         let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
         let mut entry = Umi::with_um(
             vec![UmiRow::new(
@@ -187,7 +291,13 @@ impl Renderer<Umi> for UmiRenderer {
                     .elements[0]
                     .content
                     .clone(),
-                String::new(),
+                attributes_column(bullet_list_entry.attributes.as_ref()),
+            )
+            .with_status(
+                bullet_list_entry
+                    .task_status()
+                    .map(|status| status.as_str().to_string())
+                    .unwrap_or_default(),
             )],
             context.get_config().clone(),
             context.get_lang().to_string(),
@@ -205,6 +315,627 @@ impl Renderer<Umi> for UmiRenderer {
         self.proceed(entry)
     }
 
+    fn render_numbered_list(
+        &mut self,
+        numbered_list: &unimarkup_parser::elements::indents::NumberedList,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        // Kind, delimiter, and start number are taken from the keyword of the first entry
+        let hashmap: HashMap<String, String> = HashMap::new();
+        let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+
+        let numbered_list_heading = UmiRow::new(
+            self.pos,
+            String::new(),
+            String::from("NumberedList"),
+            properties,
+            self.depth,
+            String::new(),
+            attributes_column(numbered_list.attributes.as_ref()),
+        );
+        self.pos += 1;
+
+        let mut numbered_list_content = Umi::with_um(
+            vec![numbered_list_heading],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        );
+
+        self.step_in();
+        for entry in &numbered_list.entries {
+            numbered_list_content.append(self.render_numbered_list_entry(entry, context)?)?;
+        }
+        self.step_out();
+
+        self.proceed(numbered_list_content)
+    }
+
+    fn render_numbered_list_entry(
+        &mut self,
+        numbered_list_entry: &unimarkup_parser::elements::indents::NumberedListEntry,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        let mut hashmap: HashMap<String, String> = HashMap::new();
+        hashmap.insert(
+            String::from("keyword"),
+            numbered_list_entry.keyword.as_string(),
+        );
+        let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+        let mut entry = Umi::with_um(
+            vec![UmiRow::new(
+                self.pos,
+                String::new(),
+                Block::NumberedListEntry(numbered_list_entry.to_owned())
+                    .variant_str()
+                    .to_string(),
+                properties,
+                self.depth,
+                self.render_inlines(&numbered_list_entry.heading, context)?
+                    .elements[0]
+                    .content
+                    .clone(),
+                attributes_column(numbered_list_entry.attributes.as_ref()),
+            )],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        );
+        self.pos += 1;
+
+        // Render All Numbered List Body Elements
+        self.step_in();
+        if !numbered_list_entry.body.is_empty() {
+            let next_entry = self.render_blocks(&numbered_list_entry.body, context)?;
+            let _ = entry.append(next_entry);
+        }
+        self.step_out();
+
+        self.proceed(entry)
+    }
+
+    fn render_definition_list(
+        &mut self,
+        definition_list: &unimarkup_parser::elements::indents::DefinitionList,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        let mut hashmap: HashMap<String, String> = HashMap::new();
+        if let Some(entry) = definition_list.entries.first() {
+            hashmap.insert(String::from("keyword"), entry.keyword.as_str().to_string());
+        }
+        let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+
+        let mut definition_list_content = Umi::with_um(
+            vec![UmiRow::new(
+                self.pos,
+                String::new(),
+                Block::DefinitionList(definition_list.to_owned())
+                    .variant_str()
+                    .to_string(),
+                properties,
+                self.depth,
+                String::new(),
+                attributes_column(definition_list.attributes.as_ref()),
+            )],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        );
+        self.pos += 1;
+
+        self.step_in();
+        for entry in &definition_list.entries {
+            let mut hashmap: HashMap<String, String> = HashMap::new();
+            if let Some(classifier) = &entry.classifier {
+                hashmap.insert(
+                    String::from("classifier"),
+                    self.render_inlines(classifier, context)?.elements[0]
+                        .content
+                        .clone(),
+                );
+            }
+            let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+
+            let term = self.render_inlines(&entry.term, context)?.elements[0]
+                .content
+                .clone();
+            definition_list_content.append(Umi::with_um(
+                vec![UmiRow::new(
+                    self.pos,
+                    String::new(),
+                    String::from("DefinitionListEntry"),
+                    properties,
+                    self.depth,
+                    term,
+                    String::new(),
+                )],
+                context.get_config().clone(),
+                context.get_lang().to_string(),
+            ))?;
+            self.pos += 1;
+
+            self.step_in();
+            if !entry.description.is_empty() {
+                let description = self.render_blocks(&entry.description, context)?;
+                definition_list_content.append(description)?;
+            }
+            self.step_out();
+        }
+        self.step_out();
+
+        self.proceed(definition_list_content)
+    }
+
+    fn render_table(
+        &mut self,
+        table: &unimarkup_parser::elements::enclosed::Table,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        let mut hashmap: HashMap<String, String> = HashMap::new();
+        hashmap.insert(String::from("column_count"), table.column_count.to_string());
+        insert_decorations(&mut hashmap, &table.decorations);
+        let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+
+        let table_heading = UmiRow::new(
+            self.pos,
+            String::new(),
+            String::from(Block::Table(table.to_owned()).variant_str()),
+            properties,
+            self.depth,
+            String::new(),
+            attributes_column(table.attributes.as_ref()),
+        );
+        self.pos += 1;
+
+        let mut table_content = Umi::with_um(
+            vec![table_heading],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        );
+
+        // One row per cell, because cells may span multiple rows and columns
+        self.step_in();
+        for (row_index, row) in table.rows.iter().enumerate() {
+            for cell in &row.cells {
+                let mut hashmap: HashMap<String, String> = HashMap::new();
+                hashmap.insert(String::from("row"), row_index.to_string());
+                hashmap.insert(String::from("kind"), cell.kind.as_str().to_string());
+                hashmap.insert(
+                    String::from("alignment"),
+                    cell.alignment
+                        .map(|alignment| alignment.as_str().to_string())
+                        .unwrap_or_default(),
+                );
+                hashmap.insert(String::from("column_span"), cell.column_span.to_string());
+                hashmap.insert(String::from("row_span"), cell.row_span.to_string());
+                let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+
+                let cell_row = UmiRow::new(
+                    self.pos,
+                    String::new(),
+                    String::from("TableCell"),
+                    properties,
+                    self.depth,
+                    cell.content.as_unimarkup(),
+                    String::new(),
+                );
+                self.pos += 1;
+
+                table_content.append(Umi::with_um(
+                    vec![cell_row],
+                    context.get_config().clone(),
+                    context.get_lang().to_string(),
+                ))?;
+            }
+        }
+        self.step_out();
+
+        self.proceed(table_content)
+    }
+
+    fn render_columns(
+        &mut self,
+        columns: &unimarkup_parser::elements::enclosed::Columns,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        let mut hashmap: HashMap<String, String> = HashMap::new();
+        match columns.kind {
+            ColumnsKind::Explicit => {
+                hashmap.insert(String::from("kind"), String::from("explicit"));
+            }
+            ColumnsKind::Implicit { count, split_level } => {
+                hashmap.insert(String::from("kind"), String::from("implicit"));
+                hashmap.insert(String::from("count"), count.to_string());
+                hashmap.insert(
+                    String::from("split_level"),
+                    split_level.as_str().to_string(),
+                );
+            }
+        }
+        hashmap.insert(String::from("pipe_len"), columns.pipe_len.to_string());
+        hashmap.insert(
+            String::from("implicit_closed"),
+            columns.implicit_closed.to_string(),
+        );
+        let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+
+        let mut columns_content = Umi::with_um(
+            vec![UmiRow::new(
+                self.pos,
+                String::new(),
+                Block::Columns(columns.to_owned()).variant_str().to_string(),
+                properties,
+                self.depth,
+                String::new(),
+                attributes_column(columns.attributes.as_ref()),
+            )],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        );
+        self.pos += 1;
+
+        // Every column gets its own row, so empty columns are kept
+        self.step_in();
+        for column in &columns.columns {
+            columns_content.append(Umi::with_um(
+                vec![UmiRow::new(
+                    self.pos,
+                    String::new(),
+                    String::from("Column"),
+                    String::from("{}"),
+                    self.depth,
+                    String::new(),
+                    String::new(),
+                )],
+                context.get_config().clone(),
+                context.get_lang().to_string(),
+            ))?;
+            self.pos += 1;
+
+            self.step_in();
+            if !column.is_empty() {
+                columns_content.append(self.render_blocks(column, context)?)?;
+            }
+            self.step_out();
+        }
+        self.step_out();
+
+        self.proceed(columns_content)
+    }
+
+    fn render_media_insert(
+        &mut self,
+        media_insert: &unimarkup_parser::elements::inserts::MediaInsert,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        let mut hashmap: HashMap<String, String> = HashMap::new();
+        hashmap.insert(String::from("alt"), media_insert.alt.clone());
+        hashmap.insert(String::from("source"), media_insert.source.clone());
+        hashmap.insert(
+            String::from("resolved_path"),
+            media_insert
+                .resolved_path
+                .as_ref()
+                .map(|path| path.to_string_lossy().to_string())
+                .unwrap_or_default(),
+        );
+        hashmap.insert(String::from("kind"), media_insert.kind.as_str().to_string());
+        insert_decorations(&mut hashmap, &media_insert.decorations);
+        let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+
+        let media = UmiRow::new(
+            self.pos,
+            String::new(),
+            Block::MediaInsert(media_insert.to_owned())
+                .variant_str()
+                .to_string(),
+            properties,
+            self.depth,
+            String::new(),
+            attributes_column(media_insert.attributes.as_ref()),
+        );
+        self.pos += 1;
+
+        self.proceed(Umi::with_um(
+            vec![media],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        ))
+    }
+
+    fn render_quotation(
+        &mut self,
+        quotation: &unimarkup_parser::elements::indents::Quotation,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        let hashmap: HashMap<String, String> = HashMap::new();
+        let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+
+        // Attribution is stored as content of the quotation row
+        let author = match &quotation.author {
+            Some(author) => self.render_inlines(author, context)?.elements[0]
+                .content
+                .clone(),
+            None => String::new(),
+        };
+
+        let mut quotation_content = Umi::with_um(
+            vec![UmiRow::new(
+                self.pos,
+                String::new(),
+                Block::Quotation(quotation.to_owned())
+                    .variant_str()
+                    .to_string(),
+                properties,
+                self.depth,
+                author,
+                attributes_column(quotation.attributes.as_ref()),
+            )],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        );
+        self.pos += 1;
+
+        self.step_in();
+        let content = self.render_blocks(&quotation.content, context)?;
+        let _ = quotation_content.append(content);
+        self.step_out();
+
+        self.proceed(quotation_content)
+    }
+
+    fn render_text_block(
+        &mut self,
+        text_block: &unimarkup_parser::elements::enclosed::TextBlock,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        let mut hashmap: HashMap<String, String> = HashMap::new();
+        hashmap.insert(
+            String::from("bracket_len"),
+            text_block.bracket_len.to_string(),
+        );
+        hashmap.insert(
+            String::from("implicit_closed"),
+            text_block.implicit_closed.to_string(),
+        );
+        let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+
+        let mut text_block_content = Umi::with_um(
+            vec![UmiRow::new(
+                self.pos,
+                String::new(),
+                Block::TextBlock(text_block.to_owned())
+                    .variant_str()
+                    .to_string(),
+                properties,
+                self.depth,
+                String::new(),
+                attributes_column(text_block.attributes.as_ref()),
+            )],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        );
+        self.pos += 1;
+
+        self.step_in();
+        let content = self.render_blocks(&text_block.content, context)?;
+        let _ = text_block_content.append(content);
+        self.step_out();
+
+        self.proceed(text_block_content)
+    }
+
+    fn render_footnote_definition(
+        &mut self,
+        footnote_definition: &unimarkup_parser::elements::definitions::FootnoteDefinition,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        let mut hashmap: HashMap<String, String> = HashMap::new();
+        hashmap.insert(String::from("id"), footnote_definition.id.clone());
+        let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+
+        let mut footnote_content = Umi::with_um(
+            vec![UmiRow::new(
+                self.pos,
+                String::new(),
+                Block::FootnoteDefinition(footnote_definition.to_owned())
+                    .variant_str()
+                    .to_string(),
+                properties,
+                self.depth,
+                String::new(),
+                attributes_column(footnote_definition.attributes.as_ref()),
+            )],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        );
+        self.pos += 1;
+
+        self.step_in();
+        let content = self.render_blocks(&footnote_definition.content, context)?;
+        let _ = footnote_content.append(content);
+        self.step_out();
+
+        self.proceed(footnote_content)
+    }
+
+    fn render_abbreviation_definition(
+        &mut self,
+        abbreviation_definition: &unimarkup_parser::elements::definitions::AbbreviationDefinition,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        let content = self.render_inlines(&abbreviation_definition.expansion, context)?;
+
+        let mut hashmap: HashMap<String, String> = HashMap::new();
+        hashmap.insert(
+            String::from("abbreviation"),
+            abbreviation_definition.abbreviation.clone(),
+        );
+        let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+
+        let abbreviation = UmiRow::new(
+            self.pos,
+            String::new(),
+            Block::AbbreviationDefinition(abbreviation_definition.to_owned())
+                .variant_str()
+                .to_string(),
+            properties,
+            self.depth,
+            content.elements[0].content.clone(),
+            attributes_column(abbreviation_definition.attributes.as_ref()),
+        );
+        self.pos += 1;
+
+        self.proceed(Umi::with_um(
+            vec![abbreviation],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        ))
+    }
+
+    fn render_horizontal_line(
+        &mut self,
+        horizontal_line: &unimarkup_parser::elements::atomic::HorizontalLine,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        let mut hashmap: HashMap<String, String> = HashMap::new();
+        hashmap.insert(
+            String::from("minus_len"),
+            horizontal_line.minus_len.to_string(),
+        );
+        let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+
+        let line = UmiRow::new(
+            self.pos,
+            String::new(),
+            Block::HorizontalLine(horizontal_line.to_owned())
+                .variant_str()
+                .to_string(),
+            properties,
+            self.depth,
+            String::new(),
+            attributes_column(horizontal_line.attributes.as_ref()),
+        );
+        self.pos += 1;
+
+        self.proceed(Umi::with_um(
+            vec![line],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        ))
+    }
+
+    fn render_line_break(
+        &mut self,
+        line_break: &unimarkup_parser::elements::atomic::LineBreak,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        let line_break = UmiRow::new(
+            self.pos,
+            String::new(),
+            Block::LineBreak(line_break.to_owned())
+                .variant_str()
+                .to_string(),
+            String::from("{}"),
+            self.depth,
+            String::new(),
+            String::new(),
+        );
+        self.pos += 1;
+
+        self.proceed(Umi::with_um(
+            vec![line_break],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        ))
+    }
+
+    fn render_page_break(
+        &mut self,
+        page_break: &unimarkup_parser::elements::atomic::PageBreak,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        let page_break = UmiRow::new(
+            self.pos,
+            String::new(),
+            Block::PageBreak(page_break.to_owned())
+                .variant_str()
+                .to_string(),
+            String::from("{}"),
+            self.depth,
+            String::new(),
+            String::new(),
+        );
+        self.pos += 1;
+
+        self.proceed(Umi::with_um(
+            vec![page_break],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        ))
+    }
+
+    fn render_comment_block(
+        &mut self,
+        comment: &unimarkup_parser::elements::atomic::Comment,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        let mut hashmap: HashMap<String, String> = HashMap::new();
+        hashmap.insert(String::from("content"), comment.content.clone());
+        hashmap.insert(
+            String::from("implicit_close"),
+            comment.implicit_close.to_string(),
+        );
+        let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+
+        let line = UmiRow::new(
+            self.pos,
+            String::new(),
+            Block::Comment(comment.to_owned()).variant_str().to_string(),
+            properties,
+            self.depth,
+            String::new(),
+            String::new(),
+        );
+        self.pos += 1;
+
+        self.proceed(Umi::with_um(
+            vec![line],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        ))
+    }
+
+    fn render_line_block(
+        &mut self,
+        line_block: &unimarkup_parser::elements::indents::LineBlock,
+        context: &Context,
+    ) -> Result<Umi, crate::log_id::RenderError> {
+        let hashmap: HashMap<String, String> = HashMap::new();
+        let properties = serde_json::to_string(&hashmap).unwrap_or(String::from("{}"));
+
+        let mut line_block_content = Umi::with_um(
+            vec![UmiRow::new(
+                self.pos,
+                String::new(),
+                Block::LineBlock(line_block.to_owned())
+                    .variant_str()
+                    .to_string(),
+                properties,
+                self.depth,
+                String::new(),
+                attributes_column(line_block.attributes.as_ref()),
+            )],
+            context.get_config().clone(),
+            context.get_lang().to_string(),
+        );
+        self.pos += 1;
+
+        self.step_in();
+        let content = self.render_blocks(&line_block.content, context)?;
+        let _ = line_block_content.append(content);
+        self.step_out();
+
+        self.proceed(line_block_content)
+    }
+
     fn render_inlines(
         &mut self,
         inlines: &[unimarkup_inline::element::Inline],
@@ -284,4 +1015,9 @@ impl Renderer<Umi> for UmiRenderer {
             Ok(Umi::default())
         }
     }
+
+    fn render_glossary(&mut self, _context: &Context) -> Result<Umi, crate::log_id::RenderError> {
+        // Abbreviation definitions are already part of the UMI content
+        Ok(Umi::default())
+    }
 }